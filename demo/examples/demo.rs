@@ -0,0 +1,138 @@
+//! Watch a lesson's vulnerability trigger, then watch the fix stop it
+//!
+//! Start a local validator with the programs deployed, then pick a lesson:
+//!
+//! ```text
+//! anchor build
+//! anchor localnet                                  # keep this running
+//! cargo run --example demo -- 01-missing-signer    # in a second terminal
+//! ```
+//!
+//! `anchor localnet` loads every program listed under `[[test.genesis]]` in
+//! Anchor.toml. A plain validator works too, as long as the lesson's program
+//! is loaded at its declared ID:
+//!
+//! ```text
+//! solana-test-validator --reset \
+//!     --bpf-program HVDgr5PCwyH1bGkVvB6sDqzDkjZq6FfC8qE38PV2Z8Fc \
+//!     target/deploy/missing_signer_check.so
+//! ```
+//!
+//! Each demo creates fresh accounts, sends an attack to the `_insecure`
+//! instruction and then the same attack to the secure one. The process exits
+//! non-zero if either half doesn't behave the way the lesson says.
+use std::process::ExitCode;
+
+use anchor_client::solana_sdk::native_token::LAMPORTS_PER_SOL;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{Keypair, Signer};
+use anchor_client::solana_sdk::system_program;
+use anyhow::Result;
+use security_demo::{report, without_signer, Demo, Side};
+
+type Lesson = fn(&Demo) -> Result<bool>;
+
+/// Lessons the demo can drive, by the name passed on the command line
+///
+/// Only lesson 01 for now. Several other exploits (02, 05, 12, 13, 18) need
+/// an account holding attacker-chosen data under another program's owner,
+/// which `tests/lessons` can inject but a live validator only allows through
+/// a deployed attacker program. Every lesson is driven end to end by its
+/// `tests/NN-*.ts` file and by `tests/lessons`.
+const LESSONS: &[(&str, Lesson)] = &[("01-missing-signer", missing_signer)];
+
+fn main() -> ExitCode {
+    let Some(name) = std::env::args().nth(1) else {
+        return usage();
+    };
+    let Some((_, lesson)) = LESSONS.iter().find(|(lesson, _)| *lesson == name) else {
+        eprintln!("Unknown lesson `{name}`");
+        return usage();
+    };
+
+    let outcome = Demo::connect().and_then(|demo| lesson(&demo));
+    match outcome {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("Demo could not run: {error:#}");
+            eprintln!("Is a local validator running with the programs deployed?");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("Usage: cargo run --example demo -- <lesson>");
+    eprintln!("Lessons:");
+    for (name, _) in LESSONS {
+        eprintln!("  {name}");
+    }
+    ExitCode::FAILURE
+}
+
+/// 01: name the vault authority's key without its signature
+fn missing_signer(demo: &Demo) -> Result<bool> {
+    use missing_signer_check::{accounts, instruction};
+
+    let program = demo.program(missing_signer_check::ID)?;
+    let owner = Keypair::new();
+    let attacker = demo.payer.pubkey();
+    let amount = LAMPORTS_PER_SOL / 2;
+
+    // The owner creates and funds a vault - the only time they sign anything
+    demo.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL)?;
+    let (vault, _) = Pubkey::find_program_address(
+        &[b"vault", owner.pubkey().as_ref()],
+        &missing_signer_check::ID,
+    );
+    program
+        .request()
+        .accounts(accounts::InitializeVault {
+            initializer: owner.pubkey(),
+            vault,
+            system_program: system_program::ID,
+        })
+        .args(instruction::InitializeVaultSecure {
+            withdrawal_limit: 0,
+        })
+        .signer(&owner)
+        .send()?;
+    demo.airdrop(&vault, LAMPORTS_PER_SOL)?;
+
+    println!("🔑 Missing signer check");
+    println!("  owner:    {}", owner.pubkey());
+    println!("  attacker: {attacker} (the only signer below)");
+    println!("  vault:    {vault} ({} lamports)", demo.balance(&vault)?);
+
+    let insecure = program
+        .request()
+        .accounts(accounts::WithdrawInsecure {
+            vault,
+            authority: owner.pubkey(),
+        })
+        .args(instruction::WithdrawInsecure { amount })
+        .instructions()?;
+    let vulnerable_ok = report(
+        Side::Vulnerable,
+        "withdraw_insecure",
+        &demo.send_as_payer(&without_signer(insecure, &owner.pubkey())),
+    );
+
+    let secure = program
+        .request()
+        .accounts(accounts::WithdrawSecure {
+            vault,
+            authority: owner.pubkey(),
+        })
+        .args(instruction::WithdrawSecure { amount })
+        .instructions()?;
+    let secure_ok = report(
+        Side::Secure,
+        "withdraw_secure",
+        &demo.send_as_payer(&without_signer(secure, &owner.pubkey())),
+    );
+
+    println!("  vault:    {vault} ({} lamports)", demo.balance(&vault)?);
+    Ok(vulnerable_ok && secure_ok)
+}
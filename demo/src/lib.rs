@@ -0,0 +1,127 @@
+//! Plumbing shared by `examples/demo.rs`
+//!
+//! Talks to a `solana-test-validator` on the default local port that already
+//! has the lesson programs deployed.
+use std::rc::Rc;
+use std::thread::sleep;
+use std::time::Duration;
+
+use anchor_client::solana_client::rpc_client::RpcClient;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::instruction::Instruction;
+use anchor_client::solana_sdk::native_token::LAMPORTS_PER_SOL;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{Keypair, Signature, Signer};
+use anchor_client::solana_sdk::transaction::Transaction;
+use anchor_client::{Client, Cluster, Program};
+use anyhow::Result;
+
+/// Which half of a lesson an instruction belongs to
+#[derive(Clone, Copy)]
+pub enum Side {
+    /// The attack is expected to go through
+    Vulnerable,
+    /// The same attack is expected to be rejected
+    Secure,
+}
+
+/// A funded fee payer plus a client for the local validator
+///
+/// The payer plays the attacker: `send_as_payer` signs with this key only,
+/// whatever the instructions claim.
+pub struct Demo {
+    pub payer: Rc<Keypair>,
+    client: Client<Rc<Keypair>>,
+    rpc: RpcClient,
+}
+
+impl Demo {
+    pub fn connect() -> Result<Self> {
+        let payer = Rc::new(Keypair::new());
+        let client = Client::new_with_options(
+            Cluster::Localnet,
+            payer.clone(),
+            CommitmentConfig::confirmed(),
+        );
+        let rpc = RpcClient::new_with_commitment(
+            Cluster::Localnet.url().to_string(),
+            CommitmentConfig::confirmed(),
+        );
+
+        let demo = Self { payer, client, rpc };
+        demo.airdrop(&demo.payer.pubkey(), 2 * LAMPORTS_PER_SOL)?;
+        Ok(demo)
+    }
+
+    pub fn program(&self, program_id: Pubkey) -> Result<Program<Rc<Keypair>>> {
+        Ok(self.client.program(program_id)?)
+    }
+
+    /// Airdrop `lamports` to `to` and wait until it has landed
+    pub fn airdrop(&self, to: &Pubkey, lamports: u64) -> Result<()> {
+        let signature = self.rpc.request_airdrop(to, lamports)?;
+        while !self.rpc.confirm_transaction(&signature)? {
+            sleep(Duration::from_millis(200));
+        }
+        Ok(())
+    }
+
+    pub fn balance(&self, account: &Pubkey) -> Result<u64> {
+        Ok(self.rpc.get_balance(account)?)
+    }
+
+    /// Send `instructions` signed by the payer and nobody else
+    ///
+    /// `RequestBuilder::send` refuses to build a transaction that is missing
+    /// a signature. An attacker has no such client-side check, so demos go
+    /// through here with [`without_signer`] applied first.
+    pub fn send_as_payer(&self, instructions: &[Instruction]) -> Result<Signature> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &[self.payer.as_ref()],
+            blockhash,
+        );
+        Ok(self.rpc.send_and_confirm_transaction(&transaction)?)
+    }
+}
+
+/// Clear the signer flag on every meta for `key`
+///
+/// This is the whole missing-signer attack: name the victim's account, but
+/// don't sign for it.
+pub fn without_signer(mut instructions: Vec<Instruction>, key: &Pubkey) -> Vec<Instruction> {
+    for meta in instructions.iter_mut().flat_map(|ix| ix.accounts.iter_mut()) {
+        if meta.pubkey == *key {
+            meta.is_signer = false;
+        }
+    }
+    instructions
+}
+
+/// Print how an attack on `instruction` went
+///
+/// Returns whether the outcome is the one the lesson predicts, so the demo
+/// can exit non-zero when a vulnerable path stops being exploitable or a
+/// secure one starts letting the attack through.
+pub fn report(side: Side, instruction: &str, result: &Result<Signature>) -> bool {
+    match (side, result) {
+        (Side::Vulnerable, Ok(signature)) => {
+            println!("  ❌ VULNERABLE {instruction}: attack succeeded ({signature})");
+            true
+        }
+        (Side::Vulnerable, Err(error)) => {
+            println!("  ⚠️  {instruction}: attack unexpectedly failed\n      {error}");
+            false
+        }
+        (Side::Secure, Err(error)) => {
+            println!("  ✅ SECURE {instruction}: attack rejected\n      {error}");
+            true
+        }
+        (Side::Secure, Ok(signature)) => {
+            println!("  🚨 {instruction}: attack succeeded against the fix ({signature})");
+            false
+        }
+    }
+}
@@ -1,6 +1,41 @@
 // ✅ SECURE IMPLEMENTATION - Use this pattern in production
 
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+// Shared error codes and balance helpers (see programs/common)
+pub use security_common::{space_of, Balance, Role, Roles, SecurityError};
+use security_common::{
+    checked_transfer_lamports, close_account, require_nonzero_amount, require_role,
+};
+
+/// Deposit lamports into the vault
+///
+/// The depositor is a system-owned account, so only the System Program may
+/// debit it. Direct lamport mutation (as used on the withdraw side, where the
+/// vault is owned by this program) would be rejected by the runtime here, so
+/// the transfer goes through a System Program CPI instead.
+pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    // ✅ Shared Balance helper: MathOverflow instead of wrapping
+    ctx.accounts.vault.checked_credit(amount)?;
+    
+    msg!("Deposited {} lamports into vault", amount);
+    
+    Ok(())
+}
 
 /// Secure withdraw function with proper signer verification
 ///
@@ -17,34 +52,58 @@ use anchor_lang::prelude::*;
 /// - Even if they include it in the transaction
 /// - The transaction will fail unless signed by the authority's private key
 pub fn withdraw_secure(ctx: Context<WithdrawSecure>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
     let vault = &mut ctx.accounts.vault;
     
     // ✅ SECURE: At this point, we know authority.is_signer == true
     // because of the #[account(signer)] constraint in WithdrawSecure struct
     
     // Double-check authority matches (defense in depth)
-    // This check is redundant due to the constraint, but good for explicitness
-    require!(
-        vault.authority == ctx.accounts.authority.key(),
-        ErrorCode::Unauthorized
+    // This check is redundant due to the constraint, but good for explicitness.
+    // require_keys_eq! logs both pubkeys on failure, not just the error name
+    require_keys_eq!(
+        vault.authority,
+        ctx.accounts.authority.key(),
+        SecurityError::Unauthorized
     );
     
+    // ✅ Emergency stop: nothing leaves a paused or frozen vault
+    require!(!vault.is_paused, ErrorCode::VaultPaused);
+    require!(!vault.is_frozen, ErrorCode::VaultFrozen);
+    
     // Check sufficient balance
-    let vault_lamports = ctx.accounts.vault.to_account_info().lamports();
+    let vault_lamports = vault.to_account_info().lamports();
     require!(
         vault_lamports >= amount,
-        ErrorCode::InsufficientFunds
+        SecurityError::InsufficientFunds
+    );
+    
+    // Enforce the lifetime withdrawal cap (a limit of 0 means unlimited)
+    require!(
+        vault.can_withdraw(amount),
+        ErrorCode::WithdrawalLimitExceeded
     );
     
     // ✅ SAFE: Transfer executes only after verifying:
     // 1. Authority signed the transaction (Anchor constraint)
     // 2. Authority pubkey matches vault owner (explicit check)
-    **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
-    **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+    checked_transfer_lamports(
+        &vault.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        amount,
+    )?;
     
     // Update vault state
     vault.total_withdrawn = vault.total_withdrawn.checked_add(amount)
-        .ok_or(ErrorCode::MathOverflow)?;
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    emit!(WithdrawEvent {
+        vault: vault.key(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+        total_withdrawn: vault.total_withdrawn,
+    });
     
     msg!("Securely withdrawn {} lamports from vault", amount);
     
@@ -55,6 +114,8 @@ pub fn withdraw_secure(ctx: Context<WithdrawSecure>, amount: u64) -> Result<()>
 ///
 /// USE CASE: When you need more control or can't use Anchor constraints
 pub fn withdraw_manual_check(ctx: Context<WithdrawManual>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
     let vault = &mut ctx.accounts.vault;
     
     // ✅ CRITICAL: Manual signer verification
@@ -66,34 +127,304 @@ pub fn withdraw_manual_check(ctx: Context<WithdrawManual>, amount: u64) -> Resul
     );
     
     // Check authority matches
-    require!(
-        vault.authority == ctx.accounts.authority.key(),
-        ErrorCode::Unauthorized
+    require_keys_eq!(
+        vault.authority,
+        ctx.accounts.authority.key(),
+        SecurityError::Unauthorized
     );
     
+    require!(!vault.is_paused, ErrorCode::VaultPaused);
+    require!(!vault.is_frozen, ErrorCode::VaultFrozen);
+    
     // Check sufficient balance
-    let vault_lamports = ctx.accounts.vault.to_account_info().lamports();
+    let vault_lamports = vault.to_account_info().lamports();
     require!(
         vault_lamports >= amount,
-        ErrorCode::InsufficientFunds
+        SecurityError::InsufficientFunds
+    );
+    
+    require!(
+        vault.can_withdraw(amount),
+        ErrorCode::WithdrawalLimitExceeded
     );
     
     // Safe transfer
-    **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
-    **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+    checked_transfer_lamports(
+        &vault.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        amount,
+    )?;
+    
+    vault.total_withdrawn = vault.total_withdrawn.checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    emit!(WithdrawEvent {
+        vault: vault.key(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+        total_withdrawn: vault.total_withdrawn,
+    });
+    
+    Ok(())
+}
+
+/// Time-locked withdrawal: request first, execute after a delay
+///
+/// USE CASE: Treasuries that want a window to notice (and react to) a
+/// withdrawal before funds actually leave the vault.
+///
+/// - First call (nothing pending): records `amount` as `pending_amount` and
+///   sets `unlock_timestamp = now + delay`. No lamports move.
+/// - Second call (amount pending): pays out `pending_amount` once the clock
+///   has reached `unlock_timestamp`. The `amount` and `delay` arguments are
+///   ignored on this call.
+pub fn withdraw_timelocked(
+    ctx: Context<WithdrawSecure>,
+    amount: u64,
+    delay: i64,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require_keys_eq!(
+        vault.authority,
+        ctx.accounts.authority.key(),
+        SecurityError::Unauthorized
+    );
+    require!(!vault.is_paused, ErrorCode::VaultPaused);
+    require!(!vault.is_frozen, ErrorCode::VaultFrozen);
+    
+    if vault.pending_amount == 0 {
+        // Request phase: record the withdrawal and start the timer
+        require_nonzero_amount(amount)?;
+        require!(delay >= 0, ErrorCode::InvalidDelay);
+        
+        vault.pending_amount = amount;
+        vault.unlock_timestamp = now
+            .checked_add(delay)
+            .ok_or(SecurityError::MathOverflow)?;
+        
+        msg!(
+            "Withdrawal of {} lamports requested, unlocks at {}",
+            amount,
+            vault.unlock_timestamp
+        );
+        return Ok(());
+    }
+    
+    // Execute phase: the timelock must have expired
+    require!(
+        now >= vault.unlock_timestamp,
+        ErrorCode::TimelockNotExpired
+    );
+    
+    let amount = vault.pending_amount;
+    require!(
+        vault.to_account_info().lamports() >= amount,
+        SecurityError::InsufficientFunds
+    );
+    require!(
+        vault.can_withdraw(amount),
+        ErrorCode::WithdrawalLimitExceeded
+    );
+    
+    checked_transfer_lamports(
+        &vault.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        amount,
+    )?;
     
     vault.total_withdrawn = vault.total_withdrawn.checked_add(amount)
-        .ok_or(ErrorCode::MathOverflow)?;
+        .ok_or(SecurityError::MathOverflow)?;
+    vault.pending_amount = 0;
+    vault.unlock_timestamp = 0;
+    
+    emit!(WithdrawEvent {
+        vault: vault.key(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+        total_withdrawn: vault.total_withdrawn,
+    });
+    
+    msg!("Time-locked withdrawal of {} lamports executed", amount);
     
     Ok(())
 }
 
+/// Rate-limited withdrawal: at most `window_limit` lamports per 24 hours
+///
+/// USE CASE: Treasuries that want "X lamports per day" rather than a single
+/// lifetime cap. Even a compromised authority key can only drain one
+/// window's worth before someone notices.
+pub fn withdraw_rate_limited(ctx: Context<WithdrawSecure>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    let vault = &mut ctx.accounts.vault;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require_keys_eq!(
+        vault.authority,
+        ctx.accounts.authority.key(),
+        SecurityError::Unauthorized
+    );
+    require!(!vault.is_paused, ErrorCode::VaultPaused);
+    require!(!vault.is_frozen, ErrorCode::VaultFrozen);
+    
+    // Roll the window over once it has fully elapsed
+    let window_end = vault.window_start
+        .checked_add(Vault::RATE_LIMIT_WINDOW)
+        .ok_or(SecurityError::MathOverflow)?;
+    if now >= window_end {
+        vault.window_start = now;
+        vault.withdrawn_in_window = 0;
+    }
+    
+    let withdrawn_in_window = vault.withdrawn_in_window
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    require!(
+        vault.window_limit == 0 || withdrawn_in_window <= vault.window_limit,
+        ErrorCode::RateLimitExceeded
+    );
+    
+    require!(
+        vault.to_account_info().lamports() >= amount,
+        SecurityError::InsufficientFunds
+    );
+    require!(
+        vault.can_withdraw(amount),
+        ErrorCode::WithdrawalLimitExceeded
+    );
+    
+    checked_transfer_lamports(
+        &vault.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        amount,
+    )?;
+    
+    vault.withdrawn_in_window = withdrawn_in_window;
+    vault.total_withdrawn = vault.total_withdrawn.checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    emit!(WithdrawEvent {
+        vault: vault.key(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+        total_withdrawn: vault.total_withdrawn,
+    });
+    
+    msg!(
+        "Rate-limited withdrawal of {} lamports ({} this window)",
+        amount,
+        vault.withdrawn_in_window
+    );
+    
+    Ok(())
+}
+
+/// Batch withdrawal: pay several recipients in one instruction
+///
+/// USE CASE: Payroll-style disbursement. `amounts[i]` goes to
+/// `remaining_accounts[i]`. Every transfer runs the same checks as
+/// `withdraw_secure`, against the running total. If any one of them fails,
+/// the error aborts the whole transaction and the runtime rolls back the
+/// transfers already made - recipients are paid all together or not at all.
+pub fn withdraw_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawSecure<'info>>,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    
+    require_keys_eq!(
+        vault.authority,
+        ctx.accounts.authority.key(),
+        SecurityError::Unauthorized
+    );
+    require!(!vault.is_paused, ErrorCode::VaultPaused);
+    require!(!vault.is_frozen, ErrorCode::VaultFrozen);
+    
+    // ✅ Every amount needs exactly one recipient
+    require!(
+        !amounts.is_empty() && amounts.len() == ctx.remaining_accounts.len(),
+        ErrorCode::BatchLengthMismatch
+    );
+    
+    let mut batch_total: u64 = 0;
+    for (recipient, amount) in ctx.remaining_accounts.iter().zip(amounts) {
+        require_nonzero_amount(amount)?;
+        
+        // ⚠️ Paying the vault itself would inflate total_withdrawn for free
+        require!(recipient.key() != vault.key(), ErrorCode::InvalidRecipient);
+        
+        require!(
+            vault.to_account_info().lamports() >= amount,
+            SecurityError::InsufficientFunds
+        );
+        require!(
+            vault.can_withdraw(amount),
+            ErrorCode::WithdrawalLimitExceeded
+        );
+        
+        checked_transfer_lamports(&vault.to_account_info(), recipient, amount)?;
+        
+        // ✅ Accumulate as we go so the limit check above sees earlier payouts
+        vault.total_withdrawn = vault.total_withdrawn.checked_add(amount)
+            .ok_or(SecurityError::MathOverflow)?;
+        batch_total = batch_total.checked_add(amount)
+            .ok_or(SecurityError::MathOverflow)?;
+    }
+    
+    emit!(WithdrawEvent {
+        vault: vault.key(),
+        authority: ctx.accounts.authority.key(),
+        amount: batch_total,
+        total_withdrawn: vault.total_withdrawn,
+    });
+    
+    msg!(
+        "Batch withdrawal of {} lamports to {} recipients",
+        batch_total,
+        ctx.remaining_accounts.len()
+    );
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    /// ✅ Depositor must sign for the System Program to debit them
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+    
+    pub system_program: Program<'info, System>,
+}
+
 /// Secure account validation using Signer type
 ///
 /// BEST PRACTICE: Use Signer<'info> type which enforces signature at compile time
 #[derive(Accounts)]
 pub struct WithdrawSecure<'info> {
-    #[account(mut)]
+    /// ✅ Re-derive the PDA instead of trusting whatever vault was passed in
+    ///
+    /// `Account<'info, Vault>` alone only proves the account is *a* Vault owned
+    /// by this program. Re-deriving from `[b"vault", creator]` with the stored
+    /// bump proves it sits at the address `initialize_vault_secure` gave it,
+    /// so an account whose fields were never written by that instruction
+    /// can't stand in for a real vault.
+    ///
+    /// The seeds use `creator`, which never changes, rather than `authority`,
+    /// which `accept_authority` rotates. Who may withdraw is a separate
+    /// question, answered by `has_one` - so a vault keeps working after its
+    /// authority is handed over.
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        has_one = authority @ SecurityError::Unauthorized
+    )]
     pub vault: Account<'info, Vault>,
     
     /// ✅ SOLUTION 1: Using Signer<'info> type
@@ -111,7 +442,13 @@ pub struct WithdrawSecure<'info> {
 /// BEST PRACTICE: Use #[account(signer)] constraint when using AccountInfo
 #[derive(Accounts)]
 pub struct WithdrawManual<'info> {
-    #[account(mut)]
+    /// ✅ Same PDA re-derivation as `WithdrawSecure`; the authority is
+    /// compared by hand in the handler
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump
+    )]
     pub vault: Account<'info, Vault>,
     
     /// ✅ SOLUTION 2: Using #[account(signer)] constraint
@@ -127,6 +464,7 @@ pub struct WithdrawManual<'info> {
 
 /// Secure vault account structure with additional safety features
 #[account]
+#[derive(InitSpace)]
 pub struct Vault {
     /// The authority that can withdraw from this vault
     pub authority: Pubkey,
@@ -139,11 +477,66 @@ pub struct Vault {
     
     /// Optional: Add a bump for PDA derivation if needed
     pub bump: u8,
+    
+    /// Authority proposed by the current authority, awaiting acceptance
+    pub pending_authority: Option<Pubkey>,
+    
+    /// Unix timestamp after which the pending withdrawal may execute
+    pub unlock_timestamp: i64,
+    
+    /// Amount requested through `withdraw_timelocked` (0 = none pending)
+    pub pending_amount: u64,
+    
+    /// Start of the current rate-limit window (unix timestamp)
+    pub window_start: i64,
+    
+    /// Lamports withdrawn through `withdraw_rate_limited` in the current window
+    pub withdrawn_in_window: u64,
+    
+    /// Per-window cap for `withdraw_rate_limited` (0 = no cap)
+    pub window_limit: u64,
+    
+    /// Track total deposited through the `deposit` instruction
+    pub total_deposited: u64,
+    
+    /// Emergency stop set via `set_pause`: while true, every withdrawal path fails
+    pub is_paused: bool,
+    
+    /// Admin assigns roles, operator pauses, guardian freezes - none of
+    /// them can withdraw (see `set_role`)
+    pub roles: Roles,
+    
+    /// Set by the guardian: while true, every withdrawal path fails
+    pub is_frozen: bool,
+    
+    /// Key that created the vault and seeds its PDA. Unlike `authority`,
+    /// it never changes.
+    pub creator: Pubkey,
+}
+
+/// `deposit` credits `total_deposited` through the shared checked helpers
+impl Balance for Vault {
+    fn balance(&self) -> u64 {
+        self.total_deposited
+    }
+    
+    fn balance_mut(&mut self) -> &mut u64 {
+        &mut self.total_deposited
+    }
 }
 
 impl Vault {
-    /// Space calculation: 8 + 32 + 8 + 8 + 1 = 57 bytes
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 1;
+    /// Space calculation: discriminator + derived field sizes (268 bytes)
+    ///
+    /// MIGRATION: Vaults created by earlier versions were allocated fewer
+    /// bytes (57 before `pending_authority` was added, 138 before
+    /// `is_paused`, 139 before the guardian fields, 172 before `guardian`
+    /// became `roles`, 236 before `creator`). They must be
+    /// reallocated to `Vault::LEN` before this version can deserialize them.
+    pub const LEN: usize = space_of!(Vault);
+    
+    /// Length of a rate-limit window in seconds (24 hours)
+    pub const RATE_LIMIT_WINDOW: i64 = 24 * 60 * 60;
     
     /// Initialize a new vault with security defaults
     pub fn new(authority: Pubkey, withdrawal_limit: u64, bump: u8) -> Self {
@@ -152,6 +545,21 @@ impl Vault {
             total_withdrawn: 0,
             withdrawal_limit,
             bump,
+            pending_authority: None,
+            unlock_timestamp: 0,
+            pending_amount: 0,
+            window_start: 0,
+            withdrawn_in_window: 0,
+            window_limit: 0,
+            total_deposited: 0,
+            is_paused: false,
+            roles: Roles {
+                admin: authority,
+                operator: authority,
+                guardian: Pubkey::default(),
+            },
+            is_frozen: false,
+            creator: authority,
         }
     }
     
@@ -165,23 +573,85 @@ impl Vault {
     }
 }
 
+/// Emitted on every successful withdrawal so indexers don't have to parse `msg!` output
+#[event]
+pub struct WithdrawEvent {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+}
+
+/// Emitted when a pending authority accepts control of the vault
+#[event]
+pub struct AuthorityChanged {
+    pub vault: Pubkey,
+    pub old: Pubkey,
+    pub new: Pubkey,
+}
+
 /// Enhanced error codes for secure implementation
 #[error_code]
 pub enum ErrorCode {
-    #[msg("Unauthorized: Authority mismatch")]
-    Unauthorized,
-    
     #[msg("Missing required signature")]
-    MissingSigner,
-    
-    #[msg("Insufficient funds in vault")]
-    InsufficientFunds,
+    MissingSigner = 1,
     
     #[msg("Withdrawal would exceed limit")]
-    WithdrawalLimitExceeded,
+    WithdrawalLimitExceeded = 3,
+    
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority = 5,
+    
+    #[msg("Signer is not the pending authority")]
+    InvalidPendingAuthority,
+    
+    #[msg("Time-locked withdrawal is not yet unlocked")]
+    TimelockNotExpired,
+    
+    #[msg("Timelock delay cannot be negative")]
+    InvalidDelay,
+    
+    #[msg("Cannot close vault while a withdrawal is pending")]
+    PendingWithdrawal,
+    
+    #[msg("Vault accounting is inconsistent")]
+    InconsistentAccounting,
+    
+    #[msg("Threshold must be between 1 and the number of signers")]
+    InvalidThreshold,
+    
+    #[msg("Not enough multisig signers approved this withdrawal")]
+    ThresholdNotMet,
+    
+    #[msg("Signer account supplied more than once")]
+    DuplicateSigner,
+    
+    #[msg("Withdrawal would exceed the rate limit for this window")]
+    RateLimitExceeded,
+    
+    #[msg("Vault is paused")]
+    VaultPaused,
+    
+    #[msg("Vault is frozen by its guardian")]
+    VaultFrozen,
     
-    #[msg("Math overflow in calculation")]
-    MathOverflow,
+    #[msg("Guardian must be a different key than the authority")]
+    GuardianIsAuthority,
+    
+    #[msg("Batch needs exactly one recipient account per amount")]
+    BatchLengthMismatch,
+    
+    #[msg("The vault cannot pay itself")]
+    InvalidRecipient,
+    
+    #[msg("Invariant violated: total withdrawn exceeds the withdrawal limit")]
+    WithdrawalLimitBreached,
+    
+    #[msg("Invariant violated: vault lamports do not cover its accounted balance")]
+    UnbackedBalance,
+    
+    #[msg("Invariant violated: vault address does not derive from its authority and bump")]
+    InvalidVaultDerivation,
 }
 
 // ============================================================================
@@ -203,6 +673,21 @@ pub fn initialize_vault_secure(
     vault.total_withdrawn = 0;
     vault.withdrawal_limit = withdrawal_limit;
     vault.bump = ctx.bumps.vault; // Store PDA bump if using PDA
+    vault.pending_authority = None;
+    vault.unlock_timestamp = 0;
+    vault.pending_amount = 0;
+    vault.window_start = 0;
+    vault.withdrawn_in_window = 0;
+    vault.window_limit = 0;
+    vault.total_deposited = 0;
+    vault.is_paused = false;
+    vault.roles = Roles {
+        admin: vault.authority,
+        operator: vault.authority,
+        guardian: Pubkey::default(),
+    };
+    vault.is_frozen = false;
+    vault.creator = vault.authority;
     
     msg!("Vault initialized with authority: {}", vault.authority);
     
@@ -228,32 +713,58 @@ pub struct InitializeVault<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// Example: Updating vault authority securely
+/// Example: Two-step authority transfer (step 1 - propose)
 ///
-/// Demonstrates proper authority transfer with signature checks
-pub fn update_authority(
-    ctx: Context<UpdateAuthority>,
+/// Overwriting the authority in a single instruction means a typo'd pubkey
+/// permanently bricks the vault. Instead, the current authority only
+/// *proposes* a successor, which must prove it controls the key by signing
+/// `accept_authority`. Proposing again overwrites any stale pending key.
+pub fn propose_authority(
+    ctx: Context<ProposeAuthority>,
     new_authority: Pubkey,
 ) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
     
     // ✅ At this point we know:
-    // 1. current_authority is a Signer (signed the transaction)
-    // 2. current_authority.key() matches vault.authority (from constraint)
+    // 1. authority is a Signer (signed the transaction)
+    // 2. authority.key() matches vault.authority (from has_one constraint)
+    vault.pending_authority = Some(new_authority);
+    
+    msg!("Authority transfer proposed to {}", new_authority);
+    
+    Ok(())
+}
+
+/// Example: Two-step authority transfer (step 2 - accept)
+///
+/// Only the pending authority can finalize the transfer, so the new key is
+/// guaranteed to be one somebody actually holds.
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
     
     let old_authority = vault.authority;
-    vault.authority = new_authority;
+    vault.authority = ctx.accounts.new_authority.key();
+    vault.pending_authority = None;
+    
+    // ✅ Roles held by the retiring key move with the authority
+    vault.roles.rotate_key(old_authority, ctx.accounts.new_authority.key());
+    
+    emit!(AuthorityChanged {
+        vault: vault.key(),
+        old: old_authority,
+        new: vault.authority,
+    });
     
-    msg!("Authority updated from {} to {}", old_authority, new_authority);
+    msg!("Authority updated from {} to {}", old_authority, vault.authority);
     
     Ok(())
 }
 
 #[derive(Accounts)]
-pub struct UpdateAuthority<'info> {
+pub struct ProposeAuthority<'info> {
     #[account(
         mut,
-        has_one = authority @ ErrorCode::Unauthorized
+        has_one = authority @ SecurityError::Unauthorized
     )]
     pub vault: Account<'info, Vault>,
     
@@ -263,3 +774,383 @@ pub struct UpdateAuthority<'info> {
     /// 2. The signing account matches the vault's stored authority
     pub authority: Signer<'info>,
 }
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        constraint = vault.pending_authority.is_some() @ ErrorCode::NoPendingAuthority,
+        constraint = vault.pending_authority == Some(new_authority.key())
+            @ ErrorCode::InvalidPendingAuthority
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    /// ✅ The proposed authority must sign to prove it controls the key
+    pub new_authority: Signer<'info>,
+}
+
+/// Example: Closing a vault and reclaiming rent
+///
+/// `close_account` zeroes the account data, assigns it back to the System
+/// Program and sends every remaining lamport (rent plus any undrained
+/// balance) to the authority.
+pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    
+    // ✅ Refuse to close while the vault's books don't add up
+    require!(
+        vault.withdrawal_limit == 0 || vault.total_withdrawn <= vault.withdrawal_limit,
+        ErrorCode::InconsistentAccounting
+    );
+    
+    // ✅ A requested time-locked withdrawal must be executed first
+    require!(vault.pending_amount == 0, ErrorCode::PendingWithdrawal);
+    
+    close_account(&vault.to_account_info(), &ctx.accounts.authority)?;
+    
+    msg!("Vault closed, rent returned to {}", vault.authority);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    /// ✅ has_one ties the vault to the signer, who gets the rent back
+    #[account(mut, has_one = authority @ SecurityError::Unauthorized)]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+// ============================================================================
+// MULTISIG AUTHORITY
+// ============================================================================
+
+/// Example: Creating an M-of-3 multisig vault
+///
+/// A single authority key is a single point of failure. Here withdrawals need
+/// `threshold` of the configured `signers` to sign the same transaction.
+pub fn initialize_multisig_vault(
+    ctx: Context<InitializeMultisigVault>,
+    signers: [Pubkey; 3],
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        threshold >= 1 && threshold as usize <= signers.len(),
+        ErrorCode::InvalidThreshold
+    );
+    
+    // Duplicate owners would let one key satisfy the threshold twice
+    for i in 0..signers.len() {
+        for j in (i + 1)..signers.len() {
+            require!(signers[i] != signers[j], ErrorCode::DuplicateSigner);
+        }
+    }
+    
+    let multisig_vault = &mut ctx.accounts.multisig_vault;
+    multisig_vault.signers = signers;
+    multisig_vault.threshold = threshold;
+    multisig_vault.bump = ctx.bumps.multisig_vault;
+    
+    msg!("Multisig vault initialized with threshold {}", threshold);
+    
+    Ok(())
+}
+
+/// Example: Withdrawing with M-of-N signatures
+///
+/// Signers are passed through `remaining_accounts`. An account only counts
+/// toward the threshold if:
+/// 1. It is one of the configured signers
+/// 2. It actually signed the transaction (`is_signer == true`)
+/// 3. It hasn't already been counted (duplicates are rejected outright)
+///
+/// See `MultisigVault::count_approvals` - the order of the accounts never
+/// changes the outcome.
+pub fn withdraw_multisig<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawMultisig<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    let multisig_vault = &ctx.accounts.multisig_vault;
+    
+    let approvals = multisig_vault.count_approvals(
+        ctx.remaining_accounts
+            .iter()
+            .map(|account| (account.key, account.is_signer)),
+    )?;
+    require!(
+        approvals >= multisig_vault.threshold as usize,
+        ErrorCode::ThresholdNotMet
+    );
+    
+    require!(
+        multisig_vault.to_account_info().lamports() >= amount,
+        SecurityError::InsufficientFunds
+    );
+    
+    checked_transfer_lamports(
+        &multisig_vault.to_account_info(),
+        &ctx.accounts.recipient.to_account_info(),
+        amount,
+    )?;
+    
+    msg!(
+        "Multisig withdrawal of {} lamports approved by {} signers",
+        amount,
+        approvals
+    );
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeMultisigVault<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = MultisigVault::LEN,
+        seeds = [b"multisig", creator.key().as_ref()],
+        bump
+    )]
+    pub multisig_vault: Account<'info, MultisigVault>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawMultisig<'info> {
+    #[account(mut)]
+    pub multisig_vault: Account<'info, MultisigVault>,
+    
+    /// CHECK: Destination only receives lamports; the multisig approves it
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
+}
+
+/// Vault controlled by `threshold` of three signer keys
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigVault {
+    /// Keys allowed to approve withdrawals
+    pub signers: [Pubkey; 3],
+    
+    /// Number of distinct signatures required
+    pub threshold: u8,
+    
+    pub bump: u8,
+}
+
+impl MultisigVault {
+    /// Space calculation: discriminator + derived field sizes (106 bytes)
+    pub const LEN: usize = space_of!(MultisigVault);
+    
+    /// Count the distinct configured signers among `accounts` that signed
+    ///
+    /// Every `(key, is_signer)` pair is matched against `signers` by pubkey,
+    /// so the count depends only on *which* members signed, never on the
+    /// order the accounts arrive in:
+    /// - a key outside the signer set is ignored, even if it signed
+    /// - a member that didn't sign is ignored
+    /// - a member that signed counts once; passing it again fails with
+    ///   `DuplicateSigner` wherever the repeat appears
+    pub fn count_approvals<'a>(
+        &self,
+        accounts: impl IntoIterator<Item = (&'a Pubkey, bool)>,
+    ) -> Result<usize> {
+        let mut approved: Vec<&Pubkey> = Vec::with_capacity(self.signers.len());
+        
+        for (key, is_signer) in accounts {
+            // ✅ Membership: only configured keys can approve
+            if !self.signers.contains(key) {
+                continue;
+            }
+            
+            // ✅ Presence is not approval - the key must have signed
+            if !is_signer {
+                continue;
+            }
+            
+            // ✅ Dedup by pubkey - the same signer passed twice must not count twice
+            require!(!approved.contains(&key), ErrorCode::DuplicateSigner);
+            approved.push(key);
+        }
+        
+        Ok(approved.len())
+    }
+}
+
+// ============================================================================
+// VAULT CONFIGURATION
+// ============================================================================
+
+/// Example: Setting the per-window cap used by `withdraw_rate_limited`
+pub fn set_window_limit(ctx: Context<ConfigureVault>, window_limit: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.window_limit = window_limit;
+    
+    msg!("Rate limit set to {} lamports per window", window_limit);
+    
+    Ok(())
+}
+
+/// Example: Emergency pause (kill switch)
+///
+/// If the operator suspects something is wrong - a leaked key, a bug in a
+/// withdrawal path - it can halt every withdrawal at once and investigate.
+/// Deposits keep working; unpausing restores normal operation.
+pub fn set_pause(ctx: Context<OperateVault>, paused: bool) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    
+    // ✅ Shared role check: signed, and the vault's current operator
+    require_role(&vault.roles, &ctx.accounts.operator, Role::Operator)?;
+    vault.is_paused = paused;
+    
+    msg!("Vault {}", if paused { "paused" } else { "unpaused" });
+    
+    Ok(())
+}
+
+/// Authority-only configuration changes
+#[derive(Accounts)]
+pub struct ConfigureVault<'info> {
+    #[account(
+        mut,
+        has_one = authority @ SecurityError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// Operator-only switches
+#[derive(Accounts)]
+pub struct OperateVault<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+    
+    /// Checked against `vault.roles` in the handler
+    pub operator: Signer<'info>,
+}
+
+// ============================================================================
+// ROLES (ROLE SEPARATION)
+// ============================================================================
+
+/// Example: Appointing a role holder
+///
+/// A new vault starts with the authority as admin and operator and no
+/// guardian. The guardian is a second key - a security team, a monitoring
+/// bot - that can halt the vault but has no power to move funds. Keeping it
+/// distinct from the authority is the whole point: a stolen authority key
+/// can't unfreeze, and a stolen guardian key can't withdraw.
+pub fn set_role(ctx: Context<ManageRoles>, role: Role, holder: Pubkey) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    
+    require_role(&vault.roles, &ctx.accounts.admin, Role::Admin)?;
+    if role == Role::Guardian {
+        require_keys_neq!(holder, vault.authority, ErrorCode::GuardianIsAuthority);
+    }
+    vault.roles.assign(role, holder);
+    
+    msg!("{:?} set to {}", role, holder);
+    
+    Ok(())
+}
+
+/// Admin-only role changes
+#[derive(Accounts)]
+pub struct ManageRoles<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+    
+    /// Checked against `vault.roles` in the handler
+    pub admin: Signer<'info>,
+}
+
+/// Example: Guardian freezes the vault
+pub fn freeze(ctx: Context<GuardVault>) -> Result<()> {
+    require_role(&ctx.accounts.vault.roles, &ctx.accounts.guardian, Role::Guardian)?;
+    ctx.accounts.vault.is_frozen = true;
+    
+    msg!("Vault frozen by guardian {}", ctx.accounts.guardian.key());
+    
+    Ok(())
+}
+
+/// Example: Guardian lifts the freeze
+pub fn unfreeze(ctx: Context<GuardVault>) -> Result<()> {
+    require_role(&ctx.accounts.vault.roles, &ctx.accounts.guardian, Role::Guardian)?;
+    ctx.accounts.vault.is_frozen = false;
+    
+    msg!("Vault unfrozen by guardian {}", ctx.accounts.guardian.key());
+    
+    Ok(())
+}
+
+/// Guardian-only actions: the authority is deliberately NOT accepted here
+#[derive(Accounts)]
+pub struct GuardVault<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+    
+    /// Checked against `vault.roles` in the handler
+    pub guardian: Signer<'info>,
+}
+
+// ============================================================================
+// INVARIANT CHECKS
+// ============================================================================
+
+/// Example: Read-only audit of the vault's books
+///
+/// Takes no signer and writes nothing, so auditors and monitoring bots can
+/// call (or simulate) it at any time. Each invariant fails with its own
+/// error, so a violation names itself in the logs:
+///
+/// 1. `total_withdrawn <= withdrawal_limit` whenever a limit is set
+/// 2. Lamports above the rent-exempt minimum cover every deposit not yet
+///    withdrawn (direct transfers may add more, never less)
+/// 3. `[b"vault", creator]` with the stored `bump` derives this address,
+///    and the bump is the canonical one
+///
+/// (3) is the same binding `WithdrawSecure` relies on. It holds across
+/// authority transfers, since `creator` never changes.
+pub fn check_invariants(ctx: Context<CheckInvariants>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let vault_info = vault.to_account_info();
+    
+    require!(
+        vault.withdrawal_limit == 0 || vault.total_withdrawn <= vault.withdrawal_limit,
+        ErrorCode::WithdrawalLimitBreached
+    );
+    
+    let rent_exempt = Rent::get()?.minimum_balance(vault_info.data_len());
+    let spendable = vault_info.lamports().saturating_sub(rent_exempt);
+    let outstanding = vault.total_deposited.saturating_sub(vault.total_withdrawn);
+    require!(outstanding <= spendable, ErrorCode::UnbackedBalance);
+    
+    let (expected, canonical_bump) = Pubkey::find_program_address(
+        &[b"vault", vault.creator.as_ref()],
+        ctx.program_id,
+    );
+    require!(
+        expected == vault.key() && canonical_bump == vault.bump,
+        ErrorCode::InvalidVaultDerivation
+    );
+    
+    msg!("All vault invariants hold");
+    
+    Ok(())
+}
+
+/// Any vault, no signer: the checks themselves are the point
+#[derive(Accounts)]
+pub struct CheckInvariants<'info> {
+    pub vault: Account<'info, Vault>,
+}
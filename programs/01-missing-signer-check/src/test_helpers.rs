@@ -0,0 +1,84 @@
+// 🧪 TEST HELPERS - compiled only with `--features test-helpers`
+//
+// Fuzzers and integration tests often need a vault in an edge state (one
+// lamport under its limit, say) that would take dozens of transactions to
+// reach honestly. These instructions jump straight there.
+//
+// ⚠️ `force_set_state` rewrites the vault's books. The feature is off by
+// default so a normal `anchor build` can never ship it.
+use anchor_lang::prelude::*;
+use security_common::SecurityError;
+use crate::secure::Vault;
+
+/// Emit every accounting field of the vault as a `VaultSnapshot` event
+pub fn snapshot_state(ctx: Context<SnapshotState>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    
+    emit!(VaultSnapshot {
+        vault: vault.key(),
+        authority: vault.authority,
+        lamports: vault.to_account_info().lamports(),
+        total_deposited: vault.total_deposited,
+        total_withdrawn: vault.total_withdrawn,
+        withdrawal_limit: vault.withdrawal_limit,
+        pending_amount: vault.pending_amount,
+        window_start: vault.window_start,
+        withdrawn_in_window: vault.withdrawn_in_window,
+        window_limit: vault.window_limit,
+    });
+    
+    Ok(())
+}
+
+/// Overwrite `total_withdrawn` and `withdrawal_limit` directly
+///
+/// Still authority-only: a test build should not hand every caller the
+/// keys to the books either.
+pub fn force_set_state(
+    ctx: Context<ForceSetState>,
+    total_withdrawn: u64,
+    withdrawal_limit: u64,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.total_withdrawn = total_withdrawn;
+    vault.withdrawal_limit = withdrawal_limit;
+    
+    msg!(
+        "⚠️ TEST HELPER: total_withdrawn = {}, withdrawal_limit = {}",
+        total_withdrawn,
+        withdrawal_limit
+    );
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SnapshotState<'info> {
+    pub vault: Account<'info, Vault>,
+}
+
+#[derive(Accounts)]
+pub struct ForceSetState<'info> {
+    #[account(
+        mut,
+        has_one = authority @ SecurityError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// Full accounting state of a vault at one point in time
+#[event]
+pub struct VaultSnapshot {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub lamports: u64,
+    pub total_deposited: u64,
+    pub total_withdrawn: u64,
+    pub withdrawal_limit: u64,
+    pub pending_amount: u64,
+    pub window_start: i64,
+    pub withdrawn_in_window: u64,
+    pub window_limit: u64,
+}
@@ -2,6 +2,7 @@
 // This module demonstrates a CRITICAL missing signer check vulnerability
 
 use anchor_lang::prelude::*;
+use security_common::{space_of, SecurityError};
 
 /// Vulnerable withdraw function that lacks proper signer verification
 ///
@@ -23,13 +24,13 @@ pub fn withdraw_insecure(ctx: Context<WithdrawInsecure>, amount: u64) -> Result<
     // ⚠️ CRITICAL FLAW: This only checks if the pubkeys match
     // It does NOT verify that the authority account signed this transaction
     if vault.authority != ctx.accounts.authority.key() {
-        return Err(ErrorCode::Unauthorized.into());
+        return Err(SecurityError::Unauthorized.into());
     }
     
     // Check if vault has sufficient balance
     let vault_lamports = ctx.accounts.vault.to_account_info().lamports();
     if vault_lamports < amount {
-        return Err(ErrorCode::InsufficientFunds.into());
+        return Err(SecurityError::InsufficientFunds.into());
     }
     
     // ⚠️ DANGER: Transfer executes without verifying authority's signature
@@ -62,6 +63,7 @@ pub struct WithdrawInsecure<'info> {
 
 /// Vault account structure
 #[account]
+#[derive(InitSpace)]
 pub struct Vault {
     /// The authority that can withdraw from this vault
     pub authority: Pubkey,
@@ -70,16 +72,6 @@ pub struct Vault {
 }
 
 impl Vault {
-    /// Space calculation for account allocation
-    /// 8 (discriminator) + 32 (authority) + 8 (total_withdrawn)
-    pub const LEN: usize = 8 + 32 + 8;
-}
-
-/// Custom error codes for the vulnerable program
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Unauthorized: Authority mismatch")]
-    Unauthorized,
-    #[msg("Insufficient funds in vault")]
-    InsufficientFunds,
+    /// Space calculation: discriminator + derived field sizes (48 bytes)
+    pub const LEN: usize = space_of!(Vault);
 }
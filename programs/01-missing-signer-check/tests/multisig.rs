@@ -0,0 +1,124 @@
+// Signer-order independence for withdraw_multisig
+//
+// `MultisigVault::count_approvals` is the whole approval check, taking the
+// `(key, is_signer)` pairs that `remaining_accounts` would supply. Running
+// every permutation of the same accounts through it must give the same
+// count (or the same error) every time.
+use anchor_lang::prelude::*;
+use missing_signer_check::secure::{ErrorCode, MultisigVault};
+
+fn vault(signers: [Pubkey; 3]) -> MultisigVault {
+    MultisigVault {
+        signers,
+        threshold: 2,
+        bump: 0,
+    }
+}
+
+fn members() -> [Pubkey; 3] {
+    [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ]
+}
+
+/// Every ordering of `items` (Heap's algorithm)
+fn permutations<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    fn heap<T: Clone>(k: usize, items: &mut Vec<T>, out: &mut Vec<Vec<T>>) {
+        if k <= 1 {
+            out.push(items.clone());
+            return;
+        }
+        for i in 0..k {
+            heap(k - 1, items, out);
+            let swap = if k.is_multiple_of(2) { i } else { 0 };
+            items.swap(swap, k - 1);
+        }
+    }
+
+    let mut out = Vec::new();
+    heap(items.len(), &mut items.to_vec(), &mut out);
+    out
+}
+
+fn count(vault: &MultisigVault, accounts: &[(Pubkey, bool)]) -> Result<usize> {
+    vault.count_approvals(accounts.iter().map(|(key, signed)| (key, *signed)))
+}
+
+#[test]
+fn count_is_the_same_in_every_order() {
+    let signers = members();
+    let vault = vault(signers);
+    let outsider = Pubkey::new_unique();
+    let accounts = [
+        (signers[0], true),
+        (signers[1], false),
+        (signers[2], true),
+        (outsider, true),
+        (Pubkey::new_unique(), false),
+    ];
+
+    let orders = permutations(&accounts);
+    assert_eq!(orders.len(), 120);
+    for order in orders {
+        assert_eq!(count(&vault, &order).unwrap(), 2, "order {order:?}");
+    }
+}
+
+#[test]
+fn repeated_signer_is_rejected_in_every_order() {
+    let signers = members();
+    let vault = vault(signers);
+    let accounts = [
+        (signers[0], true),
+        (signers[0], true),
+        (signers[1], true),
+        (Pubkey::new_unique(), true),
+    ];
+
+    for order in permutations(&accounts) {
+        assert_eq!(
+            count(&vault, &order).unwrap_err(),
+            ErrorCode::DuplicateSigner.into(),
+            "order {order:?}"
+        );
+    }
+}
+
+#[test]
+fn non_member_signers_are_ignored() {
+    let vault = vault(members());
+    let outsiders: Vec<_> = (0..3).map(|_| (Pubkey::new_unique(), true)).collect();
+
+    for order in permutations(&outsiders) {
+        assert_eq!(count(&vault, &order).unwrap(), 0);
+    }
+}
+
+#[test]
+fn repeating_a_non_member_or_unsigned_member_never_counts() {
+    let signers = members();
+    let vault = vault(signers);
+    let outsider = Pubkey::new_unique();
+    let accounts = [
+        (signers[0], true),
+        (signers[1], false),
+        (signers[1], false),
+        (outsider, true),
+        (outsider, true),
+    ];
+
+    for order in permutations(&accounts) {
+        assert_eq!(count(&vault, &order).unwrap(), 1, "order {order:?}");
+    }
+}
+
+#[test]
+fn len_covers_the_serialized_account() {
+    let mut data = Vec::new();
+    vault(members()).try_serialize(&mut data).unwrap();
+
+    assert_eq!(MultisigVault::LEN, data.len());
+    assert_eq!(MultisigVault::LEN, 8 + 32 * 3 + 1 + 1);
+}
@@ -22,6 +22,36 @@ pub mod missing_owner_check {
         secure::process_payment_secure(ctx, amount)
     }
     
+    /// ✅ SECURE: Authority pays into their own vault, tied with has_one
+    pub fn process_payment_has_one(ctx: Context<PaymentHasOne>, amount: u64) -> Result<()> {
+        secure::process_payment_has_one(ctx, amount)
+    }
+    
+    /// ✅ SECURE: Process payment from a classic SPL or Token-2022 account
+    pub fn process_payment_token2022(ctx: Context<PaymentToken2022>, amount: u64) -> Result<()> {
+        secure::process_payment_token2022(ctx, amount)
+    }
+    
+    /// ✅ SECURE: Process payment only from the user's associated token account
+    pub fn process_payment_ata(ctx: Context<PaymentAta>, amount: u64) -> Result<()> {
+        secure::process_payment_ata(ctx, amount)
+    }
+    
+    /// ✅ SECURE: Create the vault that collects payments
+    pub fn initialize_payment_vault(ctx: Context<InitializePaymentVault>) -> Result<()> {
+        secure::initialize_payment_vault(ctx)
+    }
+    
+    /// ✅ SECURE: Authority returns collected tokens to a payer
+    pub fn refund(ctx: Context<Refund>, amount: u64) -> Result<()> {
+        secure::refund(ctx, amount)
+    }
+    
+    /// ✅ SECURE: Create the caller's payment receipt before paying
+    pub fn initialize_receipt(ctx: Context<InitializeReceipt>) -> Result<()> {
+        secure::initialize_receipt(ctx)
+    }
+    
     /// ✅ SECURE: Process payment with manual owner check
     pub fn process_payment_manual(ctx: Context<PaymentManual>, amount: u64) -> Result<()> {
         secure::process_payment_manual(ctx, amount)
@@ -1,6 +1,9 @@
 // ✅ SECURE IMPLEMENTATION
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, TokenInterface, TransferChecked};
+use spl_token::state::AccountState;
+use security_common::{space_of, SecurityError};
 
 /// SOLUTION: Using Anchor's Account<'info, TokenAccount> type
 /// 
@@ -24,23 +27,281 @@ pub fn process_payment_secure(ctx: Context<PaymentSecure>, amount: u64) -> Resul
         ErrorCode::InvalidTokenOwner
     );
     
+    // ✅ Move the tokens into the vault's token account
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.total_collected = vault.total_collected
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    // ✅ Credit the payer's own receipt - seeds tie it to the signer and vault
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.total_paid = receipt.total_paid
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
     msg!("Processing payment of {} tokens (SECURE)", amount);
     
     Ok(())
 }
 
+/// Authority-only payment: the signer is tied to the vault with `has_one`
+///
+/// `process_payment_secure` ties accounts together with `constraint`
+/// expressions. This variant ties the signer to the vault with
+/// `has_one = authority` instead. The two end up as the same pubkey
+/// comparison; they differ in what they can express:
+///
+/// - `has_one = authority` compares `vault.authority` against the key of the
+///   account field named `authority`. It only works when the stored field and
+///   the account field share a name, and it only checks equality.
+/// - `constraint = <expr>` takes any boolean expression. Use it when the
+///   names don't line up (`user_token_account.owner` vs `user`), when the
+///   data lives on an account you don't own the layout of, or when the
+///   check is more than equality.
+///
+/// Neither one proves anything about the signer on its own - pair either
+/// with `Signer<'info>` on the account being compared against.
+pub fn process_payment_has_one(ctx: Context<PaymentHasOne>, amount: u64) -> Result<()> {
+    // ✅ By now Anchor has checked vault.authority == authority.key() and
+    // that the authority signed - the body never needs to look at it again
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.total_collected = vault.total_collected
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    msg!("Processing authority payment of {} tokens (SECURE)", amount);
+    
+    Ok(())
+}
+
+/// Token-2022 aware payment using the token interface types
+///
+/// `InterfaceAccount<'info, token_interface::TokenAccount>` runs the same owner
+/// check as `Account<'info, TokenAccount>`, except the owner may be *either*
+/// `spl_token::ID` or `spl_token_2022::ID`. Any other owner is rejected before
+/// the instruction runs. `Interface<'info, TokenInterface>` does the same for
+/// the program account, so the CPI below can only ever reach one of the two
+/// real token programs.
+///
+/// Accepting two owners opens one new mismatch: a classic account passed with
+/// the Token-2022 program (or vice versa). The constraints on
+/// `PaymentToken2022` pin every token account to the program actually passed.
+pub fn process_payment_token2022(ctx: Context<PaymentToken2022>, amount: u64) -> Result<()> {
+    let token_account = &ctx.accounts.user_token_account;
+    
+    require!(
+        token_account.amount >= amount,
+        ErrorCode::InsufficientBalance
+    );
+    
+    // ✅ transfer_checked re-validates mint and decimals, which Token-2022
+    // requires for mints with extensions
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.total_collected = vault.total_collected
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    msg!("Processing payment of {} tokens (TOKEN INTERFACE)", amount);
+    
+    Ok(())
+}
+
+/// Payment that only accepts the user's canonical associated token account
+///
+/// `token::authority = user` accepts *any* token account the user owns -
+/// including one created at an arbitrary address. When the rest of the
+/// system (indexers, refunds, airdrops) assumes "the user's account for this
+/// mint" means the ATA, the program has to insist on exactly that address.
+/// `associated_token::*` re-derives it from the wallet and mint and rejects
+/// every other account.
+pub fn process_payment_ata(ctx: Context<PaymentAta>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.user_token_account.amount >= amount,
+        ErrorCode::InsufficientBalance
+    );
+    
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.total_collected = vault.total_collected
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    msg!("Processing payment of {} tokens (ATA ONLY)", amount);
+    
+    Ok(())
+}
+
+/// Create the vault that collects payments
+pub fn initialize_payment_vault(ctx: Context<InitializePaymentVault>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.authority = ctx.accounts.authority.key();
+    vault.total_collected = 0;
+    vault.expected_mint = ctx.accounts.mint.key();
+    
+    msg!("Payment vault initialized for {}", vault.authority);
+    
+    Ok(())
+}
+
+/// Send collected tokens back to a payer
+///
+/// Only the vault authority can refund: the vault PDA is re-derived from the
+/// signer's key and `has_one` ties it to that same key. Tokens leave the
+/// vault token account under the vault PDA's signature. The payer's receipt
+/// decides where they go and how much: only to a token account the payer
+/// owns, and never more than that payer has paid in.
+pub fn refund(ctx: Context<Refund>, amount: u64) -> Result<()> {
+    // ✅ Never hand a payer back more than they paid in - the vault total
+    // alone would let one payer's refund spend everyone else's payments
+    require!(
+        amount <= ctx.accounts.receipt.total_paid,
+        ErrorCode::RefundExceedsPaid
+    );
+    require!(
+        amount <= ctx.accounts.vault.total_collected,
+        ErrorCode::RefundExceedsCollected
+    );
+    
+    let authority_key = ctx.accounts.authority.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"payment_vault",
+        authority_key.as_ref(),
+        &[ctx.bumps.vault],
+    ]];
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.total_paid = receipt.total_paid
+        .checked_sub(amount)
+        .ok_or(ErrorCode::RefundExceedsPaid)?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.total_collected = vault.total_collected
+        .checked_sub(amount)
+        .ok_or(ErrorCode::RefundExceedsCollected)?;
+    
+    msg!("Refunded {} tokens to {}", amount, ctx.accounts.receipt.user);
+    
+    Ok(())
+}
+
+/// Create the caller's payment receipt (step one of paying)
+///
+/// Receipts are created here rather than with `init_if_needed` inside the
+/// payment. Folding creation into the payment lets anyone who can predict
+/// the PDA create it first, and makes every payment silently re-run account
+/// creation logic. As a separate step with the user as both signer and
+/// payer, only the user can ever create their receipt, and the payment
+/// only ever updates an account that already exists.
+pub fn initialize_receipt(ctx: Context<InitializeReceipt>) -> Result<()> {
+    ctx.accounts.receipt.set_inner(PaymentReceipt {
+        user: ctx.accounts.user.key(),
+        total_paid: 0,
+        bump: ctx.bumps.receipt,
+    });
+    
+    msg!("Payment receipt created for {}", ctx.accounts.user.key());
+    
+    Ok(())
+}
+
 /// Manual owner check approach (alternative)
+///
+/// PANIC RISK: Raw account data is attacker-sized. Indexing or slicing it
+/// (e.g. `data[64..72]`) on a buffer that is too short panics the program
+/// instead of returning an error, so the length is checked before any
+/// bytes are read.
 pub fn process_payment_manual(ctx: Context<PaymentManual>, amount: u64) -> Result<()> {
+    let account_info = &ctx.accounts.user_token_account;
+    
     // ✅ SOLUTION: Manual owner verification before using account
     require!(
-        ctx.accounts.user_token_account.owner == &spl_token::ID,
+        account_info.owner == &spl_token::ID,
         ErrorCode::InvalidOwner
     );
     
+    // ✅ Reject the default (all-zero) pubkey outright
+    require!(
+        account_info.key() != Pubkey::default(),
+        ErrorCode::InvalidTokenAccount
+    );
+    
+    // ✅ Guard the length before touching the bytes
+    let data = account_info.try_borrow_data()?;
+    require!(
+        data.len() >= TokenAccount::LEN,
+        ErrorCode::InvalidTokenAccount
+    );
+    
     // Now safe to deserialize
-    let token_account = TokenAccount::try_deserialize(
-        &mut &ctx.accounts.user_token_account.data.borrow()[..]
-    )?;
+    let token_account = TokenAccount::try_deserialize(&mut &data[..])?;
+    
+    // ✅ An uninitialized token account holds no meaningful balance
+    require!(
+        token_account.state != AccountState::Uninitialized,
+        ErrorCode::InvalidTokenAccount
+    );
     
     require!(
         token_account.amount >= amount,
@@ -57,15 +318,131 @@ pub struct PaymentSecure<'info> {
     
     /// ✅ SOLUTION: Account<'info, TokenAccount> enforces owner check
     /// Anchor verifies owner == Token Program automatically
+    /// ✅ The mint must match what the vault accepts - a real token account
+    /// of the wrong token is still the wrong payment
     #[account(
         mut,
-        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidTokenOwner
+        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidTokenOwner,
+        constraint = user_token_account.mint == vault.expected_mint @ ErrorCode::WrongMint
     )]
     pub user_token_account: Account<'info, TokenAccount>,
     
     #[account(mut)]
     pub vault: Account<'info, PaymentVault>,
     
+    /// ✅ Destination must be a real token account held by the vault
+    #[account(
+        mut,
+        constraint = vault_token_account.owner == vault.key() @ ErrorCode::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    /// ✅ The signer's own receipt for this vault - another user's PDA, or
+    /// their receipt for another vault, cannot match these seeds
+    #[account(
+        mut,
+        seeds = [b"receipt", vault.key().as_ref(), user.key().as_ref()],
+        bump = receipt.bump
+    )]
+    pub receipt: Account<'info, PaymentReceipt>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct PaymentHasOne<'info> {
+    pub authority: Signer<'info>,
+    
+    /// ✅ has_one: `vault.authority` must equal `authority.key()` - the stored
+    /// field and the account field share a name, so no expression is needed
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::InvalidVaultAuthority
+    )]
+    pub vault: Account<'info, PaymentVault>,
+    
+    /// ✅ constraint: the token account's `owner` field is compared against
+    /// the signer - has_one can't express this, because the token account's
+    /// layout belongs to the Token program, not to this struct's names
+    #[account(
+        mut,
+        constraint = user_token_account.owner == authority.key() @ ErrorCode::InvalidTokenOwner,
+        constraint = user_token_account.mint == vault.expected_mint @ ErrorCode::WrongMint
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    /// ✅ Destination must be a real token account held by the vault
+    #[account(
+        mut,
+        constraint = vault_token_account.owner == vault.key() @ ErrorCode::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct PaymentToken2022<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// ✅ Owner must be spl_token or spl_token_2022 (checked by InterfaceAccount)
+    #[account(
+        address = vault.expected_mint @ ErrorCode::WrongMint,
+        mint::token_program = token_program
+    )]
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+    
+    /// ✅ Same owner rule, plus pinned to the token program passed below
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, PaymentVault>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program
+    )]
+    pub vault_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+    
+    /// ✅ Only the classic Token program or Token-2022 is accepted
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct PaymentAta<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(address = vault.expected_mint @ ErrorCode::WrongMint)]
+    pub mint: Account<'info, Mint>,
+    
+    /// ✅ Must sit at the ATA address derived from (user, mint) - any other
+    /// token account the user owns is rejected
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, PaymentVault>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.owner == vault.key() @ ErrorCode::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
     pub token_program: Program<'info, Token>,
 }
 
@@ -82,14 +459,111 @@ pub struct PaymentManual<'info> {
     pub vault: Account<'info, PaymentVault>,
 }
 
+#[derive(Accounts)]
+pub struct InitializePaymentVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = PaymentVault::LEN,
+        seeds = [b"payment_vault", authority.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, PaymentVault>,
+    
+    /// The only token this vault will accept payments in (classic or Token-2022)
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Refund<'info> {
+    pub authority: Signer<'info>,
+    
+    /// ✅ Vault must be this authority's PDA and record it as authority
+    #[account(
+        mut,
+        seeds = [b"payment_vault", authority.key().as_ref()],
+        bump,
+        has_one = authority @ SecurityError::Unauthorized
+    )]
+    pub vault: Account<'info, PaymentVault>,
+    
+    /// ✅ Source must be a real token account held by the vault
+    #[account(
+        mut,
+        constraint = vault_token_account.owner == vault.key() @ ErrorCode::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    /// ✅ The payer's receipt for THIS vault - a real receipt PDA, so
+    /// `total_paid` is what they paid in here, not into some other vault
+    #[account(
+        mut,
+        seeds = [b"receipt", vault.key().as_ref(), receipt.user.as_ref()],
+        bump = receipt.bump
+    )]
+    pub receipt: Account<'info, PaymentReceipt>,
+    
+    /// ✅ Refunds go back to the payer, in the vault's own mint
+    #[account(
+        mut,
+        constraint = user_token_account.owner == receipt.user @ ErrorCode::InvalidTokenOwner,
+        constraint = user_token_account.mint == vault.expected_mint @ ErrorCode::WrongMint
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeReceipt<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// The vault this receipt records payments into
+    pub vault: Account<'info, PaymentVault>,
+    
+    /// ✅ Plain `init`: fails if the receipt already exists
+    #[account(
+        init,
+        payer = user,
+        space = PaymentReceipt::LEN,
+        seeds = [b"receipt", vault.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub receipt: Account<'info, PaymentReceipt>,
+    
+    pub system_program: Program<'info, System>,
+}
+
 #[account]
+#[derive(InitSpace)]
 pub struct PaymentVault {
     pub authority: Pubkey,
     pub total_collected: u64,
+    pub expected_mint: Pubkey,
 }
 
 impl PaymentVault {
-    pub const LEN: usize = 8 + 32 + 8;
+    pub const LEN: usize = space_of!(PaymentVault);
+}
+
+/// Lifetime total a single user has paid into one vault through
+/// `process_payment_secure`, at `[b"receipt", vault, user]`
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentReceipt {
+    pub user: Pubkey,
+    pub total_paid: u64,
+    pub bump: u8,
+}
+
+impl PaymentReceipt {
+    pub const LEN: usize = space_of!(PaymentReceipt);
 }
 
 #[error_code]
@@ -100,4 +574,16 @@ pub enum ErrorCode {
     InvalidOwner,
     #[msg("Token account owner mismatch")]
     InvalidTokenOwner,
+    #[msg("Vault token account is not held by the vault")]
+    InvalidVaultTokenAccount,
+    #[msg("Token account mint does not match the vault's expected mint")]
+    WrongMint,
+    #[msg("Invalid token account")]
+    InvalidTokenAccount,
+    #[msg("Refund exceeds the amount collected")]
+    RefundExceedsCollected,
+    #[msg("Signer is not the vault's authority")]
+    InvalidVaultAuthority,
+    #[msg("Refund exceeds what this payer has paid")]
+    RefundExceedsPaid,
 }
@@ -1,5 +1,6 @@
 // ❌ VULNERABLE IMPLEMENTATION
 use anchor_lang::prelude::*;
+use security_common::space_of;
 
 /// VULNERABILITY: No owner verification on accounts
 /// 
@@ -15,6 +16,14 @@ pub fn process_payment_insecure(ctx: Context<PaymentInsecure>, amount: u64) -> R
     
     let token_data = ctx.accounts.user_token_account.try_borrow_data()?;
     
+    // Length guard: slicing a short buffer would panic rather than error.
+    // This keeps the failure clean but does NOTHING about the missing owner
+    // check - a long enough fake account still sails through.
+    require!(
+        token_data.len() >= 72,
+        ErrorCode::InvalidTokenAccount
+    );
+    
     // Manually parsing token account data (dangerous!)
     // If attacker controls the account, they control this data
     let balance = u64::from_le_bytes(
@@ -45,13 +54,14 @@ pub struct PaymentInsecure<'info> {
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct PaymentVault {
     pub authority: Pubkey,
     pub total_collected: u64,
 }
 
 impl PaymentVault {
-    pub const LEN: usize = 8 + 32 + 8;
+    pub const LEN: usize = space_of!(PaymentVault);
 }
 
 #[error_code]
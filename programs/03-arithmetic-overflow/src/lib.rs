@@ -16,15 +16,15 @@ pub mod arithmetic_overflow {
     // VULNERABLE INSTRUCTIONS
     // ========================================================================
     
-    pub fn deposit_insecure(ctx: Context<VaultOps>, amount: u64) -> Result<()> {
+    pub fn deposit_insecure(ctx: Context<InsecureVaultOps>, amount: u64) -> Result<()> {
         vulnerable::deposit_insecure(ctx, amount)
     }
     
-    pub fn withdraw_insecure(ctx: Context<VaultOps>, amount: u64) -> Result<()> {
+    pub fn withdraw_insecure(ctx: Context<InsecureVaultOps>, amount: u64) -> Result<()> {
         vulnerable::withdraw_insecure(ctx, amount)
     }
     
-    pub fn calculate_rewards_insecure(ctx: Context<VaultOps>, multiplier: u64) -> Result<()> {
+    pub fn calculate_rewards_insecure(ctx: Context<InsecureVaultOps>, multiplier: u64) -> Result<()> {
         vulnerable::calculate_rewards_insecure(ctx, multiplier)
     }
     
@@ -32,6 +32,14 @@ pub mod arithmetic_overflow {
     // SECURE INSTRUCTIONS
     // ========================================================================
     
+    pub fn initialize_vault(
+        ctx: Context<InitializeVault>,
+        max_deposit: u64,
+        min_deposit: u64,
+    ) -> Result<()> {
+        secure::initialize_vault(ctx, max_deposit, min_deposit)
+    }
+    
     pub fn deposit_secure(ctx: Context<VaultOps>, amount: u64) -> Result<()> {
         secure::deposit_secure(ctx, amount)
     }
@@ -53,7 +61,32 @@ pub mod arithmetic_overflow {
         secure::compound_interest_secure(ctx, rate_numerator, rate_denominator, periods)
     }
     
+    pub fn apply_fee_secure(
+        ctx: Context<VaultOps>,
+        amount: u64,
+        fee_bps: u64,
+        rounding: RoundingMode,
+    ) -> Result<u64> {
+        secure::apply_fee_secure(ctx, amount, fee_bps, rounding)
+    }
+    
+    pub fn quote_apy(ctx: Context<QuoteApy>, apr_bps: u64, periods: u64) -> Result<u64> {
+        secure::quote_apy(ctx, apr_bps, periods)
+    }
+    
     pub fn deposit_saturating(ctx: Context<VaultOps>, amount: u64) -> Result<()> {
         secure::deposit_saturating(ctx, amount)
     }
+    
+    pub fn initialize_large_vault(ctx: Context<InitializeLargeVault>) -> Result<()> {
+        secure::initialize_large_vault(ctx)
+    }
+    
+    pub fn deposit_zero_copy(ctx: Context<LargeVaultOps>, index: u16, amount: u64) -> Result<()> {
+        secure::deposit_zero_copy(ctx, index, amount)
+    }
+    
+    pub fn withdraw_zero_copy(ctx: Context<LargeVaultOps>, index: u16, amount: u64) -> Result<()> {
+        secure::withdraw_zero_copy(ctx, index, amount)
+    }
 }
@@ -1,58 +1,88 @@
 // ✅ SECURE IMPLEMENTATION
 use anchor_lang::prelude::*;
 
+// Shared error codes and balance helpers (see programs/common)
+pub use security_common::{Balance, SecurityError};
+use security_common::{require_nonzero_amount, space_of, try_math};
+
 /// SOLUTION: Using checked arithmetic operations
 /// 
 /// checked_* methods return Option<T>:
 /// - Some(result) if operation succeeds
 /// - None if overflow/underflow would occur
 /// 
-/// This prevents silent wrapping and ensures errors are caught
+/// This prevents silent wrapping and ensures errors are caught.
+/// `try_math!` (from security-common) expands to exactly these calls.
+/// The arithmetic itself lives in `Vault::deposit` / `Vault::withdraw`.
 pub fn deposit_secure(ctx: Context<VaultOps>, amount: u64) -> Result<()> {
-    let vault = &mut ctx.accounts.vault;
-    
-    // ✅ SAFE: checked_add returns None on overflow
-    vault.total_deposited = vault.total_deposited
-        .checked_add(amount)
-        .ok_or(ErrorCode::MathOverflow)?;
+    ctx.accounts.vault.deposit(amount)?;
     
     msg!("Securely deposited {} tokens", amount);
     Ok(())
 }
 
 pub fn withdraw_secure(ctx: Context<VaultOps>, amount: u64) -> Result<()> {
-    let vault = &mut ctx.accounts.vault;
-    
-    // ✅ SAFE: checked_sub returns None on underflow
-    vault.total_deposited = vault.total_deposited
-        .checked_sub(amount)
-        .ok_or(ErrorCode::MathUnderflow)?;
-    
-    // Also update withdrawal tracking
-    vault.total_withdrawn = vault.total_withdrawn
-        .checked_add(amount)
-        .ok_or(ErrorCode::MathOverflow)?;
+    ctx.accounts.vault.withdraw(amount)?;
     
     msg!("Securely withdrew {} tokens", amount);
     Ok(())
 }
 
+/// Reward multipliers are expressed in basis points (10_000 = 1x)
+pub const MULTIPLIER_PRECISION: u64 = 10_000;
+
+/// SOLUTION: Widen to u128 for the intermediate product
+///
+/// `total_deposited * multiplier` can exceed u64::MAX even when the final
+/// reward (after dividing by MULTIPLIER_PRECISION) fits comfortably. Doing the
+/// multiplication in u64 would reject those legitimate deposits, so the
+/// product is computed in u128 and only the final result is narrowed back.
 pub fn calculate_rewards_secure(ctx: Context<VaultOps>, multiplier: u64) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
     
-    // ✅ SAFE: Chain multiple checked operations
-    let rewards = vault.total_deposited
-        .checked_mul(multiplier)
-        .ok_or(ErrorCode::MathOverflow)?;
+    let rewards = rewards_for(vault.total_deposited, multiplier)?;
     
-    vault.total_rewards = vault.total_rewards
-        .checked_add(rewards)
-        .ok_or(ErrorCode::MathOverflow)?;
+    vault.total_rewards = try_math!(vault.total_rewards + rewards)?;
     
     msg!("Securely calculated {} rewards", rewards);
     Ok(())
 }
 
+/// Reward owed on `deposited` at `multiplier` basis points
+///
+/// Pure math, no accounts: `calculate_rewards_secure` only loads and stores
+/// around it, so every (deposited, multiplier) pair can be checked against
+/// the exact result in isolation. Returns either `deposited * multiplier /
+/// MULTIPLIER_PRECISION` or `RewardTooLarge` - never a wrapped value.
+pub fn rewards_for(deposited: u64, multiplier: u64) -> Result<u64> {
+    // ✅ SAFE: u64 * u64 always fits in u128, so this can't overflow
+    let rewards_u128 = (deposited as u128)
+        .checked_mul(multiplier as u128)
+        .ok_or(SecurityError::MathOverflow)?
+        / MULTIPLIER_PRECISION as u128;
+    
+    // ✅ SAFE: Checked downcast - error instead of truncating
+    let rewards = u64::try_from(rewards_u128)
+        .map_err(|_| ErrorCode::RewardTooLarge)?;
+    
+    Ok(rewards)
+}
+
+/// Compute `a * b / c` without overflowing on the intermediate product
+///
+/// `a * b` can exceed u64::MAX even when the quotient fits, so the product
+/// is formed in u128 (where it can't overflow) and only the final result is
+/// narrowed back to u64.
+pub fn safe_mul_div(a: u64, b: u64, c: u64) -> Result<u64> {
+    require!(c > 0, SecurityError::DivisionByZero);
+    
+    // ✅ SAFE: u64 * u64 always fits in u128
+    let quotient = (a as u128) * (b as u128) / (c as u128);
+    
+    // ✅ SAFE: Checked downcast - error instead of truncating
+    Ok(u64::try_from(quotient).map_err(|_| SecurityError::MathOverflow)?)
+}
+
 /// Example: Complex calculation with multiple safety checks
 pub fn compound_interest_secure(
     ctx: Context<VaultOps>,
@@ -63,42 +93,248 @@ pub fn compound_interest_secure(
     let vault = &mut ctx.accounts.vault;
     
     // Validate inputs
-    require!(rate_denominator > 0, ErrorCode::DivisionByZero);
+    require!(rate_denominator > 0, SecurityError::DivisionByZero);
     require!(periods > 0, ErrorCode::InvalidPeriods);
     
+    // ✅ Bound the loop: its cost grows with a number the caller picks
+    require!(periods <= MAX_COMPOUND_PERIODS, ErrorCode::TooManyPeriods);
+    
     let principal = vault.total_deposited;
+    let amount = compound(principal, rate_numerator, rate_denominator, periods)?;
+    
+    // Calculate and add total rewards
+    let total_rewards = try_math!(amount - principal)?;
+    
+    vault.total_rewards = try_math!(vault.total_rewards + total_rewards)?;
+    
+    msg!("Compound interest calculated: {} rewards", total_rewards);
+    Ok(())
+}
+
+/// Balance after `periods` of compounding - the math behind
+/// `compound_interest_secure`
+pub fn compound(
+    principal: u64,
+    rate_numerator: u64,
+    rate_denominator: u64,
+    periods: u64,
+) -> Result<u64> {
+    // ✅ Fail fast: an overflow in the last period would otherwise only be
+    // reported after paying the compute for every period before it
+    compound_upper_bound(principal, rate_numerator, rate_denominator, periods)?;
+    
     let mut amount = principal;
     
-    // Calculate compound interest safely
+    // The checked math stays as a backstop - the bound above only ever
+    // over-estimates, so nothing it lets through should fail here
     for _ in 0..periods {
         // amount = amount * (1 + rate)
-        let interest = amount
-            .checked_mul(rate_numerator)
-            .ok_or(ErrorCode::MathOverflow)?
-            .checked_div(rate_denominator)
-            .ok_or(ErrorCode::MathOverflow)?;
+        let interest = safe_mul_div(amount, rate_numerator, rate_denominator)?;
         
-        amount = amount
-            .checked_add(interest)
-            .ok_or(ErrorCode::MathOverflow)?;
+        amount = try_math!(amount + interest)?;
     }
     
-    // Calculate and add total rewards
-    let total_rewards = amount
-        .checked_sub(principal)
-        .ok_or(ErrorCode::MathUnderflow)?;
+    Ok(amount)
+}
+
+/// Most periods `compound_interest_secure` will loop over (daily for 10 years)
+pub const MAX_COMPOUND_PERIODS: u64 = 3_650;
+
+/// Largest fixed-point value `compound_upper_bound` tracks: u64::MAX as a
+/// real number. Anything bigger overflows whatever principal it multiplies.
+const BOUND_CAP: u128 = u64::MAX as u128 * APY_PRECISION;
+
+/// Upper bound on the balance `compound_interest_secure` ends up with
+///
+/// `principal * (1 + numerator / denominator)^periods`, computed by repeated
+/// squaring in `APY_PRECISION` fixed point like `apr_to_apy` - so it costs
+/// O(log periods) multiplications instead of one per period. Every step
+/// rounds *up*, and the loop itself truncates interest every period, so the
+/// loop never ends above this bound.
+///
+/// ✅ Fails with `MathOverflow` once the bound passes u64::MAX. Being an
+/// over-estimate, it can reject a run that would have fit by a hair (within
+/// about 10^-9 of u64::MAX), but never lets through one that overflows.
+pub fn compound_upper_bound(
+    principal: u64,
+    rate_numerator: u64,
+    rate_denominator: u64,
+    periods: u64,
+) -> Result<u64> {
+    require!(rate_denominator > 0, SecurityError::DivisionByZero);
     
-    vault.total_rewards = vault.total_rewards
-        .checked_add(total_rewards)
-        .ok_or(ErrorCode::MathOverflow)?;
+    // Nothing compounds on an empty balance
+    if principal == 0 {
+        return Ok(0);
+    }
     
-    msg!("Compound interest calculated: {} rewards", total_rewards);
-    Ok(())
+    // Per-period growth 1 + numerator / denominator, rounded up
+    let rate = try_math!((rate_numerator as u128) * APY_PRECISION)?
+        .div_ceil(rate_denominator as u128);
+    let mut base = try_math!(APY_PRECISION + rate)?;
+    require!(base <= BOUND_CAP, SecurityError::MathOverflow);
+    
+    let mut factor = APY_PRECISION;
+    let mut exponent = periods;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            factor = mul_fixed_ceil(factor, base)?;
+        }
+        exponent >>= 1;
+        // A squared base only exists to be multiplied in later, and factor
+        // never shrinks - so a base past the cap already means overflow
+        if exponent > 0 {
+            base = mul_fixed_ceil(base, base)?;
+        }
+    }
+    
+    let bound = try_math!((principal as u128) * factor)?.div_ceil(APY_PRECISION);
+    
+    // ✅ SAFE: Checked downcast - error instead of truncating
+    Ok(u64::try_from(bound).map_err(|_| SecurityError::MathOverflow)?)
+}
+
+/// `ceil(a * b / APY_PRECISION)` for fixed-point values up to `BOUND_CAP`
+///
+/// `a * b` alone can pass u128::MAX long before the scaled result does, so
+/// both sides are split into integer and fractional parts and multiplied
+/// piecewise. Results above `BOUND_CAP` are `MathOverflow`.
+fn mul_fixed_ceil(a: u128, b: u128) -> Result<u128> {
+    let (a_int, a_frac) = (a / APY_PRECISION, a % APY_PRECISION);
+    let (b_int, b_frac) = (b / APY_PRECISION, b % APY_PRECISION);
+    
+    // a_int and b_int are at most u64::MAX, so their product fits in u128
+    let whole = try_math!(try_math!(a_int * b_int)? * APY_PRECISION)?;
+    let cross = try_math!(try_math!(a_int * b_frac)? + try_math!(a_frac * b_int)?)?;
+    let fraction = (a_frac * b_frac).div_ceil(APY_PRECISION);
+    
+    let product = try_math!(try_math!(whole + cross)? + fraction)?;
+    require!(product <= BOUND_CAP, SecurityError::MathOverflow);
+    Ok(product)
+}
+
+/// Fees are expressed in basis points (10_000 = 100%)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Which way to round when a fee doesn't divide evenly
+///
+/// Rounding is an accounting decision, not a detail: always rounding fees
+/// `Down` leaks dust to users on every trade, always rounding `Up` charges
+/// them slightly more than the quoted rate. Protocols typically round in
+/// their own favour so the dust can't be farmed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Truncate toward zero (floor for unsigned values)
+    Down,
+    /// Any remainder bumps the result up by one (ceiling)
+    Up,
+    /// Round half up to the closest integer
+    Nearest,
+}
+
+/// Compute `amount * fee_bps / 10_000` with explicit rounding
+///
+/// The product is formed in u128 so it can never overflow, and the final
+/// fee is at most `amount` (since fee_bps <= 10_000), so it always fits u64.
+pub fn calculate_fee(amount: u64, fee_bps: u64, rounding: RoundingMode) -> Result<u64> {
+    require!(fee_bps <= BPS_DENOMINATOR, ErrorCode::InvalidFeeBps);
+    
+    let numerator = try_math!((amount as u128) * (fee_bps as u128))?;
+    let denominator = BPS_DENOMINATOR as u128;
+    
+    let fee = match rounding {
+        RoundingMode::Down => try_math!(numerator / denominator)?,
+        RoundingMode::Up => try_math!(try_math!(numerator + (denominator - 1))? / denominator)?,
+        RoundingMode::Nearest => try_math!(try_math!(numerator + (denominator / 2))? / denominator)?,
+    };
+    
+    Ok(u64::try_from(fee).map_err(|_| SecurityError::MathOverflow)?)
+}
+
+/// Example: Charging a fee with an explicit rounding direction
+///
+/// Records the fee in `total_fees` and returns it so clients can simulate
+/// the instruction to preview the charge.
+pub fn apply_fee_secure(
+    ctx: Context<VaultOps>,
+    amount: u64,
+    fee_bps: u64,
+    rounding: RoundingMode,
+) -> Result<u64> {
+    let vault = &mut ctx.accounts.vault;
+    
+    let fee = calculate_fee(amount, fee_bps, rounding)?;
+    vault.total_fees = try_math!(vault.total_fees + fee)?;
+    
+    msg!("Fee of {} charged on {} ({:?})", fee, amount, rounding);
+    Ok(fee)
+}
+
+/// Fixed-point scale used by `apr_to_apy` (1.0 == 10^12)
+pub const APY_PRECISION: u128 = 1_000_000_000_000;
+
+/// Effective annual yield (APY) of `apr_bps` compounded `periods` times a year
+///
+/// Closed form of what `compound_interest_secure` does period by period:
+/// `APY = (1 + APR / periods)^periods - 1`, in basis points. The growth
+/// factor is a u128 fixed-point number scaled by `APY_PRECISION` and is
+/// raised to the power by repeated squaring, so daily or even per-second
+/// compounding costs O(log periods) multiplications, not one per period.
+///
+/// ⚠️ PRECISION: integer fixed-point only ever truncates. The per-period
+/// rate is rounded down to 10^-12, each multiplication rounds down again,
+/// and the final conversion to basis points drops the fraction. The result
+/// is therefore a floor - up to 1 bp *below* the exact APY, never above
+/// (12% APR compounded monthly is 12.6825% and returns 1268). More precision
+/// digits shrink the error but lower the factor at which squaring overflows,
+/// which is reported as `MathOverflow` rather than wrapped.
+pub fn apr_to_apy(apr_bps: u64, periods: u64) -> Result<u64> {
+    require!(periods > 0, ErrorCode::InvalidPeriods);
+    
+    let bps = BPS_DENOMINATOR as u128;
+    
+    // Per-period rate: APR / periods, as a fixed-point fraction
+    let rate = try_math!(
+        try_math!((apr_bps as u128) * APY_PRECISION)? / try_math!(bps * (periods as u128))?
+    )?;
+    
+    let mut base = try_math!(APY_PRECISION + rate)?;
+    let mut factor = APY_PRECISION;
+    let mut exponent = periods;
+    
+    // ✅ SAFE: every fixed-point multiply is checked before rescaling
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            factor = try_math!(try_math!(factor * base)? / APY_PRECISION)?;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = try_math!(try_math!(base * base)? / APY_PRECISION)?;
+        }
+    }
+    
+    let growth = try_math!(factor - APY_PRECISION)?;
+    let apy_bps = try_math!(try_math!(growth * bps)? / APY_PRECISION)?;
+    
+    // ✅ SAFE: Checked downcast - error instead of truncating
+    Ok(u64::try_from(apy_bps).map_err(|_| SecurityError::MathOverflow)?)
+}
+
+/// Preview `apr_to_apy` on-chain
+///
+/// Touches no accounts, so clients can simulate it to get the return value.
+pub fn quote_apy(_ctx: Context<QuoteApy>, apr_bps: u64, periods: u64) -> Result<u64> {
+    let apy_bps = apr_to_apy(apr_bps, periods)?;
+    
+    msg!("{} bps APR over {} periods = {} bps APY", apr_bps, periods, apy_bps);
+    Ok(apy_bps)
 }
 
 /// Alternative: Using saturating arithmetic (caps at max/min)
 /// Use when you want to cap rather than error
 pub fn deposit_saturating(ctx: Context<VaultOps>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
     let vault = &mut ctx.accounts.vault;
     
     // Saturating: maxes out at u64::MAX instead of wrapping or erroring
@@ -108,6 +344,28 @@ pub fn deposit_saturating(ctx: Context<VaultOps>, amount: u64) -> Result<()> {
     Ok(())
 }
 
+/// Initialize a vault with a valid deposit range
+///
+/// Each deposit must be at least `min_deposit`, and the running total may
+/// not exceed `max_deposit`. Pass `0` and `u64::MAX` for an unrestricted vault.
+pub fn initialize_vault(
+    ctx: Context<InitializeVault>,
+    max_deposit: u64,
+    min_deposit: u64,
+) -> Result<()> {
+    // ✅ A floor above the cap would make every deposit impossible
+    require!(min_deposit <= max_deposit, ErrorCode::InvalidDepositRange);
+    
+    let vault = &mut ctx.accounts.vault;
+    
+    vault.set_inner(Vault::new(ctx.accounts.authority.key()));
+    vault.max_deposit = max_deposit;
+    vault.min_deposit = min_deposit;
+    
+    msg!("Vault initialized with deposit range {}..={}", min_deposit, max_deposit);
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct VaultOps<'info> {
     #[account(mut)]
@@ -117,6 +375,9 @@ pub struct VaultOps<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct QuoteApy {}
+
 #[derive(Accounts)]
 pub struct InitializeVault<'info> {
     #[account(
@@ -133,16 +394,29 @@ pub struct InitializeVault<'info> {
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct Vault {
     pub authority: Pubkey,
     pub total_deposited: u64,
     pub total_withdrawn: u64,
     pub total_rewards: u64,
-    pub max_deposit: u64,  // Optional: enforce deposit limits
+    pub max_deposit: u64,  // Deposit cap enforced by deposit_secure (u64::MAX = unlimited)
+    pub min_deposit: u64,  // Smallest single deposit deposit_secure accepts (0 = no floor)
+    pub total_fees: u64,
+}
+
+impl Balance for Vault {
+    fn balance(&self) -> u64 {
+        self.total_deposited
+    }
+    
+    fn balance_mut(&mut self) -> &mut u64 {
+        &mut self.total_deposited
+    }
 }
 
 impl Vault {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8;
+    pub const LEN: usize = space_of!(Vault);
     
     pub fn new(authority: Pubkey) -> Self {
         Self {
@@ -151,21 +425,190 @@ impl Vault {
             total_withdrawn: 0,
             total_rewards: 0,
             max_deposit: u64::MAX,
+            min_deposit: 0,
+            total_fees: 0,
         }
     }
+    
+    /// Credit a deposit - the bookkeeping behind `deposit_secure`
+    ///
+    /// Pure arithmetic on the vault, so the accounting can be checked
+    /// without a validator. On error the vault is left untouched.
+    pub fn deposit(&mut self, amount: u64) -> Result<u64> {
+        require_nonzero_amount(amount)?;
+        
+        // ✅ Reject dust: deposits below the floor only add rounding noise
+        require!(
+            amount >= self.min_deposit,
+            ErrorCode::BelowMinimumDeposit
+        );
+        
+        // ✅ Enforce the deposit cap (u64::MAX means unlimited) on the
+        // projected total, before anything is written
+        let projected_total = try_math!(self.total_deposited + amount)?;
+        require!(
+            projected_total <= self.max_deposit,
+            ErrorCode::DepositLimitExceeded
+        );
+        
+        // ✅ SAFE: checked_add via the shared Balance trait, MathOverflow on overflow
+        self.checked_credit(amount)
+    }
+    
+    /// Debit a withdrawal - the bookkeeping behind `withdraw_secure`
+    ///
+    /// Like `deposit`, either both totals change or neither does.
+    pub fn withdraw(&mut self, amount: u64) -> Result<u64> {
+        require_nonzero_amount(amount)?;
+        
+        // ✅ SAFE: checked_sub, MathUnderflow on underflow
+        let remaining = try_math!(self.total_deposited - amount)?;
+        
+        // Also update withdrawal tracking (a lifetime counter, so it can
+        // outgrow the balance and overflow on its own)
+        let total_withdrawn = try_math!(self.total_withdrawn + amount)?;
+        
+        // Both checked - only now write either
+        self.total_deposited = remaining;
+        self.total_withdrawn = total_withdrawn;
+        Ok(remaining)
+    }
+}
+
+// ============================================================================
+// ZERO-COPY LARGE VAULT
+// ============================================================================
+
+/// Number of position slots in a `LargeVault`
+pub const MAX_POSITIONS: usize = 1024;
+
+/// Create a `LargeVault` the client has already allocated
+///
+/// At ~40 KB the account is too large for `init` (a CPI can allocate at most
+/// 10 KB), so the client creates it with `SystemProgram::create_account` in
+/// the same transaction. `#[account(zero)]` then checks the discriminator is
+/// still unset, so an existing vault can never be re-initialized.
+pub fn initialize_large_vault(ctx: Context<InitializeLargeVault>) -> Result<()> {
+    let mut vault = ctx.accounts.large_vault.load_init()?;
+    vault.authority = ctx.accounts.authority.key();
+    
+    msg!("Large vault initialized with {} slots", MAX_POSITIONS);
+    Ok(())
+}
+
+/// Deposit into a single position slot without deserializing the vault
+///
+/// `load_mut()` reinterprets the account bytes in place: only the touched
+/// slot is read or written, so the cost doesn't grow with `MAX_POSITIONS`.
+/// An empty slot is claimed by its first depositor.
+pub fn deposit_zero_copy(ctx: Context<LargeVaultOps>, index: u16, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    let mut vault = ctx.accounts.large_vault.load_mut()?;
+    let owner = ctx.accounts.owner.key();
+    
+    // ✅ SAFE: get_mut returns None out of range - an error, not a panic
+    let position = vault
+        .positions
+        .get_mut(index as usize)
+        .ok_or(ErrorCode::InvalidPositionIndex)?;
+    
+    if position.owner == Pubkey::default() {
+        position.owner = owner;
+    }
+    require_keys_eq!(position.owner, owner, ErrorCode::PositionOwnerMismatch);
+    
+    position.amount = try_math!(position.amount + amount)?;
+    
+    msg!("Deposited {} into slot {} (ZERO-COPY)", amount, index);
+    Ok(())
+}
+
+/// Withdraw from a single position slot without deserializing the vault
+pub fn withdraw_zero_copy(ctx: Context<LargeVaultOps>, index: u16, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    let mut vault = ctx.accounts.large_vault.load_mut()?;
+    
+    let position = vault
+        .positions
+        .get_mut(index as usize)
+        .ok_or(ErrorCode::InvalidPositionIndex)?;
+    
+    // ✅ Only the slot's owner can withdraw from it
+    require_keys_eq!(position.owner, ctx.accounts.owner.key(), ErrorCode::PositionOwnerMismatch);
+    
+    position.amount = try_math!(position.amount - amount)?;
+    
+    msg!("Withdrew {} from slot {} (ZERO-COPY)", amount, index);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeLargeVault<'info> {
+    /// ✅ Allocated by the client, discriminator must still be zero
+    #[account(zero)]
+    pub large_vault: AccountLoader<'info, LargeVault>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct LargeVaultOps<'info> {
+    #[account(mut)]
+    pub large_vault: AccountLoader<'info, LargeVault>,
+    
+    pub owner: Signer<'info>,
+}
+
+/// One depositor's balance inside a `LargeVault`
+#[zero_copy]
+pub struct Position {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+/// Fixed-size vault read in place instead of Borsh-deserialized
+///
+/// ⚠️ Zero-copy layouts are `#[repr(C)]` and must stay free of padding:
+/// reordering fields or adding a smaller type changes the byte layout of
+/// every existing account.
+#[account(zero_copy)]
+pub struct LargeVault {
+    pub authority: Pubkey,
+    pub positions: [Position; MAX_POSITIONS],
+}
+
+impl LargeVault {
+    pub const LEN: usize = 8 + std::mem::size_of::<LargeVault>();
 }
 
 #[error_code]
 pub enum ErrorCode {
-    #[msg("Math operation resulted in overflow")]
-    MathOverflow,
+    #[msg("Invalid number of periods")]
+    InvalidPeriods = 3,
     
-    #[msg("Math operation resulted in underflow")]
-    MathUnderflow,
+    #[msg("Deposit would exceed the vault's max deposit")]
+    DepositLimitExceeded,
     
-    #[msg("Division by zero attempted")]
-    DivisionByZero,
+    #[msg("Calculated reward does not fit in u64")]
+    RewardTooLarge,
     
-    #[msg("Invalid number of periods")]
-    InvalidPeriods,
+    #[msg("Fee basis points cannot exceed 10_000")]
+    InvalidFeeBps,
+    
+    #[msg("Deposit is below the vault's minimum deposit")]
+    BelowMinimumDeposit,
+    
+    #[msg("Minimum deposit cannot exceed the max deposit")]
+    InvalidDepositRange,
+    
+    #[msg("Position index is outside the vault")]
+    InvalidPositionIndex,
+    
+    #[msg("Position belongs to a different owner")]
+    PositionOwnerMismatch,
+    
+    #[msg("Too many compounding periods in one call")]
+    TooManyPeriods,
 }
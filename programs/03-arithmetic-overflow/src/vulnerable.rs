@@ -1,5 +1,6 @@
 // ❌ VULNERABLE IMPLEMENTATION
 use anchor_lang::prelude::*;
+use security_common::space_of;
 
 /// VULNERABILITY: Unchecked arithmetic operations
 /// 
@@ -9,7 +10,7 @@ use anchor_lang::prelude::*;
 /// - Unlimited token minting
 /// - Reward pool drainage
 /// - Broken accounting
-pub fn deposit_insecure(ctx: Context<VaultOps>, amount: u64) -> Result<()> {
+pub fn deposit_insecure(ctx: Context<InsecureVaultOps>, amount: u64) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
     
     // ⚠️ DANGER: Unchecked addition - can overflow and wrap
@@ -21,7 +22,7 @@ pub fn deposit_insecure(ctx: Context<VaultOps>, amount: u64) -> Result<()> {
     Ok(())
 }
 
-pub fn withdraw_insecure(ctx: Context<VaultOps>, amount: u64) -> Result<()> {
+pub fn withdraw_insecure(ctx: Context<InsecureVaultOps>, amount: u64) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
     
     // ⚠️ DANGER: Unchecked subtraction - can underflow and wrap
@@ -33,21 +34,27 @@ pub fn withdraw_insecure(ctx: Context<VaultOps>, amount: u64) -> Result<()> {
     Ok(())
 }
 
-pub fn calculate_rewards_insecure(ctx: Context<VaultOps>, multiplier: u64) -> Result<()> {
+pub fn calculate_rewards_insecure(ctx: Context<InsecureVaultOps>, multiplier: u64) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
     
-    // ⚠️ DANGER: Unchecked multiplication - can overflow
-    let rewards = vault.total_deposited * multiplier;
+    // NOTE: This workspace builds with `overflow-checks = true`, which turns a
+    // plain `*` / `+` overflow into a panic. `wrapping_*` spells out exactly
+    // what those operators compile to when the checks are turned off, so the
+    // silent corruption can actually be observed on-chain.
+    
+    // ⚠️ DANGER: Unchecked multiplication - can overflow and wrap
+    // (multiplier is in basis points, 10_000 = 1x)
+    let rewards = vault.total_deposited.wrapping_mul(multiplier) / 10_000;
     
     // ⚠️ DANGER: Unchecked addition in reward distribution
-    vault.total_rewards = vault.total_rewards + rewards;
+    vault.total_rewards = vault.total_rewards.wrapping_add(rewards);
     
     msg!("Calculated {} rewards (INSECURE - can overflow!)", rewards);
     Ok(())
 }
 
 #[derive(Accounts)]
-pub struct VaultOps<'info> {
+pub struct InsecureVaultOps<'info> {
     #[account(mut)]
     pub vault: Account<'info, Vault>,
     
@@ -55,6 +62,7 @@ pub struct VaultOps<'info> {
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct Vault {
     pub authority: Pubkey,
     pub total_deposited: u64,
@@ -63,5 +71,5 @@ pub struct Vault {
 }
 
 impl Vault {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 8;
+    pub const LEN: usize = space_of!(Vault);
 }
@@ -0,0 +1,100 @@
+// compound_upper_bound must never sit below what the compounding loop
+// produces - otherwise it could wave through a run that overflows, leaving
+// the loop to find out the expensive way. Checked against an exact u128
+// re-implementation of the loop over seeded random inputs.
+use arithmetic_overflow::secure::{compound, compound_upper_bound, SecurityError};
+
+const RUNS: usize = 2_000;
+
+/// Deterministic splitmix64, so a failing case reproduces on every run
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// The loop in compound_interest_secure, in u128 with nothing checked;
+/// `None` once the balance passes u64::MAX
+fn reference(principal: u64, numerator: u64, denominator: u64, periods: u64) -> Option<u64> {
+    let mut amount = principal as u128;
+    for _ in 0..periods {
+        amount += amount * numerator as u128 / denominator as u128;
+        if amount > u64::MAX as u128 {
+            return None;
+        }
+    }
+    Some(amount as u64)
+}
+
+#[test]
+fn bound_never_undercuts_the_loop() {
+    let mut rng = Rng(94);
+    let (mut fit, mut overflowed) = (0, 0);
+
+    for _ in 0..RUNS {
+        // Up to 10% per period and up to 400 periods, with principals
+        // spread across the whole u64 range
+        let denominator = 1 + rng.next() % 1_000_000;
+        let numerator = rng.next() % (denominator / 10 + 1);
+        let periods = 1 + rng.next() % 400;
+        let principal = rng.next() >> (rng.next() % 64);
+
+        let result = compound(principal, numerator, denominator, periods);
+        match reference(principal, numerator, denominator, periods) {
+            Some(expected) => {
+                // A fitting run is only ever rejected right at the edge
+                match result {
+                    Ok(amount) => assert_eq!(amount, expected),
+                    Err(_) => assert!(expected > u64::MAX - u64::MAX / 1_000_000),
+                }
+                if let Ok(bound) = compound_upper_bound(principal, numerator, denominator, periods)
+                {
+                    assert!(bound >= expected);
+                }
+                fit += 1;
+            }
+            None => {
+                assert_eq!(result.unwrap_err(), SecurityError::MathOverflow.into());
+                overflowed += 1;
+            }
+        }
+    }
+
+    // Both outcomes must actually have been exercised
+    assert!(fit > 0 && overflowed > 0);
+}
+
+#[test]
+fn large_growth_on_a_small_principal_is_not_rejected() {
+    // 2^63 fits but 2^64 doesn't - even though the growth factor alone is
+    // far past what a plain u128 fixed-point multiply can hold
+    assert_eq!(compound(1, 1, 1, 63).unwrap(), 1 << 63);
+    assert_eq!(
+        compound(1, 1, 1, 64).unwrap_err(),
+        SecurityError::MathOverflow.into()
+    );
+}
+
+#[test]
+fn late_overflow_is_rejected_by_the_bound() {
+    // 1 bp per period overflows only after ~2,000 of 3,000 periods. The
+    // bound alone already reports it, without running the loop.
+    let principal = 15_000_000_000_000_000_000;
+    assert_eq!(reference(principal, 1, 10_000, 3_000), None);
+    assert_eq!(
+        compound_upper_bound(principal, 1, 10_000, 3_000).unwrap_err(),
+        SecurityError::MathOverflow.into()
+    );
+}
+
+#[test]
+fn empty_balance_never_overflows() {
+    assert_eq!(compound_upper_bound(0, u64::MAX, 1, 3_650).unwrap(), 0);
+    assert_eq!(compound(0, u64::MAX, 1, 3_650).unwrap(), 0);
+}
@@ -0,0 +1,112 @@
+// Balance conservation for deposit_secure / withdraw_secure
+//
+// `Vault::deposit` and `Vault::withdraw` are the exact bookkeeping the
+// instructions run, so a long random sequence of them can be checked here
+// without a validator: after every call, `total_deposited` must equal the
+// successful deposits minus withdrawals, computed in u128 where nothing
+// can wrap.
+use anchor_lang::prelude::*;
+use arithmetic_overflow::secure::{SecurityError, Vault};
+
+const STEPS: usize = 10_000;
+const EDGE: u64 = 1 << 16;
+
+/// Deterministic splitmix64, so a failing sequence reproduces on every run
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Half the samples sit within 2^16 of 0 or u64::MAX, so overflow and
+    /// exact-fit edges come up far more often than uniform sampling
+    fn amount(&mut self) -> u64 {
+        let raw = self.next();
+        match raw % 4 {
+            0 => raw % EDGE,
+            1 => u64::MAX - raw % EDGE,
+            _ => raw,
+        }
+    }
+}
+
+fn assert_error(result: Result<u64>, expected: impl Into<Error>) {
+    assert_eq!(result.unwrap_err(), expected.into());
+}
+
+#[test]
+fn random_deposits_and_withdrawals_conserve_the_balance() {
+    let mut rng = Rng(3);
+    let mut vault = Vault::new(Pubkey::new_unique());
+    let mut deposited: u128 = 0;
+    let mut withdrawn: u128 = 0;
+    let mut rejected = 0;
+
+    for _ in 0..STEPS {
+        let amount = rng.amount().max(1);
+        let expected = deposited - withdrawn;
+
+        if rng.next().is_multiple_of(2) {
+            if expected + amount as u128 > u64::MAX as u128 {
+                assert_error(vault.deposit(amount), SecurityError::MathOverflow);
+                rejected += 1;
+            } else {
+                assert_eq!(
+                    vault.deposit(amount).unwrap() as u128,
+                    expected + amount as u128
+                );
+                deposited += amount as u128;
+            }
+        } else if amount as u128 > expected {
+            assert_error(vault.withdraw(amount), SecurityError::MathUnderflow);
+            rejected += 1;
+        } else if withdrawn + amount as u128 > u64::MAX as u128 {
+            // total_withdrawn counts every withdrawal ever made, so it can
+            // run out of room while the balance still covers the amount
+            assert_error(vault.withdraw(amount), SecurityError::MathOverflow);
+            rejected += 1;
+        } else {
+            assert_eq!(
+                vault.withdraw(amount).unwrap() as u128,
+                expected - amount as u128
+            );
+            withdrawn += amount as u128;
+        }
+
+        // ✅ Holds after every call - a rejected one changes nothing at all
+        assert_eq!(vault.total_deposited as u128, deposited - withdrawn);
+        assert_eq!(vault.total_withdrawn as u128, withdrawn);
+    }
+
+    // Both branches must actually have been exercised
+    assert!(rejected > 0 && rejected < STEPS);
+}
+
+#[test]
+fn deposit_respects_cap_and_floor_without_side_effects() {
+    let mut vault = Vault::new(Pubkey::new_unique());
+    vault.max_deposit = 1_000;
+    vault.min_deposit = 10;
+
+    assert_eq!(vault.deposit(1_000).unwrap(), 1_000);
+    assert!(vault.deposit(10).is_err());
+    assert_eq!(vault.total_deposited, 1_000);
+
+    assert_eq!(vault.withdraw(995).unwrap(), 5);
+    assert!(vault.deposit(9).is_err());
+    assert_eq!(vault.total_deposited, 5);
+}
+
+#[test]
+fn zero_amounts_are_rejected() {
+    let mut vault = Vault::new(Pubkey::new_unique());
+
+    assert_error(vault.deposit(0), SecurityError::InvalidAmount);
+    assert_error(vault.withdraw(0), SecurityError::InvalidAmount);
+    assert_eq!(vault.total_deposited, 0);
+}
@@ -0,0 +1,97 @@
+// Property tests for the checked arithmetic behind the secure instructions
+//
+// `Vault::deposit`, `Vault::withdraw` and `rewards_for` are the exact math
+// `deposit_secure`, `withdraw_secure` and `calculate_rewards_secure` run.
+// For any u64 inputs each one must return the value exact u128 math gives,
+// or a typed error with the vault untouched - never a wrapped result.
+use anchor_lang::prelude::*;
+use arithmetic_overflow::secure::{
+    rewards_for, ErrorCode, SecurityError, Vault, MULTIPLIER_PRECISION,
+};
+use proptest::prelude::*;
+
+/// Half the samples sit within 2^16 of 0 or u64::MAX, so the exact-fit and
+/// off-by-one edges come up far more often than uniform sampling
+fn amount() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        0..=1u64 << 16,
+        (u64::MAX - (1 << 16))..=u64::MAX,
+        any::<u64>(),
+        any::<u64>(),
+    ]
+}
+
+/// An uncapped vault already holding `deposited`
+fn vault_with(deposited: u64, withdrawn: u64) -> Vault {
+    let mut vault = Vault::new(Pubkey::new_unique());
+    vault.total_deposited = deposited;
+    vault.total_withdrawn = withdrawn;
+    vault
+}
+
+proptest! {
+    #[test]
+    fn deposit_is_exact_or_overflows(deposited in amount(), amount in amount()) {
+        let mut vault = vault_with(deposited, 0);
+        let exact = deposited as u128 + amount as u128;
+
+        match vault.deposit(amount) {
+            Ok(total) => {
+                prop_assert_eq!(total as u128, exact);
+                prop_assert_eq!(vault.total_deposited, total);
+            }
+            Err(error) => {
+                let expected = if amount == 0 {
+                    SecurityError::InvalidAmount
+                } else {
+                    SecurityError::MathOverflow
+                };
+                prop_assert_eq!(error, expected.into());
+                prop_assert!(amount == 0 || exact > u64::MAX as u128);
+                prop_assert_eq!(vault.total_deposited, deposited);
+            }
+        }
+    }
+
+    #[test]
+    fn withdraw_is_exact_or_underflows(
+        deposited in amount(),
+        withdrawn in amount(),
+        amount in amount(),
+    ) {
+        let mut vault = vault_with(deposited, withdrawn);
+
+        match vault.withdraw(amount) {
+            Ok(remaining) => {
+                prop_assert_eq!(remaining as u128, deposited as u128 - amount as u128);
+                prop_assert_eq!(vault.total_withdrawn as u128, withdrawn as u128 + amount as u128);
+            }
+            Err(error) => {
+                let expected = if amount == 0 {
+                    SecurityError::InvalidAmount
+                } else if amount > deposited {
+                    SecurityError::MathUnderflow
+                } else {
+                    // total_withdrawn is a lifetime counter and can fill up first
+                    SecurityError::MathOverflow
+                };
+                prop_assert_eq!(error, expected.into());
+                prop_assert_eq!(vault.total_deposited, deposited);
+                prop_assert_eq!(vault.total_withdrawn, withdrawn);
+            }
+        }
+    }
+
+    #[test]
+    fn rewards_are_exact_or_too_large(deposited in amount(), multiplier in amount()) {
+        let exact = deposited as u128 * multiplier as u128 / MULTIPLIER_PRECISION as u128;
+
+        match rewards_for(deposited, multiplier) {
+            Ok(rewards) => prop_assert_eq!(rewards as u128, exact),
+            Err(error) => {
+                prop_assert_eq!(error, ErrorCode::RewardTooLarge.into());
+                prop_assert!(exact > u64::MAX as u128);
+            }
+        }
+    }
+}
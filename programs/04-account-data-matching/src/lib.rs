@@ -20,7 +20,27 @@ pub mod account_data_matching {
         secure::withdraw_secure(ctx, amount)
     }
     
+    pub fn withdraw_all(ctx: Context<WithdrawSecure>) -> Result<()> {
+        secure::withdraw_all(ctx)
+    }
+    
+    pub fn initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
+        secure::initialize_vault(ctx)
+    }
+    
+    pub fn initialize_program_stats(ctx: Context<InitializeProgramStats>) -> Result<()> {
+        secure::initialize_program_stats(ctx)
+    }
+    
     pub fn initialize_user_stats(ctx: Context<InitializeUserStats>) -> Result<()> {
         secure::initialize_user_stats(ctx)
     }
+    
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        secure::deposit(ctx, amount)
+    }
+    
+    pub fn close_user_stats(ctx: Context<CloseUserStats>) -> Result<()> {
+        secure::close_user_stats(ctx)
+    }
 }
@@ -1,15 +1,153 @@
 // ✅ SECURE - Proper PDA verification
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use security_common::{
+    checked_transfer_lamports, close_account, require_nonzero_amount, space_of,
+};
+pub use security_common::SecurityError;
 
 pub fn withdraw_secure(ctx: Context<WithdrawSecure>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
     // ✅ user_stats PDA is verified by Anchor constraints
-    let user_stats = &ctx.accounts.user_stats;
+    let user_stats = &mut ctx.accounts.user_stats;
     
     require!(user_stats.balance >= amount, ErrorCode::InsufficientBalance);
     
-    **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
-    **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += amount;
+    let before = user_stats.balance;
+    
+    // ✅ Debit the recorded balance before moving lamports
+    user_stats.balance = user_stats.balance
+        .checked_sub(amount)
+        .ok_or(ErrorCode::InsufficientBalance)?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.total_lamports = vault.total_lamports
+        .checked_sub(amount)
+        .ok_or(ErrorCode::InsufficientBalance)?;
+    
+    checked_transfer_lamports(
+        &vault.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        amount,
+    )?;
+    
+    let program_stats = &mut ctx.accounts.program_stats;
+    program_stats.total_locked = program_stats.total_locked
+        .checked_sub(amount)
+        .ok_or(ErrorCode::InsufficientBalance)?;
+    
+    emit_balance_changed(ctx.accounts.user.key(), before, ctx.accounts.user_stats.balance);
+    
+    Ok(())
+}
+
+/// Withdraw the caller's entire recorded balance in one call
+///
+/// The amount is read from the verified `user_stats` PDA, not passed in, so
+/// clients don't need to fetch the balance first. Runs under the same
+/// `WithdrawSecure` seeds/bump/has_one constraints as a partial withdrawal.
+pub fn withdraw_all(ctx: Context<WithdrawSecure>) -> Result<()> {
+    let amount = ctx.accounts.user_stats.balance;
+    
+    // ✅ Nothing to withdraw is an error, not a silent no-op
+    require!(amount > 0, ErrorCode::InsufficientBalance);
     
+    withdraw_secure(ctx, amount)?;
+    
+    msg!("Withdrew full balance of {} lamports", amount);
+    Ok(())
+}
+
+/// Deposit lamports into the vault and credit the user's stats
+///
+/// The transfer goes through the System Program, so the user must sign and
+/// actually own the lamports; the balance only grows by what really moved.
+pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let user_stats = &mut ctx.accounts.user_stats;
+    let before = user_stats.balance;
+    user_stats.balance = user_stats.balance
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.total_lamports = vault.total_lamports
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    let program_stats = &mut ctx.accounts.program_stats;
+    program_stats.total_locked = program_stats.total_locked
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    emit_balance_changed(ctx.accounts.user.key(), before, user_stats.balance);
+    
+    msg!("Deposited {} lamports, balance now {}", amount, user_stats.balance);
+    Ok(())
+}
+
+/// Emit `BalanceChanged` for a user_stats balance that went `before` -> `after`
+fn emit_balance_changed(user: Pubkey, before: u64, after: u64) {
+    emit!(BalanceChanged {
+        user,
+        before,
+        after,
+        // ✅ Any difference of two u64s fits in i128, in either direction
+        delta: after as i128 - before as i128,
+    });
+}
+
+/// Create the program's single vault PDA
+pub fn initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.bump = ctx.bumps.vault;
+    vault.total_lamports = 0;
+    Ok(())
+}
+
+/// Create the program-wide stats PDA
+///
+/// Like the vault, this is a singleton: the constant seeds mean there is
+/// exactly one address it can live at, and `init` fails if it exists.
+pub fn initialize_program_stats(ctx: Context<InitializeProgramStats>) -> Result<()> {
+    let program_stats = &mut ctx.accounts.program_stats;
+    program_stats.total_locked = 0;
+    program_stats.user_count = 0;
+    program_stats.bump = ctx.bumps.program_stats;
+    Ok(())
+}
+
+/// Close the caller's stats PDA and refund its rent
+///
+/// Only allowed once the balance is zero - otherwise the recorded deposit
+/// would vanish along with the account.
+pub fn close_user_stats(ctx: Context<CloseUserStats>) -> Result<()> {
+    require!(
+        ctx.accounts.user_stats.balance == 0,
+        ErrorCode::NonZeroBalance
+    );
+    
+    let program_stats = &mut ctx.accounts.program_stats;
+    program_stats.user_count = program_stats.user_count
+        .checked_sub(1)
+        .ok_or(SecurityError::MathUnderflow)?;
+    
+    close_account(&ctx.accounts.user_stats.to_account_info(), &ctx.accounts.user)?;
+    
+    msg!("Closed user stats for {}", ctx.accounts.user.key());
     Ok(())
 }
 
@@ -18,6 +156,11 @@ pub fn initialize_user_stats(ctx: Context<InitializeUserStats>) -> Result<()> {
     user_stats.user = ctx.accounts.user.key();
     user_stats.balance = 0;
     user_stats.bump = ctx.bumps.user_stats;
+    
+    let program_stats = &mut ctx.accounts.program_stats;
+    program_stats.user_count = program_stats.user_count
+        .checked_add(1)
+        .ok_or(SecurityError::MathOverflow)?;
     Ok(())
 }
 
@@ -31,12 +174,98 @@ pub struct WithdrawSecure<'info> {
         mut,
         seeds = [b"user_stats", user.key().as_ref()],
         bump = user_stats.bump,
-        has_one = user @ ErrorCode::Unauthorized
+        has_one = user @ SecurityError::Unauthorized
+    )]
+    pub user_stats: Account<'info, UserStats>,
+    
+    /// ✅ The lamport source is verified too - a correct user_stats is
+    /// worthless if the attacker picks which vault gets debited
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    /// ✅ The aggregate is a PDA too - a look-alike account can't absorb
+    /// the update while the real totals drift
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// ✅ Same PDA verification as withdraw - deposits can't be credited
+    /// to someone else's stats
+    #[account(
+        mut,
+        seeds = [b"user_stats", user.key().as_ref()],
+        bump = user_stats.bump,
+        has_one = user @ SecurityError::Unauthorized
+    )]
+    pub user_stats: Account<'info, UserStats>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseUserStats<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// ✅ Only the owner's own PDA can be closed, and rent goes back to them
+    #[account(
+        mut,
+        has_one = user @ SecurityError::Unauthorized,
+        seeds = [b"user_stats", user.key().as_ref()],
+        bump = user_stats.bump
     )]
     pub user_stats: Account<'info, UserStats>,
     
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeVault<'info> {
     #[account(mut)]
-    pub vault: AccountInfo<'info>,
+    pub payer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = Vault::LEN,
+        seeds = [b"vault"],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -53,10 +282,35 @@ pub struct InitializeUserStats<'info> {
     )]
     pub user_stats: Account<'info, UserStats>,
     
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeProgramStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = ProgramStats::LEN,
+        seeds = [b"program_stats"],
+        bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+    
     pub system_program: Program<'info, System>,
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct UserStats {
     pub user: Pubkey,
     pub balance: u64,
@@ -64,13 +318,50 @@ pub struct UserStats {
 }
 
 impl UserStats {
-    pub const LEN: usize = 8 + 32 + 8 + 1;
+    pub const LEN: usize = space_of!(UserStats);
+}
+
+/// Emitted whenever a user's recorded balance changes, so indexers can
+/// follow it without re-fetching `user_stats` after every transaction
+///
+/// `after == before + delta`; `delta` is negative for withdrawals.
+#[event]
+pub struct BalanceChanged {
+    pub user: Pubkey,
+    pub before: u64,
+    pub after: u64,
+    pub delta: i128,
+}
+
+/// Program-owned PDA holding every user's deposited lamports
+#[account]
+#[derive(InitSpace)]
+pub struct Vault {
+    pub bump: u8,
+    pub total_lamports: u64,  // Sum of all user balances
+}
+
+impl Vault {
+    pub const LEN: usize = space_of!(Vault);
+}
+
+/// Program-wide totals across every user
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramStats {
+    pub total_locked: u64,  // Sum of all user balances (total value locked)
+    pub user_count: u64,    // Number of open user_stats accounts
+    pub bump: u8,
+}
+
+impl ProgramStats {
+    pub const LEN: usize = space_of!(ProgramStats);
 }
 
 #[error_code]
 pub enum ErrorCode {
     #[msg("Insufficient balance")]
     InsufficientBalance,
-    #[msg("Unauthorized")]
-    Unauthorized,
+    #[msg("Cannot close stats with a non-zero balance")]
+    NonZeroBalance = 2,
 }
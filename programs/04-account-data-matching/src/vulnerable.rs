@@ -1,5 +1,6 @@
 // ❌ VULNERABLE - Missing PDA verification
 use anchor_lang::prelude::*;
+use security_common::space_of;
 
 pub fn withdraw_insecure(ctx: Context<WithdrawInsecure>, amount: u64) -> Result<()> {
     // ⚠️ VULNERABILITY: No verification that user_stats is correctly derived PDA
@@ -28,6 +29,7 @@ pub struct WithdrawInsecure<'info> {
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct UserStats {
     pub user: Pubkey,
     pub balance: u64,
@@ -35,7 +37,7 @@ pub struct UserStats {
 }
 
 impl UserStats {
-    pub const LEN: usize = 8 + 32 + 8 + 1;
+    pub const LEN: usize = space_of!(UserStats);
 }
 
 #[error_code]
@@ -17,4 +17,55 @@ pub mod arbitrary_cpi {
     pub fn transfer_secure(ctx: Context<TransferSecure>, amount: u64) -> Result<()> {
         secure::transfer_secure(ctx, amount)
     }
+    
+    pub fn initialize_authority_config(ctx: Context<InitializeAuthorityConfig>) -> Result<()> {
+        secure::initialize_authority_config(ctx)
+    }
+    
+    pub fn initialize_allowlist(
+        ctx: Context<InitializeAllowlist>,
+        allowed_programs: [Pubkey; MAX_ALLOWED_PROGRAMS],
+    ) -> Result<()> {
+        secure::initialize_allowlist(ctx, allowed_programs)
+    }
+    
+    pub fn update_allowlist(
+        ctx: Context<UpdateAllowlist>,
+        allowed_programs: [Pubkey; MAX_ALLOWED_PROGRAMS],
+    ) -> Result<()> {
+        secure::update_allowlist(ctx, allowed_programs)
+    }
+    
+    pub fn transfer_allowlisted(ctx: Context<TransferAllowlisted>, amount: u64) -> Result<()> {
+        secure::transfer_allowlisted(ctx, amount)
+    }
+    
+    pub fn transfer_with_hook_checks<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferWithHookChecks<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        secure::transfer_with_hook_checks(ctx, amount)
+    }
+    
+    pub fn transfer_manual(ctx: Context<TransferManual>, amount: u64) -> Result<()> {
+        secure::transfer_manual(ctx, amount)
+    }
+    
+    pub fn initialize_vault_authority(ctx: Context<InitializeVaultAuthority>) -> Result<()> {
+        secure::initialize_vault_authority(ctx)
+    }
+    
+    pub fn transfer_with_pda_authority(
+        ctx: Context<TransferWithPdaAuthority>,
+        amount: u64,
+    ) -> Result<()> {
+        secure::transfer_with_pda_authority(ctx, amount)
+    }
+    
+    pub fn transfer_with_introspection(
+        ctx: Context<TransferWithIntrospection>,
+        amount: u64,
+    ) -> Result<()> {
+        secure::transfer_with_introspection(ctx, amount)
+    }
 }
@@ -1,10 +1,25 @@
 // ✅ SECURE - Validates program IDs
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{transfer_hook, StateWithExtensions},
+    state::Mint as MintState,
+};
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TransferChecked};
+use security_common::{space_of, SecurityError};
 
+/// CONFUSED DEPUTY: this program invokes the Token Program on the caller's
+/// behalf, forwarding whatever authority it was handed. Leaving "may this
+/// signer move these tokens?" entirely to the callee works only while the
+/// callee's check happens to cover it - the moment `from` is held by a PDA
+/// this program signs for, any caller could borrow that signature. The
+/// `AuthorityConfig` records, on-chain, who may move tokens out of `from`,
+/// and the accounts struct enforces it before any CPI is made.
 pub fn transfer_secure(ctx: Context<TransferSecure>, amount: u64) -> Result<()> {
     // ✅ token_program is validated by Anchor
     // Only spl_token::ID is accepted
+    // ✅ authority is the one configured for `from` (has_one)
     
     token::transfer(
         CpiContext::new(
@@ -22,11 +37,397 @@ pub fn transfer_secure(ctx: Context<TransferSecure>, amount: u64) -> Result<()>
     Ok(())
 }
 
+/// Record who may authorize transfers out of `from`
+///
+/// Only the token account's current owner can create its config, so the
+/// authority can't be claimed by someone who merely knows the address.
+pub fn initialize_authority_config(ctx: Context<InitializeAuthorityConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.authority = ctx.accounts.authority.key();
+    config.bump = ctx.bumps.config;
+    
+    msg!("Authority {} configured for {}", config.authority, ctx.accounts.from.key());
+    Ok(())
+}
+
+/// ALTERNATIVE: Manual program ID check
+///
+/// When the program account can't be typed (e.g. it arrives through
+/// remaining_accounts, or the program has no Anchor `Id` type), compare the
+/// key yourself before the CPI. Same protection, you just can't forget the
+/// check in the accounts struct - you can forget it here instead.
+pub fn transfer_manual(ctx: Context<TransferManual>, amount: u64) -> Result<()> {
+    // ✅ Explicit check: only the real Token Program may be invoked
+    require_keys_eq!(
+        ctx.accounts.token_program.key(),
+        spl_token::ID,
+        ErrorCode::InvalidProgram
+    );
+    
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.from.to_account_info(),
+                to: ctx.accounts.to.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            }
+        ),
+        amount
+    )?;
+    
+    msg!("Transfer completed (MANUAL CHECK)");
+    Ok(())
+}
+
+/// Example: CPI signed by a PDA instead of a user
+///
+/// A PDA has no private key, so it can't sign a transaction. Instead the
+/// program passes the PDA's seeds to `invoke_signed`; the runtime re-derives
+/// the address from those seeds and this program's ID, and marks the PDA as
+/// a signer for the CPI only. Only this program can produce that signature,
+/// which is what makes a PDA a safe custodian for token accounts.
+///
+/// That also makes the program responsible for deciding *who* may ask for
+/// the signature: otherwise anyone could empty every account the PDA holds.
+/// The PDA stores its admin, and only that admin may trigger a transfer.
+pub fn transfer_with_pda_authority(
+    ctx: Context<TransferWithPdaAuthority>,
+    amount: u64,
+) -> Result<()> {
+    // ✅ admin matches the stored one (has_one) and `from` is held by the PDA
+    let bump = ctx.accounts.vault_authority.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"authority", &[bump]]];
+    
+    // ✅ Program<'info, Token> still validates the program we sign for -
+    // forwarding PDA authority to an arbitrary program would be far worse
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.from.to_account_info(),
+                to: ctx.accounts.to.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount
+    )?;
+    
+    msg!("Transfer completed (PDA SIGNED)");
+    Ok(())
+}
+
+/// Create the PDA that custodies token accounts for this program
+///
+/// The PDA is a singleton, so whoever creates it first would become its
+/// admin. Only the program's upgrade authority may create it.
+pub fn initialize_vault_authority(ctx: Context<InitializeVaultAuthority>) -> Result<()> {
+    ctx.accounts.vault_authority.set_inner(VaultAuthority {
+        admin: ctx.accounts.admin.key(),
+        bump: ctx.bumps.vault_authority,
+    });
+    
+    msg!("Vault authority initialized");
+    Ok(())
+}
+
+/// ALTERNATIVE: Refuse to be called through another program
+///
+/// Validating the programs *we* call protects against a malicious callee.
+/// This guards the other direction: a rogue program that wraps our
+/// instruction in a CPI and forwards a signature the user gave it for
+/// something else. The Instructions sysvar only lists top-level
+/// instructions, so if the one currently executing doesn't belong to this
+/// program, we were reached through a CPI.
+pub fn transfer_with_introspection(
+    ctx: Context<TransferWithIntrospection>,
+    amount: u64,
+) -> Result<()> {
+    // ✅ Offset 0 = the top-level instruction being executed right now
+    let current_ix = get_instruction_relative(0, &ctx.accounts.instructions)?;
+    require_keys_eq!(current_ix.program_id, crate::ID, ErrorCode::CpiNotAllowed);
+    
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.from.to_account_info(),
+                to: ctx.accounts.to.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            }
+        ),
+        amount
+    )?;
+    
+    msg!("Transfer completed (TOP-LEVEL ONLY)");
+    Ok(())
+}
+
+/// Maximum number of programs the CPI allowlist can hold
+pub const MAX_ALLOWED_PROGRAMS: usize = 4;
+
+/// Create the program-wide CPI allowlist
+///
+/// Unused slots are left as `Pubkey::default()`. The allowlist is a
+/// singleton: a per-user list would let any caller pass a list they wrote
+/// themselves, which is no better than accepting any program ID. Being a
+/// singleton, it goes to whoever creates it first, so only the program's
+/// upgrade authority may.
+pub fn initialize_allowlist(
+    ctx: Context<InitializeAllowlist>,
+    allowed_programs: [Pubkey; MAX_ALLOWED_PROGRAMS],
+) -> Result<()> {
+    ctx.accounts.allowlist.set_inner(CpiAllowlist {
+        admin: ctx.accounts.admin.key(),
+        allowed_programs,
+        bump: ctx.bumps.allowlist,
+    });
+    
+    msg!("CPI allowlist initialized");
+    Ok(())
+}
+
+/// Replace the programs on the CPI allowlist
+///
+/// Only the admin recorded at initialization may change the list.
+pub fn update_allowlist(
+    ctx: Context<UpdateAllowlist>,
+    allowed_programs: [Pubkey; MAX_ALLOWED_PROGRAMS],
+) -> Result<()> {
+    ctx.accounts.allowlist.allowed_programs = allowed_programs;
+    
+    msg!("CPI allowlist updated");
+    Ok(())
+}
+
+/// ALTERNATIVE: CPI into one of several approved programs
+///
+/// `Program<'info, Token>` pins exactly one program. When a program must
+/// accept several (classic SPL Token *and* Token-2022, say), check the
+/// passed ID against an on-chain list before invoking it.
+pub fn transfer_allowlisted(ctx: Context<TransferAllowlisted>, amount: u64) -> Result<()> {
+    // ✅ The target program must be on the allowlist
+    require!(
+        ctx.accounts.allowlist.allows(ctx.accounts.token_program.key),
+        ErrorCode::ProgramNotAllowed
+    );
+    
+    // ✅ transfer_checked builds the instruction for the program passed in,
+    // which is identical for both token programs
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.to.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    msg!("Transfer completed (ALLOWLISTED)");
+    Ok(())
+}
+
+/// TRANSFER HOOKS: a Token-2022 mint can name a hook program that the
+/// token program CPIs into on every `transfer_checked`. Pinning the token
+/// program is then no longer enough - whoever created the mint chose a
+/// second program that runs inside this transfer. Read the hook out of the
+/// mint and refuse any program that isn't on the allowlist before invoking.
+pub fn transfer_with_hook_checks<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferWithHookChecks<'info>>,
+    amount: u64,
+) -> Result<()> {
+    // ✅ A mint without a hook runs no extra code; one with a hook may only
+    // name an approved program
+    if let Some(hook_program) = transfer_hook_program(&ctx.accounts.mint.to_account_info())? {
+        require!(
+            ctx.accounts.allowlist.allows(&hook_program),
+            ErrorCode::HookNotAllowed
+        );
+    }
+    
+    // ⚠️ The hook's extra accounts arrive as remaining accounts and are
+    // passed through untouched - the token program checks them against the
+    // mint's validation account
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.to.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    msg!("Transfer completed (HOOK CHECKED)");
+    Ok(())
+}
+
+/// The transfer-hook program configured on `mint`, if any
+///
+/// Classic SPL Token mints have no extensions and always return `None`.
+fn transfer_hook_program(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+    Ok(transfer_hook::get_program_id(&state))
+}
+
 #[derive(Accounts)]
 pub struct TransferSecure<'info> {
     /// ✅ Program<'info, Token> validates program ID
     pub token_program: Program<'info, Token>,
     
+    /// ✅ Source must be held by the authority signing this instruction
+    #[account(
+        mut,
+        constraint = from.owner == authority.key() @ SecurityError::Unauthorized
+    )]
+    pub from: Account<'info, TokenAccount>,
+    
+    /// ✅ Both sides must hold the same mint - a valid program ID alone
+    /// doesn't stop the caller mixing up which tokens are being moved
+    #[account(
+        mut,
+        constraint = from.mint == to.mint @ ErrorCode::MintMismatch
+    )]
+    pub to: Account<'info, TokenAccount>,
+    
+    /// ✅ The configured authority for `from` - any other signer is rejected
+    #[account(
+        seeds = [b"authority_config", from.key().as_ref()],
+        bump = config.bump,
+        has_one = authority @ SecurityError::Unauthorized
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeAuthorityConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// ✅ Only the current owner of the token account can configure it
+    #[account(constraint = from.owner == authority.key() @ SecurityError::Unauthorized)]
+    pub from: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = AuthorityConfig::LEN,
+        seeds = [b"authority_config", from.key().as_ref()],
+        bump
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeAllowlist<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = CpiAllowlist::LEN,
+        seeds = [b"cpi_allowlist"],
+        bump
+    )]
+    pub allowlist: Account<'info, CpiAllowlist>,
+    
+    /// ✅ Same upgrade-authority gate as the vault authority
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::ArbitraryCpi>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ SecurityError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAllowlist<'info> {
+    pub admin: Signer<'info>,
+    
+    /// ✅ Only the stored admin may change the list
+    #[account(mut, seeds = [b"cpi_allowlist"], bump = allowlist.bump, has_one = admin)]
+    pub allowlist: Account<'info, CpiAllowlist>,
+}
+
+#[derive(Accounts)]
+pub struct TransferAllowlisted<'info> {
+    /// ✅ Only the program's own allowlist PDA is accepted
+    #[account(seeds = [b"cpi_allowlist"], bump = allowlist.bump)]
+    pub allowlist: Account<'info, CpiAllowlist>,
+    
+    /// CHECK: Checked against the allowlist in the handler
+    pub token_program: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = authority
+    )]
+    pub from: InterfaceAccount<'info, token_interface::TokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut, token::mint = mint)]
+    pub to: InterfaceAccount<'info, token_interface::TokenAccount>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferWithHookChecks<'info> {
+    /// ✅ Hook programs are checked against the same allowlist PDA
+    #[account(seeds = [b"cpi_allowlist"], bump = allowlist.bump)]
+    pub allowlist: Account<'info, CpiAllowlist>,
+    
+    /// ✅ Only SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = authority,
+        token::token_program = token_program
+    )]
+    pub from: InterfaceAccount<'info, token_interface::TokenAccount>,
+    
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub to: InterfaceAccount<'info, token_interface::TokenAccount>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferManual<'info> {
+    /// CHECK: Validated against spl_token::ID in the handler
+    pub token_program: AccountInfo<'info>,
+    
     #[account(mut)]
     pub from: Account<'info, TokenAccount>,
     
@@ -35,3 +436,136 @@ pub struct TransferSecure<'info> {
     
     pub authority: Signer<'info>,
 }
+
+#[derive(Accounts)]
+pub struct TransferWithIntrospection<'info> {
+    pub token_program: Program<'info, Token>,
+    
+    #[account(
+        mut,
+        constraint = from.owner == authority.key() @ SecurityError::Unauthorized
+    )]
+    pub from: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = from.mint == to.mint @ ErrorCode::MintMismatch
+    )]
+    pub to: Account<'info, TokenAccount>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: ✅ Address pinned to the Instructions sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultAuthority<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = VaultAuthority::LEN,
+        seeds = [b"authority"],
+        bump
+    )]
+    pub vault_authority: Account<'info, VaultAuthority>,
+    
+    /// ✅ Only the upgrade authority recorded in this program's ProgramData
+    /// may create the singleton
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::ArbitraryCpi>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ SecurityError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferWithPdaAuthority<'info> {
+    pub token_program: Program<'info, Token>,
+    
+    /// ✅ Tokens can only leave accounts the PDA actually holds
+    #[account(
+        mut,
+        constraint = from.owner == vault_authority.key() @ SecurityError::Unauthorized,
+        constraint = from.mint == to.mint @ ErrorCode::MintMismatch
+    )]
+    pub from: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub to: Account<'info, TokenAccount>,
+    
+    /// ✅ Signing PDA; only its stored admin may use it
+    #[account(seeds = [b"authority"], bump = vault_authority.bump, has_one = admin)]
+    pub vault_authority: Account<'info, VaultAuthority>,
+    
+    pub admin: Signer<'info>,
+}
+
+/// Who may authorize transfers out of one token account
+#[account]
+#[derive(InitSpace)]
+pub struct AuthorityConfig {
+    pub authority: Pubkey,
+    pub bump: u8,
+}
+
+impl AuthorityConfig {
+    pub const LEN: usize = space_of!(AuthorityConfig);
+}
+
+/// Signing PDA for token accounts the program custodies
+#[account]
+#[derive(InitSpace)]
+pub struct VaultAuthority {
+    pub admin: Pubkey,
+    pub bump: u8,
+}
+
+impl VaultAuthority {
+    pub const LEN: usize = space_of!(VaultAuthority);
+}
+
+/// Programs this program is willing to CPI into
+#[account]
+#[derive(InitSpace)]
+pub struct CpiAllowlist {
+    pub admin: Pubkey,
+    pub allowed_programs: [Pubkey; MAX_ALLOWED_PROGRAMS],
+    pub bump: u8,
+}
+
+impl CpiAllowlist {
+    pub const LEN: usize = space_of!(CpiAllowlist);
+    
+    /// ⚠️ Empty slots are `Pubkey::default()` - which is also the System
+    /// Program's ID, so it must never count as a match
+    pub fn allows(&self, program_id: &Pubkey) -> bool {
+        *program_id != Pubkey::default() && self.allowed_programs.contains(program_id)
+    }
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Invalid program ID for CPI")]
+    InvalidProgram,
+    
+    #[msg("Source and destination token accounts hold different mints")]
+    MintMismatch,
+    
+    #[msg("Program is not on the CPI allowlist")]
+    ProgramNotAllowed,
+    
+    #[msg("Instruction must be called directly, not through a CPI")]
+    CpiNotAllowed,
+    
+    #[msg("Mint's transfer-hook program is not on the CPI allowlist")]
+    HookNotAllowed,
+}
@@ -1,18 +1,40 @@
 // ❌ VULNERABLE - Accepts arbitrary program IDs
+// ⚠️ EDUCATIONAL ONLY: never ship a CPI like this
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{instruction::Instruction, program::invoke};
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+use spl_token::instruction::TokenInstruction;
 
 pub fn transfer_insecure(ctx: Context<TransferInsecure>, amount: u64) -> Result<()> {
     // ⚠️ DANGER: Accepts any program ID from user
     // Attacker can pass malicious program that doesn't transfer tokens
-    
+
+    // The instruction is byte-for-byte an SPL Token transfer...
     let transfer_ix = Instruction {
         program_id: ctx.accounts.token_program.key(),  // User-controlled!
-        accounts: vec![/* ... */],
-        data: vec![/* ... */],
+        accounts: vec![
+            AccountMeta::new(ctx.accounts.from.key(), false),
+            AccountMeta::new(ctx.accounts.to.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.authority.key(), true),
+        ],
+        data: TokenInstruction::Transfer { amount }.pack(),
     };
-    
-    invoke(&transfer_ix, &[])?;
+
+    // ...but whichever program the caller chose decides what it means.
+    // The authority's signature is forwarded to that program too.
+    invoke(
+        &transfer_ix,
+        &[
+            ctx.accounts.from.to_account_info(),
+            ctx.accounts.to.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+        ],
+    )?;
+
+    // ⚠️ "Success" only means the unknown program didn't return an error
     msg!("Transfer completed (INSECURE)");
     Ok(())
 }
@@ -21,7 +43,9 @@ pub fn transfer_insecure(ctx: Context<TransferInsecure>, amount: u64) -> Result<
 pub struct TransferInsecure<'info> {
     /// ⚠️ No validation - any program accepted
     pub token_program: AccountInfo<'info>,
+    #[account(mut)]
     pub from: AccountInfo<'info>,
+    #[account(mut)]
     pub to: AccountInfo<'info>,
     pub authority: Signer<'info>,
 }
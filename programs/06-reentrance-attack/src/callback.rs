@@ -0,0 +1,50 @@
+// Shared plumbing for the withdraw-callback examples
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+
+/// Invoke `on_withdraw(amount)` on a user-supplied callback program
+///
+/// The callback receives everything it would need to call back into this
+/// program - which is exactly what makes the ordering of state writes
+/// around this call matter.
+pub fn invoke_withdraw_callback<'info>(
+    callback_program: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    user_account: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    this_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    // Anchor instruction discriminator: sha256("global:on_withdraw")[..8]
+    let mut data = hash(b"global:on_withdraw").to_bytes()[..8].to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    let ix = Instruction {
+        program_id: callback_program.key(),
+        accounts: vec![
+            AccountMeta::new(user.key(), true),
+            AccountMeta::new(user_account.key(), false),
+            AccountMeta::new(vault.key(), false),
+            AccountMeta::new_readonly(this_program.key(), false),
+            AccountMeta::new_readonly(callback_program.key(), false),
+        ],
+        data,
+    };
+
+    invoke(
+        &ix,
+        &[
+            user.clone(),
+            user_account.clone(),
+            vault.clone(),
+            this_program.clone(),
+            callback_program.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
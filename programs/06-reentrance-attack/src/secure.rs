@@ -1,26 +1,385 @@
 // ✅ SECURE - Checks-Effects-Interactions pattern
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use security_common::{checked_transfer_lamports, require_nonzero_amount, space_of, SecurityError};
+use crate::callback::invoke_withdraw_callback;
+use crate::program::ReentranceAttack;
+use crate::tokens::{invoke_token_hook, transfer_from_vault};
 
 pub fn withdraw_secure(ctx: Context<WithdrawSecure>, amount: u64) -> Result<()> {
     let user_account = &mut ctx.accounts.user_account;
     
     // ✅ CHECKS - Validate all conditions first
     require!(user_account.balance >= amount, ErrorCode::InsufficientBalance);
-    require!(amount > 0, ErrorCode::InvalidAmount);
+    require_nonzero_amount(amount)?;
     
     // ✅ EFFECTS - Update state BEFORE external calls
     user_account.balance = user_account.balance
         .checked_sub(amount)
-        .ok_or(ErrorCode::MathUnderflow)?;
+        .ok_or(SecurityError::MathUnderflow)?;
     
     // ✅ INTERACTIONS - External calls LAST
-    **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
-    **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += amount;
+    // The vault is a program-owned PDA, so this program may debit it
+    checked_transfer_lamports(
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        amount,
+    )?;
     
     msg!("Withdrawal completed securely");
     Ok(())
 }
 
+/// ALTERNATIVE: Explicit reentrancy guard
+///
+/// CEI protects a single instruction; a persistent lock also protects
+/// against re-entry from *another* instruction while this one is mid-flight.
+///
+/// The lock must be set - and written to the account - before any
+/// interaction. Anchor only serializes account data when the handler
+/// returns, so without the explicit `exit` a re-entrant call would read the
+/// stale on-chain bytes, see `locked == false`, and walk straight in.
+pub fn withdraw_guarded(ctx: Context<WithdrawSecure>, amount: u64) -> Result<()> {
+    let user_account = &mut ctx.accounts.user_account;
+    
+    // ✅ GUARD - Refuse to run while another call holds the lock
+    require!(!user_account.locked, ErrorCode::Reentrancy);
+    
+    // ✅ Take the lock and persist it BEFORE any external call
+    user_account.locked = true;
+    user_account.exit(&crate::ID)?;
+    
+    // ✅ CHECKS
+    require!(user_account.balance >= amount, ErrorCode::InsufficientBalance);
+    require_nonzero_amount(amount)?;
+    
+    // ✅ EFFECTS
+    user_account.balance = user_account.balance
+        .checked_sub(amount)
+        .ok_or(SecurityError::MathUnderflow)?;
+    
+    // ✅ INTERACTIONS
+    checked_transfer_lamports(
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        amount,
+    )?;
+    
+    // ✅ Release the lock once all work is done
+    user_account.locked = false;
+    
+    msg!("Withdrawal completed with reentrancy guard");
+    Ok(())
+}
+
+/// Deepest nesting allowed for `withdraw_depth_tracked` on one account
+pub const MAX_CALL_DEPTH: u8 = 1;
+
+/// ALTERNATIVE: Call-depth counter
+///
+/// A boolean lock says "someone is inside"; a counter also says how deep.
+/// `call_depth` is incremented on entry and decremented on exit, so a
+/// finished call always leaves it at 0 and any nested call sees at least 1.
+/// Like the lock, the increment must be persisted with `exit` before the
+/// optional callback runs, or a nested call would read the old depth.
+///
+/// NOTE: The runtime already rejects A -> B -> A re-entry, so a callback
+/// can't actually reach the guard today. The counter is what still stands
+/// if that ever changes, or if the same account is reachable through
+/// self-recursion or another instruction of this program.
+pub fn withdraw_depth_tracked(ctx: Context<WithdrawDepthTracked>, amount: u64) -> Result<()> {
+    let user_account = &mut ctx.accounts.user_account;
+    
+    // ✅ ENTER - count this call and refuse to nest
+    user_account.call_depth = user_account.call_depth
+        .checked_add(1)
+        .ok_or(SecurityError::MathOverflow)?;
+    require!(
+        user_account.call_depth <= MAX_CALL_DEPTH,
+        ErrorCode::CallDepthExceeded
+    );
+    
+    // ✅ CHECKS
+    require!(user_account.balance >= amount, ErrorCode::InsufficientBalance);
+    require_nonzero_amount(amount)?;
+    
+    // ✅ EFFECTS - balance and depth both persisted before any interaction
+    user_account.balance = user_account.balance
+        .checked_sub(amount)
+        .ok_or(SecurityError::MathUnderflow)?;
+    user_account.exit(&crate::ID)?;
+    
+    // ✅ INTERACTIONS
+    checked_transfer_lamports(
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        amount,
+    )?;
+    
+    if let Some(callback_program) = &ctx.accounts.callback_program {
+        invoke_withdraw_callback(
+            &callback_program.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.user_account.to_account_info(),
+            &ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.this_program.to_account_info(),
+            amount,
+        )?;
+    }
+    
+    // ✅ EXIT - a completed call always leaves the depth where it found it
+    let user_account = &mut ctx.accounts.user_account;
+    user_account.call_depth = user_account.call_depth
+        .checked_sub(1)
+        .ok_or(SecurityError::MathUnderflow)?;
+    
+    msg!("Withdrawal completed at call depth {}", MAX_CALL_DEPTH);
+    Ok(())
+}
+
+/// ✅ Cross-program variant: every state write lands before the callback
+///
+/// Updating the field isn't enough on its own - Anchor serializes account
+/// data when the handler returns, so the callback would still read the old
+/// balance. `exit` writes it out before control leaves this program.
+pub fn withdraw_with_callback_secure(
+    ctx: Context<WithdrawWithCallbackSecure>,
+    amount: u64,
+) -> Result<()> {
+    let user_account = &mut ctx.accounts.user_account;
+    
+    // ✅ CHECKS
+    require!(user_account.balance >= amount, ErrorCode::InsufficientBalance);
+    require_nonzero_amount(amount)?;
+    
+    // ✅ EFFECTS - Update and persist state BEFORE the callback
+    user_account.balance = user_account.balance
+        .checked_sub(amount)
+        .ok_or(SecurityError::MathUnderflow)?;
+    user_account.exit(&crate::ID)?;
+    
+    // ✅ INTERACTIONS - Lamports and callback LAST
+    checked_transfer_lamports(
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        amount,
+    )?;
+    
+    invoke_withdraw_callback(
+        &ctx.accounts.callback_program,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.user_account.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.this_program.to_account_info(),
+        amount,
+    )?;
+    
+    msg!("Withdrawal with callback completed securely");
+    Ok(())
+}
+
+/// ✅ Token variant: CEI around an SPL `transfer_checked` CPI
+///
+/// A token transfer looks like a plain state change, but a Token-2022 mint
+/// with a transfer hook makes the token program invoke the hook program in
+/// the middle of it - code picked by whoever created the mint. So the token
+/// CPI is an INTERACTION like any other: the balance is debited and
+/// persisted first.
+///
+/// `hook_program` stands in for that hook. It runs right after the
+/// transfer, with the same view of this program's state a real hook would
+/// have.
+pub fn withdraw_tokens_secure(ctx: Context<WithdrawTokensSecure>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    let token_balance = &mut ctx.accounts.token_balance;
+    
+    // ✅ CHECKS
+    require!(token_balance.balance >= amount, ErrorCode::InsufficientBalance);
+    
+    // ✅ EFFECTS - Update and persist state BEFORE the token CPI
+    token_balance.balance = token_balance.balance
+        .checked_sub(amount)
+        .ok_or(SecurityError::MathUnderflow)?;
+    token_balance.exit(&crate::ID)?;
+    
+    // ✅ INTERACTIONS - Token transfer (and whatever it triggers) LAST
+    transfer_from_vault(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.vault_token_account.to_account_info(),
+        &ctx.accounts.mint,
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        ctx.accounts.vault.bump,
+        amount,
+    )?;
+    
+    invoke_token_hook(
+        &ctx.accounts.hook_program,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.token_balance.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.vault_token_account.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.this_program.to_account_info(),
+        amount,
+    )?;
+    
+    msg!("Token withdrawal completed securely");
+    Ok(())
+}
+
+/// Take the lock, failing if anyone already holds it
+///
+/// The lock lives in its own account, so it is persisted with `exit`
+/// immediately - the caller never has to remember to do it.
+pub fn acquire(lock: &mut Account<ReentrancyLock>) -> Result<()> {
+    require!(!lock.locked, ErrorCode::LockHeld);
+    lock.locked = true;
+    lock.exit(&crate::ID)
+}
+
+/// Give the lock back, failing if it wasn't held
+pub fn release(lock: &mut Account<ReentrancyLock>) -> Result<()> {
+    require!(lock.locked, ErrorCode::LockNotHeld);
+    lock.locked = false;
+    lock.exit(&crate::ID)
+}
+
+/// ALTERNATIVE: Mutex account spanning instructions
+///
+/// `withdraw_guarded`'s flag lives on the user account and is cleared before
+/// the instruction returns, so it only guards one instruction. Here the lock
+/// is a separate PDA and `withdraw_locked` does *not* release it: a second
+/// `withdraw_locked` is rejected until `release_lock` runs - whether it comes
+/// later in the same transaction, through a CPI, or in another transaction.
+pub fn withdraw_locked(ctx: Context<WithdrawLocked>, amount: u64) -> Result<()> {
+    // ✅ GUARD - take the lock before anything else
+    acquire(&mut ctx.accounts.lock)?;
+    
+    let user_account = &mut ctx.accounts.user_account;
+    
+    // ✅ CHECKS
+    require!(user_account.balance >= amount, ErrorCode::InsufficientBalance);
+    require_nonzero_amount(amount)?;
+    
+    // ✅ EFFECTS
+    user_account.balance = user_account.balance
+        .checked_sub(amount)
+        .ok_or(SecurityError::MathUnderflow)?;
+    
+    // ✅ INTERACTIONS
+    checked_transfer_lamports(
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        amount,
+    )?;
+    
+    msg!("Withdrawal completed, lock held until release_lock");
+    Ok(())
+}
+
+/// Hold the lock across a multi-instruction operation
+pub fn acquire_lock(ctx: Context<LockOps>) -> Result<()> {
+    acquire(&mut ctx.accounts.lock)
+}
+
+/// Release the lock taken by `acquire_lock` or `withdraw_locked`
+pub fn release_lock(ctx: Context<LockOps>) -> Result<()> {
+    release(&mut ctx.accounts.lock)
+}
+
+pub fn initialize_lock(ctx: Context<InitializeLock>) -> Result<()> {
+    ctx.accounts.lock.set_inner(ReentrancyLock {
+        user: ctx.accounts.user.key(),
+        locked: false,
+        bump: ctx.bumps.lock,
+    });
+    Ok(())
+}
+
+/// Create the program-owned vault PDA that holds all deposits
+pub fn initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
+    ctx.accounts.vault.bump = ctx.bumps.vault;
+    Ok(())
+}
+
+pub fn initialize_user_account(ctx: Context<InitializeUserAccount>) -> Result<()> {
+    let user_account = &mut ctx.accounts.user_account;
+    user_account.user = ctx.accounts.user.key();
+    user_account.balance = 0;
+    user_account.locked = false;
+    user_account.call_depth = 0;
+    Ok(())
+}
+
+/// Move lamports into the vault PDA and credit the user's balance
+pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let user_account = &mut ctx.accounts.user_account;
+    user_account.balance = user_account.balance
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    Ok(())
+}
+
+/// Create the user's balance for one mint, starting at zero
+pub fn initialize_token_balance(ctx: Context<InitializeTokenBalance>) -> Result<()> {
+    let token_balance = &mut ctx.accounts.token_balance;
+    token_balance.user = ctx.accounts.user.key();
+    token_balance.mint = ctx.accounts.mint.key();
+    token_balance.balance = 0;
+    token_balance.bump = ctx.bumps.token_balance;
+    Ok(())
+}
+
+/// Move tokens into the vault's token account and credit the user's balance
+/// for that mint
+///
+/// Token deposits never touch `UserAccount.balance`: that balance is paid out
+/// in lamports, and one shared number would let a deposit of a worthless
+/// mint be withdrawn as SOL or as another mint's tokens.
+pub fn deposit_tokens(ctx: Context<DepositTokens>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    let token_balance = &mut ctx.accounts.token_balance;
+    token_balance.balance = token_balance.balance
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct WithdrawSecure<'info> {
     #[account(mut)]
@@ -28,28 +387,348 @@ pub struct WithdrawSecure<'info> {
     
     #[account(
         mut,
-        has_one = user @ ErrorCode::Unauthorized
+        has_one = user @ SecurityError::Unauthorized
     )]
     pub user_account: Account<'info, UserAccount>,
     
+    /// ✅ Program-owned PDA as the lamport source - an arbitrary account
+    /// couldn't be debited by this program at all
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawWithCallbackSecure<'info> {
     #[account(mut)]
-    pub vault: AccountInfo<'info>,
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = user @ SecurityError::Unauthorized
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    /// CHECK: Arbitrary callback - safe only because state is final first
+    pub callback_program: AccountInfo<'info>,
+    
+    pub this_program: Program<'info, ReentranceAttack>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTokensSecure<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// ✅ The balance for this user AND this mint - tokens of one mint can
+    /// only be withdrawn against deposits of that same mint
+    #[account(
+        mut,
+        seeds = [b"token_balance", user.key().as_ref(), mint.key().as_ref()],
+        bump = token_balance.bump
+    )]
+    pub token_balance: Account<'info, TokenBalance>,
+    
+    /// Signs the token transfer; writable because the hook receives it
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// CHECK: Stand-in for a transfer hook - safe only because state is final first
+    pub hook_program: AccountInfo<'info>,
+    
+    pub this_program: Program<'info, ReentranceAttack>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawDepthTracked<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = user @ SecurityError::Unauthorized
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    /// CHECK: Optional arbitrary callback - the depth guard runs first
+    pub callback_program: Option<UncheckedAccount<'info>>,
+    
+    pub this_program: Program<'info, ReentranceAttack>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawLocked<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = user @ SecurityError::Unauthorized
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        mut,
+        seeds = [b"lock", user.key().as_ref()],
+        bump = lock.bump
+    )]
+    pub lock: Account<'info, ReentrancyLock>,
+}
+
+#[derive(Accounts)]
+pub struct LockOps<'info> {
+    pub user: Signer<'info>,
+    
+    /// ✅ Only the owner can take or drop their lock - otherwise anyone
+    /// could freeze someone else's withdrawals
+    #[account(
+        mut,
+        seeds = [b"lock", user.key().as_ref()],
+        bump = lock.bump,
+        has_one = user @ SecurityError::Unauthorized
+    )]
+    pub lock: Account<'info, ReentrancyLock>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeLock<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = ReentrancyLock::LEN,
+        seeds = [b"lock", user.key().as_ref()],
+        bump
+    )]
+    pub lock: Account<'info, ReentrancyLock>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeVault<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = Vault::LEN,
+        seeds = [b"vault"],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeUserAccount<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = UserAccount::LEN,
+        seeds = [b"user_account", user.key().as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = user @ SecurityError::Unauthorized
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTokenBalance<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = TokenBalance::LEN,
+        seeds = [b"token_balance", user.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub token_balance: Account<'info, TokenBalance>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DepositTokens<'info> {
+    pub user: Signer<'info>,
+    
+    /// ✅ Credited for this mint only - never the lamport balance
+    #[account(
+        mut,
+        seeds = [b"token_balance", user.key().as_ref(), mint.key().as_ref()],
+        bump = token_balance.bump
+    )]
+    pub token_balance: Account<'info, TokenBalance>,
+    
+    #[account(
+        seeds = [b"vault"],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct UserAccount {
     pub user: Pubkey,
     pub balance: u64,
+    pub locked: bool,  // Reentrancy guard used by withdraw_guarded
+    pub call_depth: u8,  // Nesting counter used by withdraw_depth_tracked
+}
+
+impl UserAccount {
+    pub const LEN: usize = space_of!(UserAccount);
+}
+
+/// A user's deposited tokens of one mint, at `[b"token_balance", user, mint]`
+#[account]
+#[derive(InitSpace)]
+pub struct TokenBalance {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub balance: u64,
+    pub bump: u8,
+}
+
+impl TokenBalance {
+    pub const LEN: usize = space_of!(TokenBalance);
+}
+
+/// Mutex held across instructions by `withdraw_locked` / `acquire_lock`
+#[account]
+#[derive(InitSpace)]
+pub struct ReentrancyLock {
+    pub user: Pubkey,
+    pub locked: bool,
+    pub bump: u8,
+}
+
+impl ReentrancyLock {
+    pub const LEN: usize = space_of!(ReentrancyLock);
+}
+
+/// Program-owned PDA holding deposited lamports
+#[account]
+#[derive(InitSpace)]
+pub struct Vault {
+    pub bump: u8,
+}
+
+impl Vault {
+    pub const LEN: usize = space_of!(Vault);
 }
 
 #[error_code]
 pub enum ErrorCode {
     #[msg("Insufficient balance")]
     InsufficientBalance,
-    #[msg("Invalid amount")]
-    InvalidAmount,
-    #[msg("Math underflow")]
-    MathUnderflow,
-    #[msg("Unauthorized")]
-    Unauthorized,
+    #[msg("Reentrant call rejected: account is locked")]
+    Reentrancy = 4,
+    #[msg("Reentrant call rejected: call depth exceeded")]
+    CallDepthExceeded,
+    #[msg("Lock is already held")]
+    LockHeld,
+    #[msg("Lock is not held")]
+    LockNotHeld,
 }
@@ -0,0 +1,97 @@
+// Shared plumbing for the token withdraw examples
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+use anchor_spl::token_interface::{self, Mint, TransferChecked};
+
+/// Move `amount` tokens out of the vault's token account, signed by the
+/// vault PDA
+///
+/// `transfer_checked` is the only transfer Token-2022 accepts for mints with
+/// extensions - including a transfer hook, which means this call can run
+/// code chosen by whoever configured the mint.
+pub fn transfer_from_vault<'info>(
+    token_program: &AccountInfo<'info>,
+    vault_token_account: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    vault_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[b"vault", &[vault_bump]]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: vault_token_account.clone(),
+                mint: mint.to_account_info(),
+                to: to.clone(),
+                authority: vault.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// Invoke `on_token_withdraw(amount)` on the stand-in transfer hook
+///
+/// Like a real Token-2022 hook, it is handed the accounts of the transfer it
+/// runs inside - here also the user's `TokenBalance`, which is everything it
+/// needs to read that balance and call back into this program.
+#[allow(clippy::too_many_arguments)]
+pub fn invoke_token_hook<'info>(
+    hook_program: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    token_balance: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    vault_token_account: &AccountInfo<'info>,
+    user_token_account: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    this_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    // Anchor instruction discriminator: sha256("global:on_token_withdraw")[..8]
+    let mut data = hash(b"global:on_token_withdraw").to_bytes()[..8].to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    let ix = Instruction {
+        program_id: hook_program.key(),
+        accounts: vec![
+            AccountMeta::new(user.key(), true),
+            AccountMeta::new(token_balance.key(), false),
+            AccountMeta::new(vault.key(), false),
+            AccountMeta::new_readonly(mint.key(), false),
+            AccountMeta::new(vault_token_account.key(), false),
+            AccountMeta::new(user_token_account.key(), false),
+            AccountMeta::new_readonly(token_program.key(), false),
+            AccountMeta::new_readonly(this_program.key(), false),
+            AccountMeta::new_readonly(hook_program.key(), false),
+        ],
+        data,
+    };
+
+    invoke(
+        &ix,
+        &[
+            user.clone(),
+            token_balance.clone(),
+            vault.clone(),
+            mint.clone(),
+            vault_token_account.clone(),
+            user_token_account.clone(),
+            token_program.clone(),
+            this_program.clone(),
+            hook_program.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
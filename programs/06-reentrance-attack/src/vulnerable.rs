@@ -1,9 +1,17 @@
 // ❌ VULNERABLE - External call before state update
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::callback::invoke_withdraw_callback;
+use crate::program::ReentranceAttack;
+use crate::secure::{TokenBalance, UserAccount as SharedUserAccount, Vault};
+use crate::tokens::{invoke_token_hook, transfer_from_vault};
 
 pub fn withdraw_vulnerable(ctx: Context<WithdrawVulnerable>, amount: u64) -> Result<()> {
     let user = &ctx.accounts.user;
-    require!(user.balance >= amount, ErrorCode::InsufficientBalance);
+    require!(
+        ctx.accounts.user_account.balance >= amount,
+        ErrorCode::InsufficientBalance
+    );
     
     // ⚠️ DANGER: External call BEFORE state update
     // Attacker can recursively call withdraw
@@ -17,6 +25,92 @@ pub fn withdraw_vulnerable(ctx: Context<WithdrawVulnerable>, amount: u64) -> Res
     Ok(())
 }
 
+/// ❌ Cross-program variant: a callback runs between the debit and the
+/// balance update
+///
+/// While the callback executes, `user_account.balance` on chain still holds
+/// the pre-withdrawal value. A malicious callback reads that stale balance
+/// and tries to call back in for a second withdrawal.
+///
+/// NOTE: Solana's runtime only allows direct self-recursion, so the
+/// re-entrant A -> B -> A call is rejected with `ReentrancyNotAllowed`.
+/// The stale read is still real: a callback that acts on it elsewhere
+/// (another program, an oracle, a lending market) needs no re-entry at all.
+pub fn withdraw_with_callback_vulnerable(
+    ctx: Context<WithdrawWithCallbackVulnerable>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.user_account.balance >= amount,
+        ErrorCode::InsufficientBalance
+    );
+    
+    **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    // ⚠️ DANGER: External call BEFORE state update
+    invoke_withdraw_callback(
+        &ctx.accounts.callback_program,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.user_account.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.this_program.to_account_info(),
+        amount,
+    )?;
+    
+    // ⚠️ State updated AFTER external call - too late!
+    let user_account = &mut ctx.accounts.user_account;
+    user_account.balance = user_account.balance.checked_sub(amount).unwrap();
+    
+    Ok(())
+}
+
+/// ❌ Token variant: the token CPI runs before the balance update
+///
+/// Moving tokens instead of lamports doesn't make the transfer "internal".
+/// With a Token-2022 transfer hook, the token program calls out to the
+/// hook program mid-transfer, and that program sees the stale balance just
+/// like the lamport callback above. `hook_program` stands in for the hook.
+pub fn withdraw_tokens_vulnerable(
+    ctx: Context<WithdrawTokensVulnerable>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.token_balance.balance >= amount,
+        ErrorCode::InsufficientBalance
+    );
+    
+    // ⚠️ DANGER: Token CPI (and the hook it triggers) BEFORE state update
+    transfer_from_vault(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.vault_token_account.to_account_info(),
+        &ctx.accounts.mint,
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        ctx.accounts.vault.bump,
+        amount,
+    )?;
+    
+    invoke_token_hook(
+        &ctx.accounts.hook_program,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.token_balance.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.vault_token_account.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.this_program.to_account_info(),
+        amount,
+    )?;
+    
+    // ⚠️ State updated AFTER external call - too late!
+    let token_balance = &mut ctx.accounts.token_balance;
+    token_balance.balance = token_balance.balance.checked_sub(amount).unwrap();
+    
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct WithdrawVulnerable<'info> {
     #[account(mut)]
@@ -27,6 +121,75 @@ pub struct WithdrawVulnerable<'info> {
     pub vault: AccountInfo<'info>,
 }
 
+#[derive(Accounts)]
+pub struct WithdrawWithCallbackVulnerable<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// Same account type as the secure path, so only the ordering differs
+    #[account(mut)]
+    pub user_account: Account<'info, SharedUserAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    /// ⚠️ Caller-chosen callback program
+    pub callback_program: AccountInfo<'info>,
+    
+    /// Passed to the callback so it can call back in
+    pub this_program: Program<'info, ReentranceAttack>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTokensVulnerable<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// Same account and seeds as the secure path, so only the ordering differs
+    #[account(
+        mut,
+        seeds = [b"token_balance", user.key().as_ref(), mint.key().as_ref()],
+        bump = token_balance.bump
+    )]
+    pub token_balance: Account<'info, TokenBalance>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// CHECK: ⚠️ Stand-in for the mint's transfer hook, never validated
+    pub hook_program: AccountInfo<'info>,
+    
+    /// Passed to the hook so it can call back in
+    pub this_program: Program<'info, ReentranceAttack>,
+}
+
 #[account]
 pub struct UserAccount {
     pub balance: u64,
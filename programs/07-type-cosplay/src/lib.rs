@@ -17,4 +17,24 @@ pub mod type_cosplay {
     pub fn process_secure(ctx: Context<ProcessSecure>) -> Result<()> {
         secure::process_secure(ctx)
     }
+    
+    pub fn process_manual(ctx: Context<ProcessManual>) -> Result<()> {
+        secure::process_manual(ctx)
+    }
+    
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        secure::initialize_config(ctx)
+    }
+    
+    pub fn initialize_user(ctx: Context<InitializeUser>) -> Result<()> {
+        secure::initialize_user(ctx)
+    }
+    
+    pub fn initialize_user_profile(ctx: Context<InitializeUserProfile>) -> Result<()> {
+        secure::initialize_user_profile(ctx)
+    }
+    
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        secure::migrate_config(ctx)
+    }
 }
@@ -1,29 +1,313 @@
 // ✅ SECURE - Anchor handles discriminator
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use security_common::{space_of, SecurityError};
 
 pub fn process_secure(ctx: Context<ProcessSecure>) -> Result<()> {
-    // ✅ Account<'info, Config> verifies discriminator automatically
-    let config = &ctx.accounts.config;
+    // ✅ Account<'info, Config> / Account<'info, ConfigV2> verify each
+    // revision's discriminator automatically
+    let (admin, enabled) = match (&ctx.accounts.config, &ctx.accounts.config_v2) {
+        (Some(config), None) => (config.admin, config.enabled),
+        (None, Some(config)) => (config.admin, config.enabled),
+        _ => return err!(ErrorCode::AmbiguousConfig),
+    };
+    
+    require!(enabled, ErrorCode::ConfigDisabled);
+    msg!("Processing with verified admin: {}", admin);
+    Ok(())
+}
+
+/// ALTERNATIVE: Explicit type tag checked by hand
+///
+/// Anchor's discriminator is a hidden 8-byte type tag. When an account is
+/// parsed manually, the same guarantee has to come from an explicit tag
+/// stored in the data - and it must be checked before anything else in the
+/// account is trusted.
+pub fn process_manual(ctx: Context<ProcessManual>) -> Result<()> {
+    let data = ctx.accounts.config_account.try_borrow_data()?;
+    let config = parse_tagged_config(&data)?;
     
     require!(config.enabled, ErrorCode::ConfigDisabled);
-    msg!("Processing with verified admin: {}", config.admin);
+    msg!("Processing with tag-verified admin: {}", config.admin);
+    Ok(())
+}
+
+/// Read raw account data as a Config the way `Account<'info, Config>` does
+///
+/// `try_deserialize` checks the length and the 8-byte discriminator before
+/// touching the fields, so short or foreign data is a typed error - never a
+/// panic and never a Config.
+pub fn parse_config(data: &[u8]) -> Result<Config> {
+    Config::try_deserialize(&mut &data[..])
+}
+
+/// Read raw account data as a Config the way `process_manual` does
+///
+/// Both layout revisions carry the Config tag, so the version byte after it
+/// decides how the rest is read. A v1 Config comes back in the v2 layout,
+/// with the defaults `migrate_config` would give it.
+pub fn parse_tagged_config(data: &[u8]) -> Result<ConfigV2> {
+    // ✅ Read the tag byte that follows Anchor's 8-byte discriminator
+    let tag_byte = data.get(8..9).ok_or(ErrorCode::WrongAccountType)?;
+    let account_type = AccountType::try_from_slice(tag_byte)
+        .map_err(|_| ErrorCode::WrongAccountType)?;
+    
+    // ✅ Only a Config-tagged account may be interpreted as a Config
+    require!(account_type == AccountType::Config, ErrorCode::WrongAccountType);
+    
+    // ✅ Never read one revision's bytes with the other's layout
+    match data.get(9) {
+        Some(&Config::VERSION) => {
+            Config::try_deserialize_unchecked(&mut &data[..]).map(ConfigV2::from)
+        }
+        Some(&ConfigV2::VERSION) => ConfigV2::try_deserialize_unchecked(&mut &data[..]),
+        _ => err!(ErrorCode::UnsupportedVersion),
+    }
+}
+
+pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+    ctx.accounts.config.set_inner(Config {
+        account_type: AccountType::Config,
+        version: Config::VERSION,
+        admin: ctx.accounts.admin.key(),
+        enabled: true,
+    });
+    Ok(())
+}
+
+pub fn initialize_user(ctx: Context<InitializeUser>) -> Result<()> {
+    ctx.accounts.user.set_inner(User {
+        account_type: AccountType::User,
+        version: User::VERSION,
+        authority: ctx.accounts.authority.key(),
+        active: true,
+    });
     Ok(())
 }
 
+/// Create a profile the caller fully controls
+///
+/// ⚠️ Any wallet can call this and name itself `admin` with `enabled = true`.
+/// That is harmless for a profile - and exactly what an attacker wants if
+/// code that expects a `Config` can be handed this account instead.
+pub fn initialize_user_profile(ctx: Context<InitializeUserProfile>) -> Result<()> {
+    ctx.accounts.profile.set_inner(UserProfile {
+        account_type: AccountType::UserProfile,
+        version: UserProfile::VERSION,
+        admin: ctx.accounts.owner.key(),
+        enabled: true,
+    });
+    Ok(())
+}
+
+/// Upgrade a v1 Config in place to the ConfigV2 layout
+///
+/// The account is taken as raw `AccountInfo` because its type is exactly
+/// what is changing. Everything `Account<'info, T>` would normally check -
+/// owner, discriminator, layout version - is checked here by hand before
+/// the old data is trusted, and the new discriminator is written together
+/// with the new layout so the account can never be read as the wrong type.
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config_info = ctx.accounts.config.to_account_info();
+    
+    let v1 = {
+        let data = config_info.try_borrow_data()?;
+        let discriminator = data.get(..8).ok_or(ErrorCode::WrongAccountType)?;
+        
+        // ✅ A v2 account has a different discriminator - never migrate twice
+        require!(discriminator != ConfigV2::DISCRIMINATOR, ErrorCode::AlreadyMigrated);
+        require!(discriminator == Config::DISCRIMINATOR, ErrorCode::WrongAccountType);
+        
+        let config = Config::try_deserialize(&mut &data[..])?;
+        require!(config.version == Config::VERSION, ErrorCode::UnsupportedVersion);
+        config
+    };
+    
+    // ✅ Only the current admin may rewrite the account
+    require_keys_eq!(v1.admin, ctx.accounts.admin.key(), SecurityError::Unauthorized);
+    
+    // ✅ Top up rent before growing so the account stays rent-exempt
+    let required = Rent::get()?.minimum_balance(ConfigV2::LEN);
+    let shortfall = required.saturating_sub(config_info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: config_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    config_info.resize(ConfigV2::LEN)?;
+    
+    let v2 = ConfigV2::from(v1);
+    
+    // ✅ Discriminator and data are rewritten together
+    let mut data = config_info.try_borrow_mut_data()?;
+    v2.try_serialize(&mut &mut data[..])?;
+    
+    msg!("Config migrated to v{}", ConfigV2::VERSION);
+    Ok(())
+}
+
+/// Pass the config as whichever revision it is, and leave the other out
 #[derive(Accounts)]
 pub struct ProcessSecure<'info> {
     /// ✅ Account type enforces discriminator check
+    pub config: Option<Account<'info, Config>>,
+    
+    /// ✅ Same check against the migrated layout's discriminator
+    pub config_v2: Option<Account<'info, ConfigV2>>,
+}
+
+#[derive(Accounts)]
+pub struct ProcessManual<'info> {
+    /// CHECK: Ownership is checked here, the type tag in the handler
+    #[account(owner = crate::ID)]
+    pub config_account: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(init, payer = admin, space = Config::LEN)]
     pub config: Account<'info, Config>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeUser<'info> {
+    #[account(init, payer = authority, space = User::LEN)]
+    pub user: Account<'info, User>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeUserProfile<'info> {
+    #[account(init, payer = owner, space = UserProfile::LEN)]
+    pub profile: Account<'info, UserProfile>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Discriminator, version and admin are checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub config: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Explicit type tag stored as the first field of every account
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountType {
+    Config,
+    User,
+    Vault,
+    UserProfile,
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct Config {
+    pub account_type: AccountType,
+    pub version: u8,
+    pub admin: Pubkey,
+    pub enabled: bool,
+}
+
+impl Config {
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = space_of!(Config);
+}
+
+/// Second layout revision of Config, produced by `migrate_config`
+#[account]
+#[derive(InitSpace)]
+pub struct ConfigV2 {
+    pub account_type: AccountType,
+    pub version: u8,
     pub admin: Pubkey,
     pub enabled: bool,
+    pub fee_bps: u16,
+}
+
+impl ConfigV2 {
+    pub const VERSION: u8 = 2;
+    pub const LEN: usize = space_of!(ConfigV2);
+}
+
+/// A v1 Config in the v2 layout - what `migrate_config` writes
+impl From<Config> for ConfigV2 {
+    fn from(v1: Config) -> Self {
+        ConfigV2 {
+            account_type: AccountType::Config,
+            version: ConfigV2::VERSION,
+            admin: v1.admin,
+            enabled: v1.enabled,
+            fee_bps: 0,
+        }
+    }
+}
+
+/// ⚠️ Byte-for-byte the same layout as Config - only the tags differ
+#[account]
+#[derive(InitSpace)]
+pub struct User {
+    pub account_type: AccountType,
+    pub version: u8,
+    pub authority: Pubkey,
+    pub active: bool,
+}
+
+impl User {
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = space_of!(User);
+}
+
+/// ⚠️ Same field names, types and order as Config - a collision target
+/// that only the discriminator (and the tag) tell apart
+#[account]
+#[derive(InitSpace)]
+pub struct UserProfile {
+    pub account_type: AccountType,
+    pub version: u8,
+    pub admin: Pubkey,
+    pub enabled: bool,
+}
+
+impl UserProfile {
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = space_of!(UserProfile);
 }
 
 #[error_code]
 pub enum ErrorCode {
     #[msg("Config is disabled")]
     ConfigDisabled,
+    #[msg("Account type tag does not match the expected type")]
+    WrongAccountType,
+    #[msg("Config has already been migrated")]
+    AlreadyMigrated,
+    #[msg("Unsupported account layout version")]
+    UnsupportedVersion,
+    #[msg("Pass exactly one of config and config_v2")]
+    AmbiguousConfig,
 }
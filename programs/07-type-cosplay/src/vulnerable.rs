@@ -1,24 +1,27 @@
 // ❌ VULNERABLE - No discriminator check
 use anchor_lang::prelude::*;
+use crate::secure::Config;
 
 pub fn process_vulnerable(ctx: Context<ProcessVulnerable>) -> Result<()> {
     // ⚠️ Manual deserialization without type checking
     // Attacker can pass wrong account type with similar data layout
-    let data = ctx.accounts.config_account.data.borrow();
-    let parsed_config: Config = try_from_slice(&data[8..])?;
+    let data = ctx.accounts.config_account.try_borrow_data()?;
+    let parsed_config = parse_config_unchecked(&data)?;
     
     msg!("Processing with admin: {}", parsed_config.admin);
     Ok(())
 }
 
+/// Read raw account data as a Config the way `process_vulnerable` does
+///
+/// ⚠️ Skips the discriminator - any 8 bytes are accepted as "Config"
+/// ⚠️ Slices `data[8..]` without a length check - fewer than 8 bytes panics
+pub fn parse_config_unchecked(data: &[u8]) -> Result<Config> {
+    Ok(Config::deserialize(&mut &data[8..])?)
+}
+
 #[derive(Accounts)]
 pub struct ProcessVulnerable<'info> {
     /// ⚠️ AccountInfo without type validation
     pub config_account: AccountInfo<'info>,
 }
-
-#[account]
-pub struct Config {
-    pub admin: Pubkey,
-    pub enabled: bool,
-}
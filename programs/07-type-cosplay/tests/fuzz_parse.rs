@@ -0,0 +1,159 @@
+// Fuzzing the Config parsers with arbitrary account data
+//
+// `parse_config_unchecked` is the slice-and-deserialize that
+// `process_vulnerable` runs; `parse_config` and `parse_tagged_config` are
+// the discriminator- and tag-checked paths. Random buffers show the
+// difference: the vulnerable path panics on short data and accepts any
+// discriminator, the checked paths only ever return a Config (of either
+// layout revision) or a typed error.
+use std::panic::catch_unwind;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use proptest::prelude::*;
+use type_cosplay::secure::{
+    parse_config, parse_tagged_config, AccountType, Config, ConfigV2, ErrorCode, UserProfile,
+};
+use type_cosplay::vulnerable::parse_config_unchecked;
+
+/// Errors a checked parser may return for data that isn't a Config
+fn is_typed_rejection(error: &Error) -> bool {
+    [
+        AnchorErrorCode::AccountDiscriminatorNotFound.into(),
+        AnchorErrorCode::AccountDiscriminatorMismatch.into(),
+        AnchorErrorCode::AccountDidNotDeserialize.into(),
+        Error::from(ErrorCode::WrongAccountType),
+        Error::from(ErrorCode::UnsupportedVersion),
+    ]
+    .contains(error)
+}
+
+/// The error a parser returned (Config has no Debug, so no `unwrap_err`)
+fn rejection<T>(result: Result<T>) -> Error {
+    result.err().expect("data was accepted as a Config")
+}
+
+/// Config's field layout behind an arbitrary 8-byte discriminator
+fn with_discriminator(discriminator: [u8; 8], admin: [u8; 32], enabled: bool) -> Vec<u8> {
+    let body = Config {
+        account_type: AccountType::Config,
+        version: Config::VERSION,
+        admin: Pubkey::new_from_array(admin),
+        enabled,
+    };
+    let mut data = discriminator.to_vec();
+    body.serialize(&mut data).unwrap();
+    data
+}
+
+proptest! {
+    #[test]
+    fn checked_parsers_never_panic(data in prop::collection::vec(any::<u8>(), 0..128)) {
+        let results = [
+            catch_unwind(|| parse_config(&data).map(drop)),
+            catch_unwind(|| parse_tagged_config(&data).map(drop)),
+        ];
+        for result in results {
+            prop_assert!(result.is_ok(), "checked parser panicked on {:?}", data);
+            if let Err(error) = result.unwrap() {
+                prop_assert!(is_typed_rejection(&error), "untyped error {:?}", error);
+            }
+        }
+    }
+
+    #[test]
+    fn discriminator_check_only_accepts_config(data in prop::collection::vec(any::<u8>(), 0..128)) {
+        if parse_config(&data).is_ok() {
+            prop_assert_eq!(&data[..8], Config::DISCRIMINATOR);
+        }
+    }
+
+    #[test]
+    fn unchecked_parser_panics_below_eight_bytes(data in prop::collection::vec(any::<u8>(), 0..8)) {
+        prop_assert!(catch_unwind(|| parse_config_unchecked(&data)).is_err());
+        prop_assert!(parse_config(&data).is_err());
+    }
+
+    #[test]
+    fn unchecked_parser_accepts_any_discriminator(
+        discriminator in any::<[u8; 8]>(),
+        admin in any::<[u8; 32]>(),
+        enabled in any::<bool>(),
+    ) {
+        let data = with_discriminator(discriminator, admin, enabled);
+
+        let parsed = parse_config_unchecked(&data).unwrap();
+        prop_assert_eq!(parsed.admin, Pubkey::new_from_array(admin));
+
+        if discriminator != Config::DISCRIMINATOR {
+            prop_assert_eq!(
+                rejection(parse_config(&data)),
+                AnchorErrorCode::AccountDiscriminatorMismatch.into()
+            );
+        }
+    }
+}
+
+#[test]
+fn user_profile_cosplays_only_past_the_unchecked_parser() {
+    let attacker = Pubkey::new_unique();
+    let mut data = Vec::new();
+    UserProfile {
+        account_type: AccountType::UserProfile,
+        version: UserProfile::VERSION,
+        admin: attacker,
+        enabled: true,
+    }
+    .try_serialize(&mut data)
+    .unwrap();
+
+    assert_eq!(parse_config_unchecked(&data).unwrap().admin, attacker);
+    assert_eq!(
+        rejection(parse_config(&data)),
+        AnchorErrorCode::AccountDiscriminatorMismatch.into()
+    );
+    assert_eq!(
+        rejection(parse_tagged_config(&data)),
+        ErrorCode::WrongAccountType.into()
+    );
+}
+
+#[test]
+fn tagged_parser_reads_each_revision_with_its_own_layout() {
+    let admin = Pubkey::new_unique();
+    let v1 = Config {
+        account_type: AccountType::Config,
+        version: Config::VERSION,
+        admin,
+        enabled: true,
+    };
+    let mut v1_data = Vec::new();
+    v1.clone().try_serialize(&mut v1_data).unwrap();
+    let mut v2_data = Vec::new();
+    ConfigV2 {
+        fee_bps: 250,
+        ..ConfigV2::from(v1)
+    }
+    .try_serialize(&mut v2_data)
+    .unwrap();
+
+    let from_v1 = parse_tagged_config(&v1_data).unwrap();
+    assert_eq!(
+        (from_v1.version, from_v1.admin, from_v1.fee_bps),
+        (2, admin, 0)
+    );
+
+    let from_v2 = parse_tagged_config(&v2_data).unwrap();
+    assert_eq!(
+        (from_v2.version, from_v2.admin, from_v2.fee_bps),
+        (2, admin, 250)
+    );
+
+    // A version byte neither layout claims is refused, not guessed at
+    v2_data[9] = 3;
+    assert_eq!(
+        rejection(parse_tagged_config(&v2_data)),
+        ErrorCode::UnsupportedVersion.into()
+    );
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("GYzoTT1w6BqBpFnhFTznG9maQQakobDPUadTYDAvXfQX");
+
+#[program]
+pub mod closing_accounts {
+    use super::*;
+    
+    pub fn close_insecure(ctx: Context<CloseInsecure>) -> Result<()> {
+        vulnerable::close_insecure(ctx)
+    }
+    
+    pub fn initialize(ctx: Context<Initialize>, balance: u64) -> Result<()> {
+        secure::initialize(ctx, balance)
+    }
+    
+    pub fn use_account(ctx: Context<UseAccount>) -> Result<()> {
+        secure::use_account(ctx)
+    }
+    
+    pub fn close_secure(ctx: Context<CloseSecure>) -> Result<()> {
+        secure::close_secure(ctx)
+    }
+    
+    pub fn force_defund(ctx: Context<ForceDefund>) -> Result<()> {
+        secure::force_defund(ctx)
+    }
+}
@@ -0,0 +1,135 @@
+// ✅ SECURE - Drain, wipe and poison the account
+use anchor_lang::prelude::*;
+use security_common::{checked_transfer_lamports, close_account, space_of, SecurityError};
+
+/// Marks an account as closed for good
+///
+/// No `#[account]` type hashes to all 0xFF, so `Account<'info, T>` refuses to
+/// deserialize a poisoned account even if it is re-funded and revived.
+/// (Older Anchor versions exported this constant; 0.30+ instead closes by
+/// reassigning the account to the System Program and shrinking it to zero.)
+pub const CLOSED_ACCOUNT_DISCRIMINATOR: [u8; 8] = [255; 8];
+
+pub fn initialize(ctx: Context<Initialize>, balance: u64) -> Result<()> {
+    let account = &mut ctx.accounts.account;
+    account.authority = ctx.accounts.authority.key();
+    account.balance = balance;
+    Ok(())
+}
+
+/// Any instruction that trusts the account - e.g. paying out `balance`
+pub fn use_account(ctx: Context<UseAccount>) -> Result<()> {
+    msg!("Using account with balance {}", ctx.accounts.account.balance);
+    Ok(())
+}
+
+pub fn close_secure(ctx: Context<CloseSecure>) -> Result<()> {
+    let account = &ctx.accounts.account;
+    
+    // ✅ CHECKS - Discriminator must be UserAccount (so a poisoned account
+    // can't be closed twice) and the signer must be its authority
+    {
+        let data = account.try_borrow_data()?;
+        let user_account = UserAccount::try_deserialize(&mut &data[..])?;
+        require_keys_eq!(
+            user_account.authority,
+            ctx.accounts.authority.key(),
+            SecurityError::Unauthorized
+        );
+    }
+    
+    // ✅ Transfer out ALL lamports
+    checked_transfer_lamports(account, &ctx.accounts.destination, account.lamports())?;
+    
+    // ✅ Wipe the data and write the closed discriminator
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    data[..8].copy_from_slice(&CLOSED_ACCOUNT_DISCRIMINATOR);
+    
+    msg!("Account closed (SECURE)");
+    Ok(())
+}
+
+/// ✅ Guard against revival: anyone may sweep lamports out of a poisoned
+/// account, so re-funding one is pointless
+///
+/// The sweep finishes the close for good: `close_account` also zeroes the
+/// data and hands the account to the System Program, so it can't be
+/// revived as a program account again.
+pub fn force_defund(ctx: Context<ForceDefund>) -> Result<()> {
+    let account = &ctx.accounts.account;
+    
+    {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR,
+            ErrorCode::AccountNotClosed
+        );
+    }
+    
+    close_account(account, &ctx.accounts.destination)?;
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(init, payer = authority, space = UserAccount::LEN)]
+    pub account: Account<'info, UserAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UseAccount<'info> {
+    #[account(has_one = authority)]
+    pub account: Account<'info, UserAccount>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSecure<'info> {
+    /// CHECK: Deserialized and verified in the handler. A typed
+    /// Account<'info, UserAccount> would re-serialize over the poisoned
+    /// bytes when the instruction exits.
+    #[account(mut, owner = crate::ID)]
+    pub account: AccountInfo<'info>,
+    
+    /// CHECK: Only receives lamports
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ForceDefund<'info> {
+    /// CHECK: Must carry CLOSED_ACCOUNT_DISCRIMINATOR, checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub account: AccountInfo<'info>,
+    
+    /// CHECK: Only receives lamports
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UserAccount {
+    pub authority: Pubkey,
+    pub balance: u64,
+}
+
+impl UserAccount {
+    pub const LEN: usize = space_of!(UserAccount);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Account is not closed")]
+    AccountNotClosed,
+}
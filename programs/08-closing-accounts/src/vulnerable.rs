@@ -0,0 +1,34 @@
+// ❌ VULNERABLE - Closing by draining lamports only
+use anchor_lang::prelude::*;
+use crate::secure::UserAccount;
+
+pub fn close_insecure(ctx: Context<CloseInsecure>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+    let destination = &ctx.accounts.destination;
+    
+    // Move every lamport out of the account
+    let dest_starting_lamports = destination.lamports();
+    **destination.try_borrow_mut_lamports()? = dest_starting_lamports
+        .checked_add(account.lamports())
+        .unwrap();
+    **account.try_borrow_mut_lamports()? = 0;
+    
+    // ⚠️ DANGER: Data and discriminator are left intact
+    // The runtime only garbage-collects zero-lamport accounts at the END of
+    // the transaction. A later instruction in the same transaction can send
+    // rent back and the account survives, still a valid UserAccount.
+    msg!("Account closed (INSECURE)");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseInsecure<'info> {
+    #[account(mut, has_one = authority)]
+    pub account: Account<'info, UserAccount>,
+    
+    /// ⚠️ Receives the lamports - nothing else is cleaned up
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+    
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("EmzXpVEGakRYjo637yD6hR8Gm6b4D2TchbdjF9AmJx4b");
+
+#[program]
+pub mod duplicate_mutable_accounts {
+    use super::*;
+    
+    pub fn transfer_insecure(ctx: Context<TransferInsecure>, amount: u64) -> Result<()> {
+        vulnerable::transfer_insecure(ctx, amount)
+    }
+    
+    pub fn initialize(ctx: Context<Initialize>, balance: u64) -> Result<()> {
+        secure::initialize(ctx, balance)
+    }
+    
+    pub fn transfer_secure(ctx: Context<TransferSecure>, amount: u64) -> Result<()> {
+        secure::transfer_secure(ctx, amount)
+    }
+}
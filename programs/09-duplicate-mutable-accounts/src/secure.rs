@@ -0,0 +1,71 @@
+// ✅ SECURE - Mutable accounts must be distinct
+use anchor_lang::prelude::*;
+use security_common::{space_of, SecurityError};
+
+pub fn initialize(ctx: Context<Initialize>, balance: u64) -> Result<()> {
+    let account = &mut ctx.accounts.account;
+    account.authority = ctx.accounts.authority.key();
+    account.balance = balance;
+    Ok(())
+}
+
+pub fn transfer_secure(ctx: Context<TransferSecure>, amount: u64) -> Result<()> {
+    // ✅ account_a != account_b is enforced by the accounts constraint
+    let account_a = &mut ctx.accounts.account_a;
+    let account_b = &mut ctx.accounts.account_b;
+    
+    account_a.balance = account_a.balance
+        .checked_sub(amount)
+        .ok_or(ErrorCode::InsufficientBalance)?;
+    account_b.balance = account_b.balance
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    msg!("Transferred {} (SECURE)", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(init, payer = authority, space = UserAccount::LEN)]
+    pub account: Account<'info, UserAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferSecure<'info> {
+    #[account(mut, has_one = authority)]
+    pub account_a: Account<'info, UserAccount>,
+    
+    /// ✅ Two mutable slots must never alias the same account
+    #[account(
+        mut,
+        constraint = account_a.key() != account_b.key() @ ErrorCode::DuplicateAccount
+    )]
+    pub account_b: Account<'info, UserAccount>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UserAccount {
+    pub authority: Pubkey,
+    pub balance: u64,
+}
+
+impl UserAccount {
+    pub const LEN: usize = space_of!(UserAccount);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The same account was passed for two mutable slots")]
+    DuplicateAccount,
+    #[msg("Insufficient balance")]
+    InsufficientBalance,
+}
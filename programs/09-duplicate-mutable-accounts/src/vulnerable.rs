@@ -0,0 +1,31 @@
+// ❌ VULNERABLE - Same account accepted in both mutable slots
+use anchor_lang::prelude::*;
+use crate::secure::UserAccount;
+
+pub fn transfer_insecure(ctx: Context<TransferInsecure>, amount: u64) -> Result<()> {
+    // ⚠️ If account_a and account_b are the same account, Anchor gives us
+    // two independent in-memory copies of it
+    let account_a = &mut ctx.accounts.account_a;
+    let account_b = &mut ctx.accounts.account_b;
+    
+    account_a.balance = account_a.balance.checked_sub(amount).unwrap();
+    account_b.balance = account_b.balance.checked_add(amount).unwrap();
+    
+    // ⚠️ Both copies are serialized on exit in declaration order, so
+    // account_b's write (balance + amount) overwrites account_a's debit.
+    // Transferring to yourself mints `amount` out of thin air.
+    msg!("Transferred {} (INSECURE)", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferInsecure<'info> {
+    #[account(mut, has_one = authority)]
+    pub account_a: Account<'info, UserAccount>,
+    
+    /// ⚠️ Nothing stops this being account_a again
+    #[account(mut)]
+    pub account_b: Account<'info, UserAccount>,
+    
+    pub authority: Signer<'info>,
+}
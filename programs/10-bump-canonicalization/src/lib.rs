@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("BvrA1nL5mz1icwNcPidUgyjfhn2hH7VX3fH9o7JXiDvZ");
+
+#[program]
+pub mod bump_canonicalization {
+    use super::*;
+    
+    pub fn initialize_insecure(ctx: Context<InitializeInsecure>, bump: u8) -> Result<()> {
+        vulnerable::initialize_insecure(ctx, bump)
+    }
+    
+    pub fn set_value_insecure(
+        ctx: Context<SetValueInsecure>,
+        value: u64,
+        bump: u8,
+    ) -> Result<()> {
+        vulnerable::set_value_insecure(ctx, value, bump)
+    }
+    
+    pub fn initialize_secure(ctx: Context<InitializeSecure>) -> Result<()> {
+        secure::initialize_secure(ctx)
+    }
+    
+    pub fn set_value_secure(ctx: Context<SetValueSecure>, value: u64) -> Result<()> {
+        secure::set_value_secure(ctx, value)
+    }
+}
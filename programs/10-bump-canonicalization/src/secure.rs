@@ -0,0 +1,66 @@
+// ✅ SECURE - Canonical bump, derived once and stored
+use anchor_lang::prelude::*;
+use security_common::space_of;
+
+pub fn initialize_secure(ctx: Context<InitializeSecure>) -> Result<()> {
+    let user_data = &mut ctx.accounts.user_data;
+    user_data.authority = ctx.accounts.authority.key();
+    user_data.value = 0;
+    
+    // ✅ Anchor found this with find_program_address - the canonical bump
+    user_data.bump = ctx.bumps.user_data;
+    
+    msg!("Initialized user data with canonical bump {}", user_data.bump);
+    Ok(())
+}
+
+pub fn set_value_secure(ctx: Context<SetValueSecure>, value: u64) -> Result<()> {
+    ctx.accounts.user_data.value = value;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeSecure<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// ✅ `bump` with no value: Anchor derives the canonical bump, so only
+    /// one address per seed set can ever be initialized
+    #[account(
+        init,
+        payer = authority,
+        space = UserData::LEN,
+        seeds = [b"user_data", authority.key().as_ref()],
+        bump
+    )]
+    pub user_data: Account<'info, UserData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetValueSecure<'info> {
+    pub authority: Signer<'info>,
+    
+    /// ✅ Reuse the stored canonical bump - no caller input, and no
+    /// repeated find_program_address cost
+    #[account(
+        mut,
+        seeds = [b"user_data", authority.key().as_ref()],
+        bump = user_data.bump,
+        has_one = authority
+    )]
+    pub user_data: Account<'info, UserData>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UserData {
+    pub authority: Pubkey,
+    pub value: u64,
+    pub bump: u8,
+}
+
+impl UserData {
+    pub const LEN: usize = space_of!(UserData);
+}
@@ -0,0 +1,91 @@
+// ❌ VULNERABLE - Trusts a caller-supplied bump
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::secure::UserData;
+
+pub fn initialize_insecure(ctx: Context<InitializeInsecure>, bump: u8) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    
+    // ⚠️ DANGER: create_program_address accepts ANY bump that lands off
+    // the curve - roughly half of all 256 values. Each one is a different,
+    // equally "valid" PDA for the same seeds.
+    let address = Pubkey::create_program_address(
+        &[b"user_data", authority.as_ref(), &[bump]],
+        ctx.program_id,
+    )
+    .map_err(|_| ErrorCode::InvalidPda)?;
+    require_keys_eq!(address, ctx.accounts.user_data.key(), ErrorCode::InvalidPda);
+    
+    let signer_seeds: &[&[&[u8]]] = &[&[b"user_data", authority.as_ref(), &[bump]]];
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::CreateAccount {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.user_data.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Rent::get()?.minimum_balance(UserData::LEN),
+        UserData::LEN as u64,
+        ctx.program_id,
+    )?;
+    
+    let user_data = UserData {
+        authority,
+        value: 0,
+        bump,
+    };
+    let mut data = ctx.accounts.user_data.try_borrow_mut_data()?;
+    user_data.try_serialize(&mut &mut data[..])?;
+    
+    // ⚠️ "One account per user" no longer holds - call again with another
+    // bump and the same user gets a second account
+    msg!("Initialized user data with bump {}", bump);
+    Ok(())
+}
+
+pub fn set_value_insecure(
+    ctx: Context<SetValueInsecure>,
+    value: u64,
+    bump: u8,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    
+    // ⚠️ Same problem on every later call: any off-curve bump passes
+    let address = Pubkey::create_program_address(
+        &[b"user_data", authority.as_ref(), &[bump]],
+        ctx.program_id,
+    )
+    .map_err(|_| ErrorCode::InvalidPda)?;
+    require_keys_eq!(address, ctx.accounts.user_data.key(), ErrorCode::InvalidPda);
+    
+    ctx.accounts.user_data.value = value;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeInsecure<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// ⚠️ Address checked only against the caller's bump
+    #[account(mut)]
+    pub user_data: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetValueInsecure<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority)]
+    pub user_data: Account<'info, UserData>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Account does not match the derived PDA")]
+    InvalidPda,
+}
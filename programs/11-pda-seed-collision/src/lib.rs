@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("EmbyDGqP5x4ALU8PJKL1pf9Qg8Lvz79kpUcnGVqrxpjd");
+
+#[program]
+pub mod pda_seed_collision {
+    use super::*;
+    
+    pub fn register_insecure(
+        ctx: Context<RegisterInsecure>,
+        namespace: String,
+        name: String,
+    ) -> Result<()> {
+        vulnerable::register_insecure(ctx, namespace, name)
+    }
+    
+    pub fn register_secure(
+        ctx: Context<RegisterSecure>,
+        namespace: String,
+        name: String,
+    ) -> Result<()> {
+        secure::register_secure(ctx, namespace, name)
+    }
+}
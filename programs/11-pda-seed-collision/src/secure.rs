@@ -0,0 +1,71 @@
+// ✅ SECURE - Length-prefixed seeds
+use anchor_lang::prelude::*;
+use security_common::space_of;
+
+pub fn register_secure(
+    ctx: Context<RegisterSecure>,
+    namespace: String,
+    name: String,
+) -> Result<()> {
+    require!(
+        namespace.len() <= MAX_FIELD_LEN && name.len() <= MAX_FIELD_LEN,
+        ErrorCode::FieldTooLong
+    );
+    
+    let profile = &mut ctx.accounts.profile;
+    profile.owner = ctx.accounts.owner.key();
+    profile.namespace = namespace;
+    profile.name = name;
+    
+    msg!("Registered {}/{} (SECURE)", profile.namespace, profile.name);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, name: String)]
+pub struct RegisterSecure<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// ✅ Each variable-length seed is preceded by its length, so the byte
+    /// stream is unambiguous: [2]"ab"[1]"c" can never equal [1]"a"[2]"bc".
+    /// Fixed-size seeds (a Pubkey, a u64's le bytes) need no prefix.
+    #[account(
+        init,
+        payer = owner,
+        space = Profile::LEN,
+        seeds = [
+            &[namespace.len() as u8],
+            namespace.as_bytes(),
+            &[name.len() as u8],
+            name.as_bytes(),
+        ],
+        bump
+    )]
+    pub profile: Account<'info, Profile>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// A single PDA seed may be at most 32 bytes
+pub const MAX_FIELD_LEN: usize = 32;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Profile {
+    pub owner: Pubkey,
+    #[max_len(MAX_FIELD_LEN)]
+    pub namespace: String,
+    #[max_len(MAX_FIELD_LEN)]
+    pub name: String,
+}
+
+impl Profile {
+    pub const LEN: usize = space_of!(Profile);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Namespace and name must be at most 32 bytes")]
+    FieldTooLong,
+}
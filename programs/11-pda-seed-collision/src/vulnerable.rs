@@ -0,0 +1,43 @@
+// ❌ VULNERABLE - Variable-length seeds placed back to back
+use anchor_lang::prelude::*;
+use crate::secure::{ErrorCode, Profile, MAX_FIELD_LEN};
+
+pub fn register_insecure(
+    ctx: Context<RegisterInsecure>,
+    namespace: String,
+    name: String,
+) -> Result<()> {
+    require!(
+        namespace.len() <= MAX_FIELD_LEN && name.len() <= MAX_FIELD_LEN,
+        ErrorCode::FieldTooLong
+    );
+    
+    let profile = &mut ctx.accounts.profile;
+    profile.owner = ctx.accounts.owner.key();
+    profile.namespace = namespace;
+    profile.name = name;
+    
+    msg!("Registered {}/{} (INSECURE)", profile.namespace, profile.name);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, name: String)]
+pub struct RegisterInsecure<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// ⚠️ DANGER: PDA derivation hashes the seeds concatenated, with no
+    /// separators. ("ab", "c") and ("a", "bc") both hash "abc" - the same
+    /// address. Whoever registers first squats the other's profile.
+    #[account(
+        init,
+        payer = owner,
+        space = Profile::LEN,
+        seeds = [namespace.as_bytes(), name.as_bytes()],
+        bump
+    )]
+    pub profile: Account<'info, Profile>,
+    
+    pub system_program: Program<'info, System>,
+}
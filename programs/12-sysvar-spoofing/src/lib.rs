@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("DotfnBNNJF9TUFFGarSdNbEPcaBx2RqwxtWyiMuouCW1");
+
+#[program]
+pub mod sysvar_spoofing {
+    use super::*;
+    
+    pub fn unlock_insecure(ctx: Context<UnlockInsecure>) -> Result<()> {
+        vulnerable::unlock_insecure(ctx)
+    }
+    
+    pub fn create_lock(ctx: Context<CreateLock>, amount: u64, unlock_at: i64) -> Result<()> {
+        secure::create_lock(ctx, amount, unlock_at)
+    }
+    
+    pub fn unlock_secure(ctx: Context<UnlockSecure>) -> Result<()> {
+        secure::unlock_secure(ctx)
+    }
+}
@@ -0,0 +1,85 @@
+// ✅ SECURE - Sysvars come from the runtime, not the caller
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use security_common::{close_account, space_of};
+
+pub fn create_lock(ctx: Context<CreateLock>, amount: u64, unlock_at: i64) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.lock.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let lock = &mut ctx.accounts.lock;
+    lock.owner = ctx.accounts.owner.key();
+    lock.unlock_at = unlock_at;
+    
+    msg!("Locked {} lamports until {}", amount, unlock_at);
+    Ok(())
+}
+
+pub fn unlock_secure(ctx: Context<UnlockSecure>) -> Result<()> {
+    // ✅ Clock::get() reads the sysvar straight from the runtime - there is
+    // no account for an attacker to substitute. Rent::get() works the same.
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(now >= ctx.accounts.lock.unlock_at, ErrorCode::StillLocked);
+    
+    // Unlocking returns the locked lamports and the rent to the owner
+    close_account(&ctx.accounts.lock.to_account_info(), &ctx.accounts.owner)?;
+    
+    msg!("Unlocked at timestamp {} (SECURE)", now);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateLock<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(init, payer = owner, space = TimeLock::LEN)]
+    pub lock: Account<'info, TimeLock>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnlockSecure<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(mut, has_one = owner)]
+    pub lock: Account<'info, TimeLock>,
+    
+    /// CHECK: Kept so the interface matches the insecure version. The
+    /// address constraint rejects spoofed clocks; when an instruction
+    /// must accept a sysvar account, this (or `Sysvar<'info, Clock>`)
+    /// is the check to add.
+    #[account(address = sysvar::clock::ID @ ErrorCode::InvalidSysvar)]
+    pub clock: AccountInfo<'info>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TimeLock {
+    pub owner: Pubkey,
+    pub unlock_at: i64,
+}
+
+impl TimeLock {
+    pub const LEN: usize = space_of!(TimeLock);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Lock has not expired yet")]
+    StillLocked,
+    #[msg("Account is not the expected sysvar")]
+    InvalidSysvar,
+}
@@ -0,0 +1,38 @@
+// ❌ VULNERABLE - Reads "the clock" from any account the caller passes
+use anchor_lang::prelude::*;
+use crate::secure::{ErrorCode, TimeLock};
+use security_common::close_account;
+
+/// Byte offset of `unix_timestamp` in the Clock sysvar layout
+/// (slot, epoch_start_timestamp, epoch, leader_schedule_epoch, unix_timestamp)
+const UNIX_TIMESTAMP_OFFSET: usize = 32;
+
+pub fn unlock_insecure(ctx: Context<UnlockInsecure>) -> Result<()> {
+    // ⚠️ DANGER: No check that this is really the Clock sysvar.
+    // An attacker passes their own account with any timestamp they like.
+    let data = ctx.accounts.clock.try_borrow_data()?;
+    let timestamp_bytes: [u8; 8] = data
+        .get(UNIX_TIMESTAMP_OFFSET..UNIX_TIMESTAMP_OFFSET + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(ErrorCode::InvalidSysvar)?;
+    let now = i64::from_le_bytes(timestamp_bytes);
+    
+    require!(now >= ctx.accounts.lock.unlock_at, ErrorCode::StillLocked);
+    
+    close_account(&ctx.accounts.lock.to_account_info(), &ctx.accounts.owner)?;
+    
+    msg!("Unlocked at (claimed) timestamp {} (INSECURE)", now);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnlockInsecure<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(mut, has_one = owner)]
+    pub lock: Account<'info, TimeLock>,
+    
+    /// ⚠️ Supposed to be the Clock sysvar - never verified
+    pub clock: AccountInfo<'info>,
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("8HfEHcsKjzLDVS45X2RyNnMHPtk8WLjnNxPkJ1XG3z3k");
+
+#[program]
+pub mod reload_after_cpi {
+    use super::*;
+    
+    pub fn pay_out_insecure(ctx: Context<PayOutInsecure>, amount: u64, min_reserve: u64) -> Result<()> {
+        vulnerable::pay_out_insecure(ctx, amount, min_reserve)
+    }
+    
+    pub fn pay_out_secure(ctx: Context<PayOutSecure>, amount: u64, min_reserve: u64) -> Result<()> {
+        secure::pay_out_secure(ctx, amount, min_reserve)
+    }
+}
@@ -0,0 +1,48 @@
+// ✅ SECURE - Reloads accounts after a CPI that modifies them
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn pay_out_secure(ctx: Context<PayOutSecure>, amount: u64, min_reserve: u64) -> Result<()> {
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            }
+        ),
+        amount
+    )?;
+    
+    // ✅ Re-deserialize from the account's current data
+    ctx.accounts.source.reload()?;
+    
+    let remaining = ctx.accounts.source.amount;
+    
+    // ✅ Reserve check sees the post-transfer balance. Failing here
+    // reverts the transfer along with the rest of the instruction.
+    require!(remaining >= min_reserve, ErrorCode::BelowReserve);
+    
+    msg!("Paid out {}, reserve now {} (SECURE)", amount, remaining);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PayOutSecure<'info> {
+    #[account(mut, token::authority = authority)]
+    pub source: Account<'info, TokenAccount>,
+    
+    #[account(mut, token::mint = source.mint)]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Payout would leave the source below its minimum reserve")]
+    BelowReserve,
+}
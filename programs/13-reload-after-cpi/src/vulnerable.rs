@@ -0,0 +1,43 @@
+// ❌ VULNERABLE - Trusts account data deserialized before a CPI
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::secure::ErrorCode;
+
+pub fn pay_out_insecure(ctx: Context<PayOutInsecure>, amount: u64, min_reserve: u64) -> Result<()> {
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            }
+        ),
+        amount
+    )?;
+    
+    // ⚠️ DANGER: `source` was deserialized when the instruction started.
+    // The Token Program updated the account data, but this copy still
+    // holds the balance from before the transfer.
+    let remaining = ctx.accounts.source.amount;
+    
+    // ⚠️ Reserve check passes on a balance that no longer exists
+    require!(remaining >= min_reserve, ErrorCode::BelowReserve);
+    
+    msg!("Paid out {}, reserve now {} (INSECURE - stale)", amount, remaining);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PayOutInsecure<'info> {
+    #[account(mut, token::authority = authority)]
+    pub source: Account<'info, TokenAccount>,
+    
+    #[account(mut, token::mint = source.mint)]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("B3buiVTLX6SZQcjtDrimP5zTakAvDK6DoCibjDa41z4t");
+
+#[program]
+pub mod rent_exemption {
+    use super::*;
+    
+    pub fn withdraw_insecure(ctx: Context<WithdrawInsecure>, amount: u64) -> Result<()> {
+        vulnerable::withdraw_insecure(ctx, amount)
+    }
+    
+    pub fn initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
+        secure::initialize_vault(ctx)
+    }
+    
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        secure::deposit(ctx, amount)
+    }
+    
+    pub fn withdraw_secure(ctx: Context<WithdrawSecure>, amount: u64) -> Result<()> {
+        secure::withdraw_secure(ctx, amount)
+    }
+}
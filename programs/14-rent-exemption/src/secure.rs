@@ -0,0 +1,114 @@
+// ✅ SECURE - Withdrawals never cross the rent-exempt minimum
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use security_common::{checked_transfer_lamports, require_nonzero_amount, space_of, SecurityError};
+
+pub fn withdraw_secure(ctx: Context<WithdrawSecure>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    let vault_info = ctx.accounts.vault.to_account_info();
+    
+    // ✅ The part of the balance that keeps the account alive is not
+    // withdrawable - closing the account is a separate, explicit action
+    let rent_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+    let remaining = vault_info
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(SecurityError::InsufficientFunds)?;
+    
+    require!(remaining >= rent_minimum, ErrorCode::WouldBreakRentExemption);
+    
+    checked_transfer_lamports(&vault_info, &ctx.accounts.owner.to_account_info(), amount)?;
+    
+    msg!("Withdrew {} lamports, {} left (SECURE)", amount, remaining);
+    Ok(())
+}
+
+pub fn initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
+    ctx.accounts.vault.set_inner(Vault {
+        owner: ctx.accounts.owner.key(),
+        bump: ctx.bumps.vault,
+    });
+    Ok(())
+}
+
+pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSecure<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"vault", owner.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeVault<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = Vault::LEN,
+        seeds = [b"vault", owner.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"vault", owner.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Program-owned PDA holding the owner's lamports on top of its rent
+#[account]
+#[derive(InitSpace)]
+pub struct Vault {
+    pub owner: Pubkey,
+    pub bump: u8,
+}
+
+impl Vault {
+    pub const LEN: usize = space_of!(Vault);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Withdrawal would leave the account below the rent-exempt minimum")]
+    WouldBreakRentExemption,
+}
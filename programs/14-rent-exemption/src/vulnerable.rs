@@ -0,0 +1,35 @@
+// ❌ VULNERABLE - Withdrawal ignores the rent-exempt minimum
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use security_common::SecurityError;
+use crate::secure::Vault;
+
+pub fn withdraw_insecure(ctx: Context<WithdrawInsecure>, amount: u64) -> Result<()> {
+    let vault_info = ctx.accounts.vault.to_account_info();
+    
+    // ⚠️ Only checks that the lamports exist - not that the account can
+    // survive without them. Draining to zero lets the runtime purge the
+    // vault (and its state) at the end of the transaction; draining to
+    // dust makes the whole transaction fail with an opaque rent error.
+    require!(vault_info.lamports() >= amount, SecurityError::InsufficientFunds);
+    
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    msg!("Withdrew {} lamports (INSECURE)", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawInsecure<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"vault", owner.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
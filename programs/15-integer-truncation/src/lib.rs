@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("zPVScECR8ztcehXwkKRoYPWYHqdkoDnNnmuKnt5ARBX");
+
+#[program]
+pub mod integer_truncation {
+    use super::*;
+    
+    pub fn request_withdrawal_insecure(ctx: Context<RequestWithdrawalInsecure>, amount: u64) -> Result<()> {
+        vulnerable::request_withdrawal_insecure(ctx, amount)
+    }
+    
+    pub fn initialize_ledger(ctx: Context<InitializeLedger>) -> Result<()> {
+        secure::initialize_ledger(ctx)
+    }
+    
+    pub fn request_withdrawal_secure(ctx: Context<RequestWithdrawalSecure>, amount: u64) -> Result<()> {
+        secure::request_withdrawal_secure(ctx, amount)
+    }
+}
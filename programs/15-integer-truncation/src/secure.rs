@@ -0,0 +1,93 @@
+// ✅ SECURE - Checked conversion between integer widths
+use anchor_lang::prelude::*;
+use security_common::{require_nonzero_amount, space_of, SecurityError};
+
+/// Maximum total a user may request per day
+pub const DAILY_LIMIT: u32 = 1_000;
+
+pub fn request_withdrawal_secure(ctx: Context<RequestWithdrawalSecure>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    let ledger = &mut ctx.accounts.ledger;
+    
+    // ✅ try_from fails instead of dropping bits
+    let amount_u32 = u32::try_from(amount).map_err(|_| ErrorCode::Truncation)?;
+    
+    let requested_today = ledger.requested_today
+        .checked_add(amount_u32)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    require!(requested_today <= DAILY_LIMIT, ErrorCode::LimitExceeded);
+    ledger.requested_today = requested_today;
+    
+    // ✅ Limit and payout now describe the same number
+    ledger.pending = ledger.pending
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    msg!("Queued {} (SECURE)", amount);
+    Ok(())
+}
+
+pub fn initialize_ledger(ctx: Context<InitializeLedger>) -> Result<()> {
+    ctx.accounts.ledger.set_inner(Ledger {
+        authority: ctx.accounts.authority.key(),
+        pending: 0,
+        requested_today: 0,
+        bump: ctx.bumps.ledger,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestWithdrawalSecure<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"ledger", authority.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, Ledger>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeLedger<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Ledger::LEN,
+        seeds = [b"ledger", authority.key().as_ref()],
+        bump
+    )]
+    pub ledger: Account<'info, Ledger>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Ledger {
+    pub authority: Pubkey,
+    /// Total amount queued for payout
+    pub pending: u64,
+    /// Running total checked against DAILY_LIMIT
+    pub requested_today: u32,
+    pub bump: u8,
+}
+
+impl Ledger {
+    pub const LEN: usize = space_of!(Ledger);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Amount does not fit in the target integer type")]
+    Truncation,
+    #[msg("Daily withdrawal limit exceeded")]
+    LimitExceeded,
+}
@@ -0,0 +1,42 @@
+// ❌ VULNERABLE - Silent truncation with `as`
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use security_common::SecurityError;
+use crate::secure::{ErrorCode, Ledger, DAILY_LIMIT};
+
+pub fn request_withdrawal_insecure(ctx: Context<RequestWithdrawalInsecure>, amount: u64) -> Result<()> {
+    let ledger = &mut ctx.accounts.ledger;
+    
+    // ⚠️ DANGER: `as` keeps only the low 32 bits.
+    // 4_294_967_396 (2^32 + 100) becomes 100.
+    let amount_u32 = amount as u32;
+    
+    let requested_today = ledger.requested_today
+        .checked_add(amount_u32)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    // ⚠️ Limit is checked against the truncated value...
+    require!(requested_today <= DAILY_LIMIT, ErrorCode::LimitExceeded);
+    ledger.requested_today = requested_today;
+    
+    // ...but the full u64 amount is what gets queued for payout
+    ledger.pending = ledger.pending
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    msg!("Queued {} (counted as {}) (INSECURE)", amount, amount_u32);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestWithdrawalInsecure<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"ledger", authority.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, Ledger>,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("5v5fCx6VVPgg4tw7qvX7mZevbQtihHHfQsFZG8kPHGWo");
+
+#[program]
+pub mod precision_loss {
+    use super::*;
+    
+    pub fn accrue_insecure(ctx: Context<AccrueInsecure>) -> Result<()> {
+        vulnerable::accrue_insecure(ctx)
+    }
+    
+    pub fn initialize_pool(ctx: Context<InitializePool>, total_staked: u64, reward_pool: u64) -> Result<()> {
+        secure::initialize_pool(ctx, total_staked, reward_pool)
+    }
+    
+    pub fn open_position(ctx: Context<OpenPosition>, stake: u64) -> Result<()> {
+        secure::open_position(ctx, stake)
+    }
+    
+    pub fn accrue_secure(ctx: Context<AccrueSecure>) -> Result<()> {
+        secure::accrue_secure(ctx)
+    }
+}
@@ -0,0 +1,115 @@
+// ✅ SECURE - Multiplies first, in u128
+use anchor_lang::prelude::*;
+use security_common::{space_of, SecurityError};
+
+pub fn accrue_secure(ctx: Context<AccrueSecure>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let position = &mut ctx.accounts.position;
+    
+    // ✅ stake * reward_pool keeps full precision; u128 means the product
+    // of two u64 values can't overflow. Only the final division rounds.
+    require!(pool.total_staked > 0, SecurityError::DivisionByZero);
+    let reward = (position.stake as u128)
+        .checked_mul(pool.reward_pool as u128)
+        .ok_or(SecurityError::MathOverflow)?
+        / pool.total_staked as u128;
+    
+    // ✅ Checked narrowing back to u64
+    position.reward = u64::try_from(reward).map_err(|_| SecurityError::MathOverflow)?;
+    
+    msg!("Accrued reward {} (SECURE)", position.reward);
+    Ok(())
+}
+
+pub fn initialize_pool(ctx: Context<InitializePool>, total_staked: u64, reward_pool: u64) -> Result<()> {
+    ctx.accounts.pool.set_inner(Pool {
+        authority: ctx.accounts.authority.key(),
+        total_staked,
+        reward_pool,
+    });
+    Ok(())
+}
+
+pub fn open_position(ctx: Context<OpenPosition>, stake: u64) -> Result<()> {
+    ctx.accounts.position.set_inner(Position {
+        owner: ctx.accounts.owner.key(),
+        pool: ctx.accounts.pool.key(),
+        stake,
+        reward: 0,
+        bump: ctx.bumps.position,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AccrueSecure<'info> {
+    pub owner: Signer<'info>,
+    
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool,
+        seeds = [b"position", pool.key().as_ref(), owner.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(init, payer = authority, space = Pool::LEN)]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenPosition<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = Position::LEN,
+        seeds = [b"position", pool.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub position: Account<'info, Position>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Staking pool - the stake and rewards of every position combined
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    pub authority: Pubkey,
+    pub total_staked: u64,
+    pub reward_pool: u64,
+}
+
+impl Pool {
+    pub const LEN: usize = space_of!(Pool);
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Position {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub stake: u64,
+    pub reward: u64,
+    pub bump: u8,
+}
+
+impl Position {
+    pub const LEN: usize = space_of!(Position);
+}
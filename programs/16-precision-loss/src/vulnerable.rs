@@ -0,0 +1,40 @@
+// ❌ VULNERABLE - Divides before multiplying
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use security_common::SecurityError;
+use crate::secure::{Pool, Position};
+
+pub fn accrue_insecure(ctx: Context<AccrueInsecure>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let position = &mut ctx.accounts.position;
+    
+    // ⚠️ DANGER: integer division truncates first.
+    // 1_000 / 1_000_000_000 == 0, so the multiply has nothing left to scale.
+    let share = position.stake
+        .checked_div(pool.total_staked)
+        .ok_or(SecurityError::DivisionByZero)?;
+    let reward = share
+        .checked_mul(pool.reward_pool)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    position.reward = reward;
+    
+    msg!("Accrued reward {} (INSECURE)", reward);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AccrueInsecure<'info> {
+    pub owner: Signer<'info>,
+    
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool,
+        seeds = [b"position", pool.key().as_ref(), owner.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+}
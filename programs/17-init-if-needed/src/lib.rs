@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("3vTVffbu2fMQtCPwZAhRV276uE9CeYR5peEixksv113Q");
+
+#[program]
+pub mod init_if_needed {
+    use super::*;
+    
+    pub fn deposit_insecure(ctx: Context<DepositInsecure>, amount: u64) -> Result<()> {
+        vulnerable::deposit_insecure(ctx, amount)
+    }
+    
+    pub fn deposit_secure(ctx: Context<DepositSecure>, amount: u64) -> Result<()> {
+        secure::deposit_secure(ctx, amount)
+    }
+    
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        secure::withdraw(ctx, amount)
+    }
+}
@@ -0,0 +1,105 @@
+// ✅ SECURE - Validates the account on both init_if_needed branches
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use security_common::{checked_transfer_lamports, require_nonzero_amount, space_of, SecurityError};
+
+pub fn deposit_secure(ctx: Context<DepositSecure>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    let vault = &mut ctx.accounts.vault;
+    
+    if vault.authority == Pubkey::default() {
+        vault.authority = ctx.accounts.user.key();
+        vault.user = ctx.accounts.user.key();
+        vault.bump = ctx.bumps.vault;
+    }
+    
+    // ✅ Runs whether the vault was just created or already existed -
+    // a vault someone else initialized for this user is rejected
+    require_keys_eq!(vault.authority, ctx.accounts.user.key(), SecurityError::Unauthorized);
+    require_keys_eq!(vault.user, ctx.accounts.user.key(), SecurityError::Unauthorized);
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    vault.balance = vault.balance
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    msg!("Deposited {} for {} (SECURE)", amount, vault.user);
+    Ok(())
+}
+
+/// Withdraw lamports to the vault's authority
+pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+    require_nonzero_amount(amount)?;
+    
+    let vault = &mut ctx.accounts.vault;
+    
+    vault.balance = vault.balance
+        .checked_sub(amount)
+        .ok_or(SecurityError::InsufficientFunds)?;
+    
+    checked_transfer_lamports(
+        &vault.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        amount,
+    )?;
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositSecure<'info> {
+    /// ✅ The user opens their own vault - and pays for it
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = Vault::LEN,
+        seeds = [b"vault", user.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = authority @ SecurityError::Unauthorized,
+        seeds = [b"vault", vault.user.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Vault {
+    /// Who may withdraw
+    pub authority: Pubkey,
+    /// Whose vault this is (part of the PDA seeds)
+    pub user: Pubkey,
+    pub balance: u64,
+    pub bump: u8,
+}
+
+impl Vault {
+    pub const LEN: usize = space_of!(Vault);
+}
@@ -0,0 +1,57 @@
+// ❌ VULNERABLE - Trusts whatever init_if_needed finds
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use security_common::SecurityError;
+use crate::secure::Vault;
+
+pub fn deposit_insecure(ctx: Context<DepositInsecure>, amount: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    
+    // ⚠️ DANGER: only the very first caller sets the fields. Anyone can be
+    // that caller for any user, so the "defaults" are attacker-chosen.
+    if vault.authority == Pubkey::default() {
+        vault.authority = ctx.accounts.payer.key();
+        vault.user = ctx.accounts.user.key();
+        vault.bump = ctx.bumps.vault;
+    }
+    
+    // ⚠️ Already-initialized branch: fields are trusted as-is
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    vault.balance = vault.balance
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    msg!("Deposited {} for {} (INSECURE)", amount, vault.user);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositInsecure<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: ⚠️ Anyone may open a vault on this user's behalf
+    pub user: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Vault::LEN,
+        seeds = [b"vault", user.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("5XwcUiBcZqgEMvaQiDUfqakre74FfTxdWatXou99yE9v");
+
+#[program]
+pub mod remaining_accounts {
+    use super::*;
+    
+    pub fn distribute_insecure(ctx: Context<DistributeInsecure>) -> Result<()> {
+        vulnerable::distribute_insecure(ctx)
+    }
+    
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>, amount: u64) -> Result<()> {
+        secure::initialize_treasury(ctx, amount)
+    }
+    
+    pub fn create_reward(ctx: Context<CreateReward>, owner: Pubkey, amount: u64) -> Result<()> {
+        secure::create_reward(ctx, owner, amount)
+    }
+    
+    pub fn distribute_secure(ctx: Context<DistributeSecure>) -> Result<()> {
+        secure::distribute_secure(ctx)
+    }
+}
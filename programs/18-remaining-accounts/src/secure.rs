@@ -0,0 +1,177 @@
+// ✅ SECURE - Validates every remaining account by hand
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use security_common::{checked_transfer_lamports, space_of, SecurityError};
+
+pub fn distribute_secure(ctx: Context<DistributeSecure>) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    
+    require!(
+        ctx.remaining_accounts.chunks_exact(2).remainder().is_empty(),
+        ErrorCode::InvalidRemainingAccounts
+    );
+    
+    let mut paid: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len() / 2);
+    
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let (reward_info, recipient) = (&pair[0], &pair[1]);
+        
+        // ✅ Owned by this program
+        require_keys_eq!(*reward_info.owner, crate::ID, ErrorCode::InvalidRewardAccount);
+        
+        // ✅ Discriminator checked - it really is a Reward
+        let reward = Reward::try_deserialize(&mut &reward_info.data.borrow()[..])?;
+        
+        // ✅ The canonical PDA for this (epoch, owner), so each owner has
+        // exactly one reward per epoch
+        let (expected, bump) = Pubkey::find_program_address(
+            &[b"reward", &reward.epoch.to_le_bytes(), reward.owner.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(reward_info.key(), expected, ErrorCode::InvalidRewardAccount);
+        require!(reward.bump == bump, ErrorCode::InvalidRewardAccount);
+        
+        // ✅ The same reward can't be listed twice in one crank
+        require!(!paid.contains(&reward_info.key()), ErrorCode::DuplicateReward);
+        paid.push(reward_info.key());
+        
+        require_keys_eq!(recipient.key(), reward.owner, ErrorCode::InvalidRecipient);
+        require!(reward.epoch == treasury.epoch, ErrorCode::WrongEpoch);
+        
+        checked_transfer_lamports(&treasury.to_account_info(), recipient, reward.amount)?;
+        
+        msg!("Paid {} to {} (SECURE)", reward.amount, reward.owner);
+    }
+    
+    // ✅ Only the admin closes an epoch: rewards left off the list become
+    // unpayable once it moves on, so a stranger's empty crank must not move it
+    treasury.epoch = treasury.epoch
+        .checked_add(1)
+        .ok_or(SecurityError::MathOverflow)?;
+    Ok(())
+}
+
+/// Create the treasury PDA and fund it
+pub fn initialize_treasury(ctx: Context<InitializeTreasury>, amount: u64) -> Result<()> {
+    ctx.accounts.treasury.set_inner(Treasury {
+        admin: ctx.accounts.admin.key(),
+        epoch: 0,
+        bump: ctx.bumps.treasury,
+    });
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.admin.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    Ok(())
+}
+
+/// Admin allocates a reward for the current epoch
+pub fn create_reward(ctx: Context<CreateReward>, owner: Pubkey, amount: u64) -> Result<()> {
+    ctx.accounts.reward.set_inner(Reward {
+        owner,
+        epoch: ctx.accounts.treasury.epoch,
+        amount,
+        bump: ctx.bumps.reward,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DistributeSecure<'info> {
+    /// ✅ The crank ends the epoch, so only the admin may run it
+    pub admin: Signer<'info>,
+    
+    /// The reward list itself is validated in the handler
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        has_one = admin @ SecurityError::Unauthorized
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = Treasury::LEN,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct CreateReward<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(seeds = [b"treasury"], bump = treasury.bump, has_one = admin)]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = Reward::LEN,
+        seeds = [b"reward", treasury.epoch.to_le_bytes().as_ref(), owner.as_ref()],
+        bump
+    )]
+    pub reward: Account<'info, Reward>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Program-owned PDA holding reward lamports; its epoch advances per crank
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    pub admin: Pubkey,
+    pub epoch: u64,
+    pub bump: u8,
+}
+
+impl Treasury {
+    pub const LEN: usize = space_of!(Treasury);
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Reward {
+    pub owner: Pubkey,
+    pub epoch: u64,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl Reward {
+    pub const LEN: usize = space_of!(Reward);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("remaining_accounts must be (reward, recipient) pairs")]
+    InvalidRemainingAccounts,
+    #[msg("Reward account is not a valid reward PDA of this program")]
+    InvalidRewardAccount,
+    #[msg("Reward listed more than once")]
+    DuplicateReward,
+    #[msg("Recipient does not match reward owner")]
+    InvalidRecipient,
+    #[msg("Reward is not for the current epoch")]
+    WrongEpoch,
+}
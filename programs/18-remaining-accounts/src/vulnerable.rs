@@ -0,0 +1,44 @@
+// ❌ VULNERABLE - Trusts remaining_accounts without validation
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use security_common::SecurityError;
+use crate::secure::{ErrorCode, Reward, Treasury};
+
+pub fn distribute_insecure(ctx: Context<DistributeInsecure>) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    
+    require!(
+        ctx.remaining_accounts.chunks_exact(2).remainder().is_empty(),
+        ErrorCode::InvalidRemainingAccounts
+    );
+    
+    // remaining_accounts: [reward, recipient, reward, recipient, ...]
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let (reward_info, recipient) = (&pair[0], &pair[1]);
+        
+        // ⚠️ DANGER: Anchor applies no checks to remaining_accounts.
+        // No owner check, no discriminator, no PDA derivation - any account
+        // laid out like a Reward is believed.
+        let reward = Reward::try_deserialize_unchecked(&mut &reward_info.data.borrow()[..])?;
+        
+        require_keys_eq!(recipient.key(), reward.owner, ErrorCode::InvalidRecipient);
+        require!(reward.epoch == treasury.epoch, ErrorCode::WrongEpoch);
+        
+        **treasury.to_account_info().try_borrow_mut_lamports()? -= reward.amount;
+        **recipient.try_borrow_mut_lamports()? += reward.amount;
+        
+        msg!("Paid {} to {} (INSECURE)", reward.amount, reward.owner);
+    }
+    
+    treasury.epoch = treasury.epoch
+        .checked_add(1)
+        .ok_or(SecurityError::MathOverflow)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DistributeInsecure<'info> {
+    /// Permissionless crank - anyone may submit the reward list
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+}
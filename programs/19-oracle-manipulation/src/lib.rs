@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("HwVvoEM5Zh826esqgGCb7FbgLtUNRQMkdKYzjR6jK3td");
+
+#[program]
+pub mod oracle_manipulation {
+    use super::*;
+    
+    pub fn borrow_insecure(ctx: Context<BorrowInsecure>, amount: u64) -> Result<()> {
+        vulnerable::borrow_insecure(ctx, amount)
+    }
+    
+    pub fn initialize_feed(ctx: Context<InitializeFeed>) -> Result<()> {
+        secure::initialize_feed(ctx)
+    }
+    
+    pub fn update_price(ctx: Context<UpdatePrice>, price: u64, conf: u64, publish_slot: u64) -> Result<()> {
+        secure::update_price(ctx, price, conf, publish_slot)
+    }
+    
+    pub fn initialize_market(ctx: Context<InitializeMarket>) -> Result<()> {
+        secure::initialize_market(ctx)
+    }
+    
+    pub fn open_loan(ctx: Context<OpenLoan>, collateral: u64) -> Result<()> {
+        secure::open_loan(ctx, collateral)
+    }
+    
+    pub fn borrow_secure(ctx: Context<BorrowSecure>, amount: u64) -> Result<()> {
+        secure::borrow_secure(ctx, amount)
+    }
+}
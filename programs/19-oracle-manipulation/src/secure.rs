@@ -0,0 +1,239 @@
+// ✅ SECURE - Rejects stale or low-confidence prices
+use anchor_lang::prelude::*;
+use security_common::{space_of, SecurityError};
+
+/// Oldest price accepted, in slots (~10 seconds)
+pub const MAX_STALENESS_SLOTS: u64 = 25;
+/// Widest confidence interval accepted, in basis points of the price
+pub const MAX_CONF_BPS: u64 = 100;
+/// Loans may borrow up to 50% of collateral value
+pub const LTV_BPS: u64 = 5_000;
+/// Prices are quoted with 6 decimals
+pub const PRICE_DECIMALS: u32 = 6;
+
+pub fn borrow_secure(ctx: Context<BorrowSecure>, amount: u64) -> Result<()> {
+    let feed = &ctx.accounts.price_feed;
+    let loan = &mut ctx.accounts.loan;
+    
+    require!(feed.price > 0, ErrorCode::InvalidPrice);
+    
+    // ✅ Fresh: published within the last MAX_STALENESS_SLOTS. A slot
+    // from the future is rejected, not counted as age zero
+    let current_slot = Clock::get()?.slot;
+    let age = current_slot
+        .checked_sub(feed.publish_slot)
+        .ok_or(ErrorCode::FuturePrice)?;
+    require!(age <= MAX_STALENESS_SLOTS, ErrorCode::StalePrice);
+    
+    // ✅ Precise: conf / price <= MAX_CONF_BPS / 10_000
+    require!(
+        (feed.conf as u128) * 10_000 <= (feed.price as u128) * MAX_CONF_BPS as u128,
+        ErrorCode::WideConfidence
+    );
+    
+    let limit = max_borrow(loan.collateral, feed.price)?;
+    
+    let borrowed = loan.borrowed
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    require!(borrowed <= limit, ErrorCode::InsufficientCollateral);
+    loan.borrowed = borrowed;
+    
+    msg!("Borrowed {} against limit {} (SECURE)", amount, limit);
+    Ok(())
+}
+
+/// Maximum borrow for `collateral` units at `price` (6 decimals)
+pub fn max_borrow(collateral: u64, price: u64) -> Result<u64> {
+    let value = (collateral as u128)
+        .checked_mul(price as u128)
+        .ok_or(SecurityError::MathOverflow)?
+        / 10u128.pow(PRICE_DECIMALS);
+    let limit = value * LTV_BPS as u128 / 10_000;
+    
+    u64::try_from(limit).map_err(|_| SecurityError::MathOverflow.into())
+}
+
+pub fn initialize_feed(ctx: Context<InitializeFeed>) -> Result<()> {
+    ctx.accounts.price_feed.set_inner(PriceFeed {
+        authority: ctx.accounts.authority.key(),
+        price: 0,
+        conf: 0,
+        publish_slot: 0,
+    });
+    Ok(())
+}
+
+/// Publish a price - stands in for an oracle network's update
+pub fn update_price(ctx: Context<UpdatePrice>, price: u64, conf: u64, publish_slot: u64) -> Result<()> {
+    let feed = &mut ctx.accounts.price_feed;
+    feed.price = price;
+    feed.conf = conf;
+    feed.publish_slot = publish_slot;
+    Ok(())
+}
+
+/// Create a lending market that trusts exactly one price feed
+///
+/// The market is the admin's: loans opened against it borrow its liquidity,
+/// so a market someone creates with their own feed only puts their own
+/// funds at risk.
+pub fn initialize_market(ctx: Context<InitializeMarket>) -> Result<()> {
+    ctx.accounts.market.set_inner(Market {
+        admin: ctx.accounts.admin.key(),
+        price_feed: ctx.accounts.price_feed.key(),
+        bump: ctx.bumps.market,
+    });
+    Ok(())
+}
+
+pub fn open_loan(ctx: Context<OpenLoan>, collateral: u64) -> Result<()> {
+    ctx.accounts.loan.set_inner(Loan {
+        owner: ctx.accounts.owner.key(),
+        market: ctx.accounts.market.key(),
+        collateral,
+        borrowed: 0,
+        bump: ctx.bumps.loan,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BorrowSecure<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = market,
+        seeds = [b"loan", market.key().as_ref(), owner.key().as_ref()],
+        bump = loan.bump
+    )]
+    pub loan: Account<'info, Loan>,
+    
+    /// ✅ The market the loan was opened against, which names its feed
+    #[account(has_one = price_feed @ ErrorCode::WrongPriceFeed)]
+    pub market: Account<'info, Market>,
+    
+    /// ✅ Only the market's own feed. Account<PriceFeed> alone would accept
+    /// a feed anyone initialized and published to - fresh, tight and wrong
+    pub price_feed: Account<'info, PriceFeed>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeFeed<'info> {
+    #[account(init, payer = authority, space = PriceFeed::LEN)]
+    pub price_feed: Account<'info, PriceFeed>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePrice<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority)]
+    pub price_feed: Account<'info, PriceFeed>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeMarket<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = Market::LEN,
+        seeds = [b"market", admin.key().as_ref()],
+        bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    /// The feed this market values collateral with
+    pub price_feed: Account<'info, PriceFeed>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenLoan<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = Loan::LEN,
+        seeds = [b"loan", market.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub loan: Account<'info, Loan>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Minimal price account modeled on Pyth's price/conf/publish fields
+#[account]
+#[derive(InitSpace)]
+pub struct PriceFeed {
+    pub authority: Pubkey,
+    /// Price with PRICE_DECIMALS decimals
+    pub price: u64,
+    /// Confidence interval (±), same units as price
+    pub conf: u64,
+    pub publish_slot: u64,
+}
+
+impl PriceFeed {
+    pub const LEN: usize = space_of!(PriceFeed);
+}
+
+/// A lending market, at `[b"market", admin]`
+#[account]
+#[derive(InitSpace)]
+pub struct Market {
+    pub admin: Pubkey,
+    /// The only feed loans in this market are valued with
+    pub price_feed: Pubkey,
+    pub bump: u8,
+}
+
+impl Market {
+    pub const LEN: usize = space_of!(Market);
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Loan {
+    pub owner: Pubkey,
+    pub market: Pubkey,
+    pub collateral: u64,
+    pub borrowed: u64,
+    pub bump: u8,
+}
+
+impl Loan {
+    pub const LEN: usize = space_of!(Loan);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Oracle price is stale")]
+    StalePrice,
+    #[msg("Oracle confidence interval is too wide")]
+    WideConfidence,
+    #[msg("Oracle price is invalid")]
+    InvalidPrice,
+    #[msg("Borrow exceeds collateral limit")]
+    InsufficientCollateral,
+    #[msg("Oracle price is published in the future")]
+    FuturePrice,
+    #[msg("Price feed is not the market's feed")]
+    WrongPriceFeed,
+}
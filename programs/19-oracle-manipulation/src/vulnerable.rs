@@ -0,0 +1,39 @@
+// ❌ VULNERABLE - Uses the oracle price without checking its quality
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use security_common::SecurityError;
+use crate::secure::{max_borrow, ErrorCode, Loan, PriceFeed};
+
+pub fn borrow_insecure(ctx: Context<BorrowInsecure>, amount: u64) -> Result<()> {
+    let feed = &ctx.accounts.price_feed;
+    let loan = &mut ctx.accounts.loan;
+    
+    // ⚠️ DANGER: the price may be hours old, or so uncertain that the
+    // publisher's own confidence band spans a fraction of the value
+    let limit = max_borrow(loan.collateral, feed.price)?;
+    
+    let borrowed = loan.borrowed
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    require!(borrowed <= limit, ErrorCode::InsufficientCollateral);
+    loan.borrowed = borrowed;
+    
+    msg!("Borrowed {} against limit {} (INSECURE)", amount, limit);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BorrowInsecure<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"loan", loan.market.as_ref(), owner.key().as_ref()],
+        bump = loan.bump
+    )]
+    pub loan: Account<'info, Loan>,
+    
+    /// ⚠️ Any PriceFeed - not even tied to the loan's market
+    pub price_feed: Account<'info, PriceFeed>,
+}
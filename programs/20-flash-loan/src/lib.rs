@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("Ft4xsxzoKsesCtbg22BPJne88ttE12rYAjq7xuz4xDhM");
+
+#[program]
+pub mod flash_loan {
+    use super::*;
+    
+    pub fn borrow_insecure(ctx: Context<BorrowInsecure>, amount: u64, will_repay: bool) -> Result<()> {
+        vulnerable::borrow_insecure(ctx, amount, will_repay)
+    }
+    
+    pub fn initialize_pool(ctx: Context<InitializePool>, amount: u64) -> Result<()> {
+        secure::initialize_pool(ctx, amount)
+    }
+    
+    pub fn borrow_secure(ctx: Context<BorrowSecure>, amount: u64) -> Result<()> {
+        secure::borrow_secure(ctx, amount)
+    }
+    
+    pub fn repay(ctx: Context<Repay>, amount: u64) -> Result<()> {
+        secure::repay(ctx, amount)
+    }
+}
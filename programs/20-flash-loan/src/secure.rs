@@ -0,0 +1,175 @@
+// ✅ SECURE - Repayment enforced inside the same transaction
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use security_common::{checked_transfer_lamports, space_of};
+
+pub fn borrow_secure(ctx: Context<BorrowSecure>, amount: u64) -> Result<()> {
+    let pool_key = ctx.accounts.pool.key();
+    let instructions = &ctx.accounts.instructions;
+    
+    // ✅ One loan at a time - two borrows can't share one repay
+    require!(ctx.accounts.pool.outstanding == 0, ErrorCode::LoanActive);
+    
+    // ✅ Look ahead in this transaction for a repay into this pool that
+    // covers the loan. If the transaction doesn't contain one, nothing
+    // executes at all - the loan never leaves the pool.
+    let current = load_current_index_checked(instructions)? as usize;
+    let mut repay_found = false;
+    let mut index = current + 1;
+    while let Ok(ix) = load_instruction_at_checked(index, instructions) {
+        if ix.program_id == crate::ID
+            && ix.data.starts_with(crate::instruction::Repay::DISCRIMINATOR)
+            && ix.accounts.get(1).map(|meta| meta.pubkey) == Some(pool_key)
+        {
+            let repaid = ix.data
+                .get(8..16)
+                .and_then(|bytes| bytes.try_into().ok())
+                .map(u64::from_le_bytes)
+                .unwrap_or(0);
+            if repaid >= amount {
+                repay_found = true;
+                break;
+            }
+        }
+        index += 1;
+    }
+    require!(repay_found, ErrorCode::MissingRepay);
+    
+    let pool_info = ctx.accounts.pool.to_account_info();
+    require!(amount <= available_liquidity(&pool_info)?, ErrorCode::InsufficientLiquidity);
+    
+    // ✅ Remember what must come back; repay checks the real balance
+    let pool = &mut ctx.accounts.pool;
+    pool.outstanding = amount;
+    pool.balance_before = pool_info.lamports();
+    
+    checked_transfer_lamports(&pool_info, &ctx.accounts.borrower.to_account_info(), amount)?;
+    
+    msg!("Lent {} lamports (SECURE)", amount);
+    Ok(())
+}
+
+/// Return a flash loan
+///
+/// The pool's actual lamport balance - not the amount argument - decides
+/// whether the loan is settled.
+pub fn repay(ctx: Context<Repay>, amount: u64) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.borrower.to_account_info(),
+                to: ctx.accounts.pool.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let pool_lamports = ctx.accounts.pool.to_account_info().lamports();
+    let pool = &mut ctx.accounts.pool;
+    
+    // ✅ Vault must be back where it started
+    require!(pool_lamports >= pool.balance_before, ErrorCode::MissingRepay);
+    
+    pool.outstanding = 0;
+    pool.balance_before = 0;
+    
+    msg!("Repaid {} lamports", amount);
+    Ok(())
+}
+
+pub fn initialize_pool(ctx: Context<InitializePool>, amount: u64) -> Result<()> {
+    ctx.accounts.pool.set_inner(Pool {
+        bump: ctx.bumps.pool,
+        outstanding: 0,
+        balance_before: 0,
+    });
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.pool.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    Ok(())
+}
+
+/// Lamports the pool can lend without dropping below rent exemption
+pub fn available_liquidity(pool_info: &AccountInfo) -> Result<u64> {
+    let rent_minimum = Rent::get()?.minimum_balance(pool_info.data_len());
+    Ok(pool_info.lamports().saturating_sub(rent_minimum))
+}
+
+#[derive(Accounts)]
+pub struct BorrowSecure<'info> {
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+    
+    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    pub pool: Account<'info, Pool>,
+    
+    /// CHECK: ✅ Address pinned to the Instructions sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Repay<'info> {
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+    
+    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    pub pool: Account<'info, Pool>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = Pool::LEN,
+        seeds = [b"pool"],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Program-owned PDA holding lendable lamports
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    pub bump: u8,
+    /// Amount of the loan in flight, 0 when none
+    pub outstanding: u64,
+    /// Pool lamports before the loan went out
+    pub balance_before: u64,
+}
+
+impl Pool {
+    pub const LEN: usize = space_of!(Pool);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Flash loan is not repaid in the same transaction")]
+    MissingRepay,
+    #[msg("A flash loan is already in progress")]
+    LoanActive,
+    #[msg("Insufficient liquidity in pool")]
+    InsufficientLiquidity,
+}
@@ -0,0 +1,28 @@
+// ❌ VULNERABLE - Takes the borrower's word for repayment
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use crate::secure::{available_liquidity, ErrorCode, Pool};
+
+pub fn borrow_insecure(ctx: Context<BorrowInsecure>, amount: u64, will_repay: bool) -> Result<()> {
+    // ⚠️ DANGER: a flag in instruction data is a promise, not a payment.
+    // Nothing checks that `repay` is ever called.
+    require!(will_repay, ErrorCode::MissingRepay);
+    
+    let pool_info = ctx.accounts.pool.to_account_info();
+    require!(amount <= available_liquidity(&pool_info)?, ErrorCode::InsufficientLiquidity);
+    
+    **pool_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.borrower.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    msg!("Lent {} lamports (INSECURE)", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BorrowInsecure<'info> {
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+    
+    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    pub pool: Account<'info, Pool>,
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("9AVqo95qMFPjAGoAYFMj9ykbvb2WQWnxLpWwMTCL9cmb");
+
+#[program]
+pub mod signature_verification {
+    use super::*;
+    
+    pub fn redeem_insecure(ctx: Context<RedeemInsecure>, amount: u64, signature: [u8; 64]) -> Result<()> {
+        vulnerable::redeem_insecure(ctx, amount, signature)
+    }
+    
+    pub fn initialize_config(ctx: Context<InitializeConfig>, signer: Pubkey) -> Result<()> {
+        secure::initialize_config(ctx, signer)
+    }
+    
+    pub fn redeem_secure(ctx: Context<RedeemSecure>, amount: u64) -> Result<()> {
+        secure::redeem_secure(ctx, amount)
+    }
+}
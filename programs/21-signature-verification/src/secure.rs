@@ -0,0 +1,178 @@
+// ✅ SECURE - Binds to an Ed25519 verification in the same transaction
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use security_common::{space_of, SecurityError};
+
+/// Offsets pointing at data inside the Ed25519 instruction itself
+const SAME_INSTRUCTION: u16 = u16::MAX;
+/// num_signatures (1) + padding (1) + one Ed25519SignatureOffsets (14)
+const OFFSETS_END: usize = 16;
+
+pub fn redeem_secure(ctx: Context<RedeemSecure>, amount: u64) -> Result<()> {
+    let instructions = &ctx.accounts.instructions;
+    
+    // ✅ The Ed25519 program instruction must come directly before this one.
+    // If its signature check had failed, the whole transaction would have
+    // failed - so its presence means the signature is valid.
+    let current = load_current_index_checked(instructions)? as usize;
+    require!(current > 0, ErrorCode::MissingSignatureVerification);
+    let ix = load_instruction_at_checked(current - 1, instructions)?;
+    require_keys_eq!(ix.program_id, ed25519_program::ID, ErrorCode::MissingSignatureVerification);
+    
+    // ✅ ...but valid for *what*? Parse the offsets and check that it
+    // verified our signer over our message.
+    let data = &ix.data;
+    require!(data.len() >= OFFSETS_END && data[0] == 1, ErrorCode::InvalidSignatureInstruction);
+    
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let signature_ix = read_u16(4);
+    let public_key_offset = read_u16(6) as usize;
+    let public_key_ix = read_u16(8);
+    let message_offset = read_u16(10) as usize;
+    let message_size = read_u16(12) as usize;
+    let message_ix = read_u16(14);
+    
+    // ✅ All data must live in the Ed25519 instruction - otherwise it could
+    // point at bytes in some other instruction we haven't looked at
+    require!(
+        signature_ix == SAME_INSTRUCTION
+            && public_key_ix == SAME_INSTRUCTION
+            && message_ix == SAME_INSTRUCTION,
+        ErrorCode::InvalidSignatureInstruction
+    );
+    
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(ErrorCode::InvalidSignatureInstruction)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(ErrorCode::InvalidSignatureInstruction)?;
+    
+    require!(
+        public_key == ctx.accounts.config.signer.as_ref(),
+        ErrorCode::WrongSigner
+    );
+    require!(
+        message == voucher_message(&ctx.accounts.claimant.key(), amount).as_slice(),
+        ErrorCode::MessageMismatch
+    );
+    
+    ctx.accounts.voucher.set_inner(Voucher {
+        claimant: ctx.accounts.claimant.key(),
+        amount,
+    });
+    
+    msg!("Redeemed voucher for {} (SECURE)", amount);
+    Ok(())
+}
+
+/// The signed message: claimant pubkey followed by the amount (LE)
+pub fn voucher_message(claimant: &Pubkey, amount: u64) -> Vec<u8> {
+    let mut message = claimant.to_bytes().to_vec();
+    message.extend_from_slice(&amount.to_le_bytes());
+    message
+}
+
+/// Record the key whose signatures authorize vouchers
+///
+/// Whoever chooses `signer` can mint any voucher, so the config is a
+/// singleton and only the program's upgrade authority may create it.
+pub fn initialize_config(ctx: Context<InitializeConfig>, signer: Pubkey) -> Result<()> {
+    ctx.accounts.config.set_inner(Config {
+        signer,
+        bump: ctx.bumps.config,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RedeemSecure<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    
+    /// ✅ Only the program's own config - a config the caller made could
+    /// name a signer whose key they hold
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    /// ✅ One voucher per claimant - a signed message can't be replayed
+    #[account(
+        init,
+        payer = claimant,
+        space = Voucher::LEN,
+        seeds = [b"voucher", config.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub voucher: Account<'info, Voucher>,
+    
+    /// CHECK: ✅ Address pinned to the Instructions sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Config::LEN,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// ✅ Only the upgrade authority recorded in this program's ProgramData
+    /// may create the singleton
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::SignatureVerification>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ SecurityError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Holds the off-chain key whose signatures authorize vouchers
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub signer: Pubkey,
+    pub bump: u8,
+}
+
+impl Config {
+    pub const LEN: usize = space_of!(Config);
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Voucher {
+    pub claimant: Pubkey,
+    pub amount: u64,
+}
+
+impl Voucher {
+    pub const LEN: usize = space_of!(Voucher);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("No Ed25519 verification precedes this instruction")]
+    MissingSignatureVerification,
+    #[msg("Ed25519 instruction is malformed or references other instructions")]
+    InvalidSignatureInstruction,
+    #[msg("Message was signed by the wrong key")]
+    WrongSigner,
+    #[msg("Signed message does not match this redemption")]
+    MessageMismatch,
+}
@@ -0,0 +1,38 @@
+// ❌ VULNERABLE - Accepts a signature without verifying it
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use crate::secure::{Config, ErrorCode, Voucher};
+
+pub fn redeem_insecure(ctx: Context<RedeemInsecure>, amount: u64, signature: [u8; 64]) -> Result<()> {
+    // ⚠️ DANGER: checks that *a* signature was supplied, never that the
+    // configured signer produced it over this message. 64 random bytes pass.
+    require!(signature != [0u8; 64], ErrorCode::MissingSignatureVerification);
+    
+    ctx.accounts.voucher.set_inner(Voucher {
+        claimant: ctx.accounts.claimant.key(),
+        amount,
+    });
+    
+    msg!("Redeemed voucher for {} (INSECURE)", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RedeemInsecure<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = claimant,
+        space = Voucher::LEN,
+        seeds = [b"voucher", config.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub voucher: Account<'info, Voucher>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("4uwt6V4ZGv7XrAkG82QcsynmRezbtx8UwRHtRJk7gfWB");
+
+#[program]
+pub mod freeze_authority {
+    use super::*;
+    
+    pub fn cast_vote_insecure(ctx: Context<CastVoteInsecure>) -> Result<()> {
+        vulnerable::cast_vote_insecure(ctx)
+    }
+    
+    pub fn create_proposal(ctx: Context<CreateProposal>) -> Result<()> {
+        secure::create_proposal(ctx)
+    }
+    
+    pub fn cast_vote_secure(ctx: Context<CastVoteSecure>) -> Result<()> {
+        secure::cast_vote_secure(ctx)
+    }
+    
+    pub fn freeze_holder(ctx: Context<FreezeControl>) -> Result<()> {
+        secure::freeze_holder(ctx)
+    }
+    
+    pub fn thaw_holder(ctx: Context<FreezeControl>) -> Result<()> {
+        secure::thaw_holder(ctx)
+    }
+}
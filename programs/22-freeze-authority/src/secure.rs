@@ -0,0 +1,165 @@
+// ✅ SECURE - Rejects frozen accounts; freezes only via the mint's authority
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, FreezeAccount, Mint, ThawAccount, Token, TokenAccount};
+use security_common::{space_of, SecurityError};
+
+pub fn cast_vote_secure(ctx: Context<CastVoteSecure>) -> Result<()> {
+    // ✅ Frozen balances carry no weight
+    require!(!ctx.accounts.voter_token.is_frozen(), ErrorCode::AccountFrozen);
+    
+    let weight = ctx.accounts.voter_token.amount;
+    
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.yes_votes = proposal.yes_votes
+        .checked_add(weight)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    ctx.accounts.vote_record.weight = weight;
+    
+    msg!("Voted with weight {} (SECURE)", weight);
+    Ok(())
+}
+
+/// Freeze a holder's token account
+///
+/// The mint's freeze authority is a PDA of this program, so the only way
+/// to freeze is through this instruction - and only the issuer (the
+/// mint authority) may call it.
+pub fn freeze_holder(ctx: Context<FreezeControl>) -> Result<()> {
+    let mint_key = ctx.accounts.mint.key();
+    let bump = ctx.bumps.freeze_authority;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"freeze_authority", mint_key.as_ref(), &[bump]]];
+    
+    token::freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.holder_token.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.freeze_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+    
+    msg!("Froze {}", ctx.accounts.holder_token.key());
+    Ok(())
+}
+
+pub fn thaw_holder(ctx: Context<FreezeControl>) -> Result<()> {
+    let mint_key = ctx.accounts.mint.key();
+    let bump = ctx.bumps.freeze_authority;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"freeze_authority", mint_key.as_ref(), &[bump]]];
+    
+    token::thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ThawAccount {
+            account: ctx.accounts.holder_token.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.freeze_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+    
+    msg!("Thawed {}", ctx.accounts.holder_token.key());
+    Ok(())
+}
+
+pub fn create_proposal(ctx: Context<CreateProposal>) -> Result<()> {
+    ctx.accounts.proposal.set_inner(Proposal {
+        mint: ctx.accounts.mint.key(),
+        yes_votes: 0,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CastVoteSecure<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        token::mint = proposal.mint,
+        token::authority = voter
+    )]
+    pub voter_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::LEN,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeControl<'info> {
+    pub issuer: Signer<'info>,
+    
+    /// ✅ Only the issuer may freeze, and the program must actually hold
+    /// the mint's freeze authority
+    #[account(
+        constraint = mint.mint_authority == COption::Some(issuer.key()) @ SecurityError::Unauthorized,
+        constraint = mint.freeze_authority == COption::Some(freeze_authority.key()) @ ErrorCode::NotFreezeAuthority
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    /// CHECK: PDA used only as the freeze authority signer
+    #[account(seeds = [b"freeze_authority", mint.key().as_ref()], bump)]
+    pub freeze_authority: AccountInfo<'info>,
+    
+    #[account(mut, token::mint = mint)]
+    pub holder_token: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(init, payer = payer, space = Proposal::LEN)]
+    pub proposal: Account<'info, Proposal>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    /// Governance token whose balances are voting weight
+    pub mint: Pubkey,
+    pub yes_votes: u64,
+}
+
+impl Proposal {
+    pub const LEN: usize = space_of!(Proposal);
+}
+
+/// One vote per voter per proposal
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecord {
+    pub weight: u64,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = space_of!(VoteRecord);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Token account is frozen")]
+    AccountFrozen,
+    #[msg("Program is not the mint's freeze authority")]
+    NotFreezeAuthority,
+}
@@ -0,0 +1,50 @@
+// ❌ VULNERABLE - Counts tokens in frozen accounts
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use security_common::SecurityError;
+use crate::secure::{Proposal, VoteRecord};
+
+pub fn cast_vote_insecure(ctx: Context<CastVoteInsecure>) -> Result<()> {
+    // ⚠️ DANGER: the account may be frozen. Its balance can't move, was
+    // frozen by the issuer for a reason (seized, compromised, sanctioned),
+    // and still carries full voting weight here.
+    let weight = ctx.accounts.voter_token.amount;
+    
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.yes_votes = proposal.yes_votes
+        .checked_add(weight)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    ctx.accounts.vote_record.weight = weight;
+    
+    msg!("Voted with weight {} (INSECURE)", weight);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CastVoteInsecure<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+    
+    /// ⚠️ Mint and owner checked - frozen state ignored
+    #[account(
+        token::mint = proposal.mint,
+        token::authority = voter
+    )]
+    pub voter_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::LEN,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    pub system_program: Program<'info, System>,
+}
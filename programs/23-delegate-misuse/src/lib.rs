@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("Ffk9LX4hxj8MK9c4SAMdnjGeLCkQNChNtNFfiTrLFcn4");
+
+#[program]
+pub mod delegate_misuse {
+    use super::*;
+    
+    pub fn spend_insecure(ctx: Context<SpendInsecure>, amount: u64) -> Result<()> {
+        vulnerable::spend_insecure(ctx, amount)
+    }
+    
+    pub fn open_vault(ctx: Context<OpenVault>) -> Result<()> {
+        secure::open_vault(ctx)
+    }
+    
+    pub fn approve_spender(ctx: Context<ApproveSpender>, amount: u64) -> Result<()> {
+        secure::approve_spender(ctx, amount)
+    }
+    
+    pub fn revoke_spender(ctx: Context<RevokeSpender>) -> Result<()> {
+        secure::revoke_spender(ctx)
+    }
+    
+    pub fn spend_secure(ctx: Context<SpendSecure>, amount: u64) -> Result<()> {
+        secure::spend_secure(ctx, amount)
+    }
+}
@@ -0,0 +1,190 @@
+// ✅ SECURE - Enforces the delegate's allowance and keeps it accurate
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Approve, Mint, Revoke, Token, TokenAccount, Transfer};
+
+pub fn spend_secure(ctx: Context<SpendSecure>, amount: u64) -> Result<()> {
+    let vault_token = &ctx.accounts.vault_token;
+    
+    // ✅ Right delegate...
+    require!(
+        vault_token.delegate == COption::Some(ctx.accounts.spender.key()),
+        ErrorCode::NotDelegate
+    );
+    
+    // ✅ ...within its allowance
+    require!(amount <= vault_token.delegated_amount, ErrorCode::ExceedsDelegatedAmount);
+    let remaining = vault_token.delegated_amount - amount;
+    
+    let user_key = ctx.accounts.user.key();
+    let bump = ctx.bumps.vault_authority;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"vault_authority", user_key.as_ref(), &[bump]]];
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_token.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    
+    // ✅ Owner-signed transfers don't touch the allowance, so update it
+    // here - and revoke the delegate entirely once it's used up
+    if remaining > 0 {
+        token::approve(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Approve {
+                    to: ctx.accounts.vault_token.to_account_info(),
+                    delegate: ctx.accounts.spender.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            remaining,
+        )?;
+    } else {
+        token::revoke(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Revoke {
+                source: ctx.accounts.vault_token.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+    
+    msg!("Delegate spent {}, {} allowance left (SECURE)", amount, remaining);
+    Ok(())
+}
+
+/// Create the user's vault token account, owned by a program PDA
+pub fn open_vault(_ctx: Context<OpenVault>) -> Result<()> {
+    Ok(())
+}
+
+/// Let `spender` spend up to `amount` from the user's vault
+pub fn approve_spender(ctx: Context<ApproveSpender>, amount: u64) -> Result<()> {
+    let user_key = ctx.accounts.user.key();
+    let bump = ctx.bumps.vault_authority;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"vault_authority", user_key.as_ref(), &[bump]]];
+    
+    token::approve(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Approve {
+                to: ctx.accounts.vault_token.to_account_info(),
+                delegate: ctx.accounts.spender.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+/// Remove any delegate from the user's vault
+pub fn revoke_spender(ctx: Context<RevokeSpender>) -> Result<()> {
+    let user_key = ctx.accounts.user.key();
+    let bump = ctx.bumps.vault_authority;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"vault_authority", user_key.as_ref(), &[bump]]];
+    
+    token::revoke(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Revoke {
+            source: ctx.accounts.vault_token.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+#[derive(Accounts)]
+pub struct SpendSecure<'info> {
+    pub spender: Signer<'info>,
+    
+    /// CHECK: Only used to derive the vault PDAs
+    pub user: AccountInfo<'info>,
+    
+    /// CHECK: PDA that owns the vault token account
+    #[account(seeds = [b"vault_authority", user.key().as_ref()], bump)]
+    pub vault_authority: AccountInfo<'info>,
+    
+    #[account(mut, seeds = [b"vault_token", user.key().as_ref()], bump)]
+    pub vault_token: Account<'info, TokenAccount>,
+    
+    #[account(mut, token::mint = vault_token.mint)]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct OpenVault<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    /// CHECK: PDA that owns the vault token account
+    #[account(seeds = [b"vault_authority", user.key().as_ref()], bump)]
+    pub vault_authority: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = user,
+        token::mint = mint,
+        token::authority = vault_authority,
+        seeds = [b"vault_token", user.key().as_ref()],
+        bump
+    )]
+    pub vault_token: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveSpender<'info> {
+    /// ✅ Only the vault's user can grant an allowance
+    pub user: Signer<'info>,
+    
+    /// CHECK: Any account may be approved as a spender
+    pub spender: AccountInfo<'info>,
+    
+    /// CHECK: PDA that owns the vault token account
+    #[account(seeds = [b"vault_authority", user.key().as_ref()], bump)]
+    pub vault_authority: AccountInfo<'info>,
+    
+    #[account(mut, seeds = [b"vault_token", user.key().as_ref()], bump)]
+    pub vault_token: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeSpender<'info> {
+    pub user: Signer<'info>,
+    
+    /// CHECK: PDA that owns the vault token account
+    #[account(seeds = [b"vault_authority", user.key().as_ref()], bump)]
+    pub vault_authority: AccountInfo<'info>,
+    
+    #[account(mut, seeds = [b"vault_token", user.key().as_ref()], bump)]
+    pub vault_token: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Signer is not the vault's delegate")]
+    NotDelegate,
+    #[msg("Amount exceeds the delegated allowance")]
+    ExceedsDelegatedAmount,
+}
@@ -0,0 +1,57 @@
+// ❌ VULNERABLE - Spends for a delegate with the owner's authority
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::secure::ErrorCode;
+
+pub fn spend_insecure(ctx: Context<SpendInsecure>, amount: u64) -> Result<()> {
+    // Checks *who* the delegate is...
+    require!(
+        ctx.accounts.vault_token.delegate == COption::Some(ctx.accounts.spender.key()),
+        ErrorCode::NotDelegate
+    );
+    
+    // ⚠️ DANGER: ...but not `delegated_amount`. And because the vault
+    // authority (the owner) signs, the Token Program never decrements the
+    // allowance either - the delegate can spend the whole vault, repeatedly.
+    let user_key = ctx.accounts.user.key();
+    let bump = ctx.bumps.vault_authority;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"vault_authority", user_key.as_ref(), &[bump]]];
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_token.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    
+    msg!("Delegate spent {} (INSECURE)", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SpendInsecure<'info> {
+    pub spender: Signer<'info>,
+    
+    /// CHECK: Only used to derive the vault PDAs
+    pub user: AccountInfo<'info>,
+    
+    /// CHECK: PDA that owns the vault token account
+    #[account(seeds = [b"vault_authority", user.key().as_ref()], bump)]
+    pub vault_authority: AccountInfo<'info>,
+    
+    #[account(mut, seeds = [b"vault_token", user.key().as_ref()], bump)]
+    pub vault_token: Account<'info, TokenAccount>,
+    
+    #[account(mut, token::mint = vault_token.mint)]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
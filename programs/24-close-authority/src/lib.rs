@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("8i2z6GWDbXZVUXccth4EZJxsp6kwCGTDaCVSq1uaqLzR");
+
+#[program]
+pub mod close_authority {
+    use super::*;
+    
+    pub fn close_escrow_insecure(ctx: Context<CloseEscrowInsecure>) -> Result<()> {
+        vulnerable::close_escrow_insecure(ctx)
+    }
+    
+    pub fn open_escrow(ctx: Context<OpenEscrow>, unlock_at: i64) -> Result<()> {
+        secure::open_escrow(ctx, unlock_at)
+    }
+    
+    pub fn close_escrow_secure(ctx: Context<CloseEscrowSecure>) -> Result<()> {
+        secure::close_escrow_secure(ctx)
+    }
+}
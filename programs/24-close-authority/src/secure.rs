@@ -0,0 +1,165 @@
+// ✅ SECURE - Close destinations are pinned to the maker
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use security_common::{close_account, space_of};
+
+pub fn close_escrow_secure(ctx: Context<CloseEscrowSecure>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.escrow.unlock_at,
+        ErrorCode::StillLocked
+    );
+    
+    // ✅ Destinations were validated by the accounts struct - whoever
+    // cranks the close, everything goes back to the maker
+    sweep_and_close(
+        &ctx.accounts.escrow,
+        &ctx.accounts.escrow_token,
+        &ctx.accounts.refund_token,
+        &ctx.accounts.rent_destination,
+        &ctx.accounts.token_program,
+    )?;
+    
+    msg!("Escrow closed, refunded to {} (SECURE)", ctx.accounts.escrow.maker);
+    Ok(())
+}
+
+/// Move every token out of the escrow, then close its token account and
+/// the escrow itself, both rents going to `rent_destination`
+pub fn sweep_and_close<'info>(
+    escrow: &Account<'info, Escrow>,
+    escrow_token: &Account<'info, TokenAccount>,
+    refund_token: &Account<'info, TokenAccount>,
+    rent_destination: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[b"escrow", escrow.maker.as_ref(), &[escrow.bump]]];
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: escrow_token.to_account_info(),
+                to: refund_token.to_account_info(),
+                authority: escrow.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        escrow_token.amount,
+    )?;
+    
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: escrow_token.to_account_info(),
+            destination: rent_destination.clone(),
+            authority: escrow.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+    
+    close_account(&escrow.to_account_info(), rent_destination)
+}
+
+pub fn open_escrow(ctx: Context<OpenEscrow>, unlock_at: i64) -> Result<()> {
+    ctx.accounts.escrow.set_inner(Escrow {
+        maker: ctx.accounts.maker.key(),
+        mint: ctx.accounts.mint.key(),
+        unlock_at,
+        bump: ctx.bumps.escrow,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseEscrowSecure<'info> {
+    /// Permissionless crank - anyone may close an expired escrow
+    pub closer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.maker.as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    /// ✅ Only the escrow can close its token account - a close authority
+    /// set to anyone else could close it first and take the rent
+    #[account(
+        mut,
+        seeds = [b"escrow_token", escrow.maker.as_ref()],
+        bump,
+        constraint = escrow_token.close_authority == COption::None
+            || escrow_token.close_authority == COption::Some(escrow.key())
+            @ ErrorCode::UnexpectedCloseAuthority
+    )]
+    pub escrow_token: Account<'info, TokenAccount>,
+    
+    /// ✅ Tokens can only go back to the maker
+    #[account(
+        mut,
+        token::mint = escrow.mint,
+        token::authority = escrow.maker
+    )]
+    pub refund_token: Account<'info, TokenAccount>,
+    
+    /// CHECK: ✅ Address pinned to the maker
+    #[account(mut, address = escrow.maker @ ErrorCode::InvalidDestination)]
+    pub rent_destination: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct OpenEscrow<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = maker,
+        space = Escrow::LEN,
+        seeds = [b"escrow", maker.key().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        init,
+        payer = maker,
+        token::mint = mint,
+        token::authority = escrow,
+        seeds = [b"escrow_token", maker.key().as_ref()],
+        bump
+    )]
+    pub escrow_token: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Escrow {
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    /// After this time anyone may close the escrow back to the maker
+    pub unlock_at: i64,
+    pub bump: u8,
+}
+
+impl Escrow {
+    pub const LEN: usize = space_of!(Escrow);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Escrow is still locked")]
+    StillLocked,
+    #[msg("Close destination must be the escrow maker")]
+    InvalidDestination,
+    #[msg("Escrow token account has an unexpected close authority")]
+    UnexpectedCloseAuthority,
+}
@@ -0,0 +1,51 @@
+// ❌ VULNERABLE - Caller chooses where an escrow's funds go on close
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::secure::{sweep_and_close, Escrow, ErrorCode};
+
+pub fn close_escrow_insecure(ctx: Context<CloseEscrowInsecure>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.escrow.unlock_at,
+        ErrorCode::StillLocked
+    );
+    
+    // ⚠️ DANGER: tokens go to `refund_token` and rent to `rent_destination`,
+    // and neither has anything to do with the maker
+    sweep_and_close(
+        &ctx.accounts.escrow,
+        &ctx.accounts.escrow_token,
+        &ctx.accounts.refund_token,
+        &ctx.accounts.rent_destination,
+        &ctx.accounts.token_program,
+    )?;
+    
+    msg!("Escrow closed (INSECURE)");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseEscrowInsecure<'info> {
+    /// Permissionless crank - anyone may close an expired escrow
+    pub closer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.maker.as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(mut, seeds = [b"escrow_token", escrow.maker.as_ref()], bump)]
+    pub escrow_token: Account<'info, TokenAccount>,
+    
+    /// ⚠️ Same mint - but any owner
+    #[account(mut, token::mint = escrow.mint)]
+    pub refund_token: Account<'info, TokenAccount>,
+    
+    /// CHECK: ⚠️ Receives all rent - never validated
+    #[account(mut)]
+    pub rent_destination: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
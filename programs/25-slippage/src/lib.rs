@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("EhNVerZJDksrqUGYerksp2pwCpaou5TLcdupaP9XiFpw");
+
+#[program]
+pub mod slippage {
+    use super::*;
+    
+    pub fn swap_insecure(ctx: Context<SwapInsecure>, amount_in: u64) -> Result<()> {
+        vulnerable::swap_insecure(ctx, amount_in)
+    }
+    
+    pub fn initialize_pool(ctx: Context<InitializePool>, reserve_a: u64, reserve_b: u64) -> Result<()> {
+        secure::initialize_pool(ctx, reserve_a, reserve_b)
+    }
+    
+    pub fn open_trader(ctx: Context<OpenTrader>, balance_a: u64) -> Result<()> {
+        secure::open_trader(ctx, balance_a)
+    }
+    
+    pub fn swap_secure(ctx: Context<SwapSecure>, amount_in: u64, min_amount_out: u64) -> Result<()> {
+        secure::swap_secure(ctx, amount_in, min_amount_out)
+    }
+}
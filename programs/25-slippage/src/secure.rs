@@ -0,0 +1,144 @@
+// ✅ SECURE - Caller sets the worst price they'll accept
+use anchor_lang::prelude::*;
+use security_common::{space_of, SecurityError};
+
+pub fn swap_secure(ctx: Context<SwapSecure>, amount_in: u64, min_amount_out: u64) -> Result<()> {
+    let amount_out = execute_swap(&mut ctx.accounts.pool, &mut ctx.accounts.trader, amount_in)?;
+    
+    // ✅ Worse than the user's limit? Revert the whole swap.
+    require!(amount_out >= min_amount_out, ErrorCode::SlippageExceeded);
+    
+    msg!("Swapped {} A for {} B (SECURE)", amount_in, amount_out);
+    Ok(())
+}
+
+/// Constant-product swap of token A for token B
+///
+/// amount_out = reserve_b * amount_in / (reserve_a + amount_in), which keeps
+/// reserve_a * reserve_b from decreasing. Rounds down in the pool's favor.
+pub fn execute_swap(pool: &mut Pool, trader: &mut Trader, amount_in: u64) -> Result<u64> {
+    require!(amount_in > 0, ErrorCode::ZeroAmount);
+    
+    trader.balance_a = trader.balance_a
+        .checked_sub(amount_in)
+        .ok_or(ErrorCode::InsufficientBalance)?;
+    
+    let new_reserve_a = pool.reserve_a
+        .checked_add(amount_in)
+        .ok_or(SecurityError::MathOverflow)?;
+    let amount_out = u64::try_from(
+        (pool.reserve_b as u128) * (amount_in as u128) / (new_reserve_a as u128),
+    )
+    .map_err(|_| SecurityError::MathOverflow)?;
+    
+    pool.reserve_a = new_reserve_a;
+    pool.reserve_b -= amount_out;
+    trader.balance_b = trader.balance_b
+        .checked_add(amount_out)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    Ok(amount_out)
+}
+
+pub fn initialize_pool(ctx: Context<InitializePool>, reserve_a: u64, reserve_b: u64) -> Result<()> {
+    ctx.accounts.pool.set_inner(Pool { reserve_a, reserve_b });
+    Ok(())
+}
+
+/// Open a trader account with a starting balance of token A
+///
+/// Balances are plain numbers so the pool math can be studied without
+/// token accounts.
+pub fn open_trader(ctx: Context<OpenTrader>, balance_a: u64) -> Result<()> {
+    ctx.accounts.trader.set_inner(Trader {
+        owner: ctx.accounts.owner.key(),
+        pool: ctx.accounts.pool.key(),
+        balance_a,
+        balance_b: 0,
+        bump: ctx.bumps.trader,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SwapSecure<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool,
+        seeds = [b"trader", pool.key().as_ref(), owner.key().as_ref()],
+        bump = trader.bump
+    )]
+    pub trader: Account<'info, Trader>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(init, payer = payer, space = Pool::LEN)]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenTrader<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = Trader::LEN,
+        seeds = [b"trader", pool.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub trader: Account<'info, Trader>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Constant-product pool: reserve_a * reserve_b = k
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+}
+
+impl Pool {
+    pub const LEN: usize = space_of!(Pool);
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Trader {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub balance_a: u64,
+    pub balance_b: u64,
+    pub bump: u8,
+}
+
+impl Trader {
+    pub const LEN: usize = space_of!(Trader);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Output is below the minimum amount out")]
+    SlippageExceeded,
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+    #[msg("Insufficient balance")]
+    InsufficientBalance,
+}
@@ -0,0 +1,31 @@
+// ❌ VULNERABLE - Swap executes at any price
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use crate::secure::{execute_swap, Pool, Trader};
+
+pub fn swap_insecure(ctx: Context<SwapInsecure>, amount_in: u64) -> Result<()> {
+    // ⚠️ DANGER: the user quoted a price off-chain, but the pool may have
+    // moved before this transaction lands - including on purpose, by a
+    // sandwich attacker trading just before and just after it
+    let amount_out = execute_swap(&mut ctx.accounts.pool, &mut ctx.accounts.trader, amount_in)?;
+    
+    msg!("Swapped {} A for {} B (INSECURE)", amount_in, amount_out);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SwapInsecure<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool,
+        seeds = [b"trader", pool.key().as_ref(), owner.key().as_ref()],
+        bump = trader.bump
+    )]
+    pub trader: Account<'info, Trader>,
+}
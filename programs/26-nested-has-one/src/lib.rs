@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("97qYtsTu6WBSsYRC9gQY2oasEKxQ9m1d3xk6EmSF6bKw");
+
+#[program]
+pub mod nested_has_one {
+    use super::*;
+    
+    pub fn update_project_insecure(ctx: Context<UpdateProjectInsecure>, budget: u64) -> Result<()> {
+        vulnerable::update_project_insecure(ctx, budget)
+    }
+    
+    pub fn initialize_organization(ctx: Context<InitializeOrganization>) -> Result<()> {
+        secure::initialize_organization(ctx)
+    }
+    
+    pub fn create_project(ctx: Context<CreateProject>, budget: u64) -> Result<()> {
+        secure::create_project(ctx, budget)
+    }
+    
+    pub fn update_project_secure(ctx: Context<UpdateProjectSecure>, budget: u64) -> Result<()> {
+        secure::update_project_secure(ctx, budget)
+    }
+}
@@ -0,0 +1,95 @@
+// ✅ SECURE - Every link from signer to project is checked
+use anchor_lang::prelude::*;
+use security_common::{space_of, SecurityError};
+
+pub fn update_project_secure(ctx: Context<UpdateProjectSecure>, budget: u64) -> Result<()> {
+    // ✅ By now: signer == organization.authority
+    //            organization == project.organization
+    ctx.accounts.project.budget = budget;
+    
+    msg!("Project budget set to {} (SECURE)", budget);
+    Ok(())
+}
+
+pub fn initialize_organization(ctx: Context<InitializeOrganization>) -> Result<()> {
+    ctx.accounts.organization.set_inner(Organization {
+        authority: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
+
+/// Create a project under an organization the signer controls
+pub fn create_project(ctx: Context<CreateProject>, budget: u64) -> Result<()> {
+    ctx.accounts.project.set_inner(Project {
+        organization: ctx.accounts.organization.key(),
+        budget,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateProjectSecure<'info> {
+    pub authority: Signer<'info>,
+    
+    /// ✅ Link 1: signer -> organization
+    #[account(has_one = authority @ SecurityError::Unauthorized)]
+    pub organization: Account<'info, Organization>,
+    
+    /// ✅ Link 2: organization -> project
+    #[account(mut, has_one = organization @ ErrorCode::ProjectNotInOrganization)]
+    pub project: Account<'info, Project>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeOrganization<'info> {
+    #[account(init, payer = authority, space = Organization::LEN)]
+    pub organization: Account<'info, Organization>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProject<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ SecurityError::Unauthorized)]
+    pub organization: Account<'info, Organization>,
+    
+    #[account(init, payer = authority, space = Project::LEN)]
+    pub project: Account<'info, Project>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Parent account: owns any number of projects
+#[account]
+#[derive(InitSpace)]
+pub struct Organization {
+    pub authority: Pubkey,
+}
+
+impl Organization {
+    pub const LEN: usize = space_of!(Organization);
+}
+
+/// Child account: authority is inherited through `organization`
+#[account]
+#[derive(InitSpace)]
+pub struct Project {
+    pub organization: Pubkey,
+    pub budget: u64,
+}
+
+impl Project {
+    pub const LEN: usize = space_of!(Project);
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Project does not belong to this organization")]
+    ProjectNotInOrganization,
+}
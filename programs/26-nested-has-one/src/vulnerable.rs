@@ -0,0 +1,28 @@
+// ❌ VULNERABLE - Checks the signer against the org, not the project's org
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use security_common::SecurityError;
+use crate::secure::{Organization, Project};
+
+pub fn update_project_insecure(ctx: Context<UpdateProjectInsecure>, budget: u64) -> Result<()> {
+    // ⚠️ DANGER: the signer is proven to control `organization`, but nothing
+    // ties `project` to that organization. Any org authority can pass their
+    // own org next to someone else's project and rewrite it.
+    ctx.accounts.project.budget = budget;
+    
+    msg!("Project budget set to {} (INSECURE)", budget);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateProjectInsecure<'info> {
+    pub authority: Signer<'info>,
+    
+    /// ✅ Signer must be this org's authority...
+    #[account(has_one = authority @ SecurityError::Unauthorized)]
+    pub organization: Account<'info, Organization>,
+    
+    /// ⚠️ ...but this project may belong to any org
+    #[account(mut)]
+    pub project: Account<'info, Project>,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("9CoCUHTGVMSU7KXfG47hnUn1NAVe1ncv2EGcjTs9yi5o");
+
+#[program]
+pub mod realloc {
+    use super::*;
+    
+    pub fn reserve_insecure(ctx: Context<ReserveInsecure>, additional: u32) -> Result<()> {
+        vulnerable::reserve_insecure(ctx, additional)
+    }
+    
+    pub fn initialize_list(ctx: Context<InitializeList>) -> Result<()> {
+        secure::initialize_list(ctx)
+    }
+    
+    pub fn reserve_secure(ctx: Context<ReserveSecure>, additional: u32) -> Result<()> {
+        secure::reserve_secure(ctx, additional)
+    }
+    
+    pub fn push_item(ctx: Context<PushItem>, item: u64) -> Result<()> {
+        secure::push_item(ctx, item)
+    }
+}
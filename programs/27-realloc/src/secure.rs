@@ -0,0 +1,113 @@
+// ✅ SECURE - New bytes are zeroed and their rent is paid
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use security_common::{space_of, SecurityError};
+
+pub fn reserve_secure(ctx: Context<ReserveSecure>, additional: u32) -> Result<()> {
+    let list_info = ctx.accounts.list.to_account_info();
+    let new_len = grown_len(list_info.data_len(), additional)?;
+    
+    // ✅ Pay the rent difference BEFORE growing
+    let required = Rent::get()?.minimum_balance(new_len);
+    let shortfall = required.saturating_sub(list_info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: list_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    
+    // ✅ resize() is realloc(new_len, true): the new region is zero-filled
+    list_info.resize(new_len)?;
+    
+    msg!("List grown to {} bytes (SECURE)", new_len);
+    Ok(())
+}
+
+/// Append an item into capacity reserved earlier
+pub fn push_item(ctx: Context<PushItem>, item: u64) -> Result<()> {
+    let list = &mut ctx.accounts.list;
+    let needed = ItemList::space(list.items.len() + 1);
+    
+    require!(list.to_account_info().data_len() >= needed, ErrorCode::ListFull);
+    list.items.push(item);
+    Ok(())
+}
+
+pub fn initialize_list(ctx: Context<InitializeList>) -> Result<()> {
+    ctx.accounts.list.set_inner(ItemList {
+        authority: ctx.accounts.authority.key(),
+        items: Vec::new(),
+    });
+    Ok(())
+}
+
+/// Account length after reserving room for `additional` more items
+pub fn grown_len(current: usize, additional: u32) -> Result<usize> {
+    (additional as usize)
+        .checked_mul(ItemList::ITEM_SIZE)
+        .and_then(|bytes| current.checked_add(bytes))
+        .ok_or_else(|| SecurityError::MathOverflow.into())
+}
+
+#[derive(Accounts)]
+pub struct ReserveSecure<'info> {
+    /// ✅ Pays for the extra space
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ SecurityError::Unauthorized)]
+    pub list: Account<'info, ItemList>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PushItem<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ SecurityError::Unauthorized)]
+    pub list: Account<'info, ItemList>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeList<'info> {
+    #[account(init, payer = authority, space = ItemList::space(0))]
+    pub list: Account<'info, ItemList>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Growable list: bytes past the serialized `items` are spare capacity
+#[account]
+#[derive(InitSpace)]
+pub struct ItemList {
+    pub authority: Pubkey,
+    /// Sized for an empty list; `space` adds room per item
+    #[max_len(0)]
+    pub items: Vec<u64>,
+}
+
+impl ItemList {
+    pub const ITEM_SIZE: usize = 8;
+    
+    /// The empty list plus `items` slots
+    pub const fn space(items: usize) -> usize {
+        space_of!(ItemList) + items * Self::ITEM_SIZE
+    }
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("No reserved capacity left - grow the list first")]
+    ListFull,
+}
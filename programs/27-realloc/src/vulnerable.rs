@@ -0,0 +1,30 @@
+// ❌ VULNERABLE - Grows the account without zeroing or paying for it
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use security_common::SecurityError;
+use crate::secure::{grown_len, ItemList};
+
+pub fn reserve_insecure(ctx: Context<ReserveInsecure>, additional: u32) -> Result<()> {
+    let list_info = ctx.accounts.list.to_account_info();
+    let new_len = grown_len(list_info.data_len(), additional)?;
+    
+    // ⚠️ DANGER 1: zero_init = false. The new bytes are whatever is already
+    // in the input buffer - if the account shrank earlier in this
+    // instruction, that is its old data, now readable as "empty" slots.
+    //
+    // ⚠️ DANGER 2: no rent top-up. The account needs more lamports to stay
+    // rent-exempt at the larger size; nobody pays them.
+    #[allow(deprecated)]
+    list_info.realloc(new_len, false)?;
+    
+    msg!("List grown to {} bytes (INSECURE)", new_len);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReserveInsecure<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ SecurityError::Unauthorized)]
+    pub list: Account<'info, ItemList>,
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("AaGCb1SmYU1PUrzR5g4Jgsd3ME6Hozv3FJbuPAW7WoNs");
+
+#[program]
+pub mod vesting {
+    use super::*;
+    
+    pub fn release_insecure(ctx: Context<ReleaseInsecure>, now: i64) -> Result<()> {
+        vulnerable::release_insecure(ctx, now)
+    }
+    
+    pub fn create_schedule(
+        ctx: Context<CreateSchedule>,
+        total_amount: u64,
+        start_ts: i64,
+        cliff_seconds: i64,
+        duration_seconds: i64,
+    ) -> Result<()> {
+        secure::create_schedule(ctx, total_amount, start_ts, cliff_seconds, duration_seconds)
+    }
+    
+    pub fn release_secure(ctx: Context<ReleaseSecure>) -> Result<()> {
+        secure::release_secure(ctx)
+    }
+}
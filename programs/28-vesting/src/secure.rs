@@ -0,0 +1,144 @@
+// ✅ SECURE - Time comes from the Clock sysvar
+use anchor_lang::prelude::*;
+use security_common::{space_of, SecurityError};
+
+pub fn release_secure(ctx: Context<ReleaseSecure>) -> Result<()> {
+    // ✅ The runtime's clock - not something the caller can choose
+    let now = Clock::get()?.unix_timestamp;
+    let released = release_vested(&mut ctx.accounts.schedule, now)?;
+    
+    msg!("Released {} (SECURE)", released);
+    Ok(())
+}
+
+/// Move everything vested by `now` but not yet released into `released`
+///
+/// Returns the amount released by this call.
+pub fn release_vested(schedule: &mut VestingSchedule, now: i64) -> Result<u64> {
+    let vested = schedule.vested_amount(now)?;
+    let releasable = vested
+        .checked_sub(schedule.released)
+        .ok_or(SecurityError::MathOverflow)?;
+    
+    require!(releasable > 0, ErrorCode::NothingToRelease);
+    
+    schedule.released = vested;
+    Ok(releasable)
+}
+
+/// Create a cliff + linear vesting schedule for `beneficiary`
+///
+/// Nothing vests before `start_ts + cliff_seconds`. From then on the vested
+/// amount follows the straight line from `start_ts` to
+/// `start_ts + duration_seconds`, so the cliff releases its accrued share at
+/// once and the rest streams in.
+pub fn create_schedule(
+    ctx: Context<CreateSchedule>,
+    total_amount: u64,
+    start_ts: i64,
+    cliff_seconds: i64,
+    duration_seconds: i64,
+) -> Result<()> {
+    require!(duration_seconds > 0, ErrorCode::InvalidSchedule);
+    require!(
+        (0..=duration_seconds).contains(&cliff_seconds),
+        ErrorCode::InvalidSchedule
+    );
+    
+    ctx.accounts.schedule.set_inner(VestingSchedule {
+        beneficiary: ctx.accounts.beneficiary.key(),
+        total_amount,
+        released: 0,
+        start_ts,
+        cliff_ts: start_ts
+            .checked_add(cliff_seconds)
+            .ok_or(SecurityError::MathOverflow)?,
+        end_ts: start_ts
+            .checked_add(duration_seconds)
+            .ok_or(SecurityError::MathOverflow)?,
+        bump: ctx.bumps.schedule,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReleaseSecure<'info> {
+    pub beneficiary: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = beneficiary,
+        seeds = [b"vesting", beneficiary.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Account<'info, VestingSchedule>,
+}
+
+#[derive(Accounts)]
+pub struct CreateSchedule<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// CHECK: Only stored and used as a seed
+    pub beneficiary: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = VestingSchedule::LEN,
+        seeds = [b"vesting", beneficiary.key().as_ref()],
+        bump
+    )]
+    pub schedule: Account<'info, VestingSchedule>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Balances are plain numbers so the schedule math can be studied without
+/// token accounts
+#[account]
+#[derive(InitSpace)]
+pub struct VestingSchedule {
+    pub beneficiary: Pubkey,
+    pub total_amount: u64,
+    pub released: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+    pub bump: u8,
+}
+
+impl VestingSchedule {
+    pub const LEN: usize = space_of!(VestingSchedule);
+    
+    /// Amount vested at `now`, clamped to [0, total_amount]
+    pub fn vested_amount(&self, now: i64) -> Result<u64> {
+        // ✅ Before the cliff: nothing
+        if now < self.cliff_ts {
+            return Ok(0);
+        }
+        // ✅ After the end: everything, never more
+        if now >= self.end_ts {
+            return Ok(self.total_amount);
+        }
+        
+        // ✅ In between: total * elapsed / duration, in u128 so the product
+        // can't overflow before the division brings it back down
+        let elapsed = (now - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+        let vested = (self.total_amount as u128)
+            .checked_mul(elapsed)
+            .ok_or(SecurityError::MathOverflow)?
+            / duration;
+        
+        Ok(u64::try_from(vested).map_err(|_| SecurityError::MathOverflow)?)
+    }
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Cliff must be within a non-empty vesting duration")]
+    InvalidSchedule,
+    #[msg("Nothing has vested since the last release")]
+    NothingToRelease,
+}
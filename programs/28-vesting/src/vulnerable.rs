@@ -0,0 +1,26 @@
+// ❌ VULNERABLE - Trusts the caller's idea of what time it is
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use crate::secure::{release_vested, VestingSchedule};
+
+pub fn release_insecure(ctx: Context<ReleaseInsecure>, now: i64) -> Result<()> {
+    // ⚠️ DANGER: `now` is an instruction argument. The beneficiary passes a
+    // timestamp past `end_ts` and the whole grant unlocks on day one.
+    let released = release_vested(&mut ctx.accounts.schedule, now)?;
+    
+    msg!("Released {} (INSECURE)", released);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReleaseInsecure<'info> {
+    pub beneficiary: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = beneficiary,
+        seeds = [b"vesting", beneficiary.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Account<'info, VestingSchedule>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("ATFMMtAU4GEzWnYiKZa5gSAswHHEr7bgXFtX4kpQc9yz");
+
+#[program]
+pub mod dos_unbounded {
+    use super::*;
+    
+    pub fn initialize_unbounded_list(ctx: Context<InitializeUnboundedList>) -> Result<()> {
+        vulnerable::initialize_unbounded_list(ctx)
+    }
+    
+    pub fn append_insecure(ctx: Context<AppendInsecure>, value: u64) -> Result<()> {
+        vulnerable::append_insecure(ctx, value)
+    }
+    
+    pub fn initialize_list(ctx: Context<InitializeList>) -> Result<()> {
+        secure::initialize_list(ctx)
+    }
+    
+    pub fn append_secure(ctx: Context<AppendSecure>, value: u64) -> Result<()> {
+        secure::append_secure(ctx, value)
+    }
+    
+    pub fn remove_entry(ctx: Context<RemoveEntry>, index: u16) -> Result<()> {
+        secure::remove_entry(ctx, index)
+    }
+}
@@ -0,0 +1,103 @@
+// ✅ SECURE - The list has a hard size and a per-user share
+use anchor_lang::prelude::*;
+use security_common::space_of;
+
+/// Total entries the list can ever hold
+pub const MAX_ENTRIES: usize = 16;
+
+/// Entries a single user may hold at once
+pub const MAX_ENTRIES_PER_USER: usize = 4;
+
+pub fn append_secure(ctx: Context<AppendSecure>, value: u64) -> Result<()> {
+    let list = &mut ctx.accounts.list;
+    let author = ctx.accounts.user.key();
+    
+    // ✅ Global bound: worst-case cost of loading the list is fixed
+    require!(list.entries.len() < MAX_ENTRIES, ErrorCode::ListFull);
+    
+    // ✅ Per-user bound: one user can't take every slot
+    let owned = list.entries.iter().filter(|entry| entry.author == author).count();
+    require!(owned < MAX_ENTRIES_PER_USER, ErrorCode::UserLimitReached);
+    
+    list.entries.push(Entry { author, value });
+    
+    msg!("Appended entry {} of {} (SECURE)", list.entries.len(), MAX_ENTRIES);
+    Ok(())
+}
+
+/// Free a slot - only the entry's author can remove it
+pub fn remove_entry(ctx: Context<RemoveEntry>, index: u16) -> Result<()> {
+    let list = &mut ctx.accounts.list;
+    let entry = list.entries.get(index as usize).ok_or(ErrorCode::InvalidIndex)?;
+    
+    require_keys_eq!(entry.author, ctx.accounts.user.key(), ErrorCode::NotEntryAuthor);
+    list.entries.swap_remove(index as usize);
+    Ok(())
+}
+
+pub fn initialize_list(ctx: Context<InitializeList>) -> Result<()> {
+    ctx.accounts.list.entries = Vec::new();
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AppendSecure<'info> {
+    pub user: Signer<'info>,
+    
+    /// ✅ Allocated at full size up front - appends never grow it
+    #[account(mut)]
+    pub list: Account<'info, EntryList>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveEntry<'info> {
+    pub user: Signer<'info>,
+    
+    #[account(mut)]
+    pub list: Account<'info, EntryList>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeList<'info> {
+    #[account(init, payer = payer, space = EntryList::space(MAX_ENTRIES))]
+    pub list: Account<'info, EntryList>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct Entry {
+    pub author: Pubkey,
+    pub value: u64,
+}
+
+/// Shared list anyone can append to
+#[account]
+#[derive(InitSpace)]
+pub struct EntryList {
+    /// Sized for an empty list; `space` adds room per entry
+    #[max_len(0)]
+    pub entries: Vec<Entry>,
+}
+
+impl EntryList {
+    /// The empty list plus `entries` entries
+    pub const fn space(entries: usize) -> usize {
+        space_of!(EntryList) + entries * Entry::INIT_SPACE
+    }
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("List is full")]
+    ListFull,
+    #[msg("User already holds the maximum number of entries")]
+    UserLimitReached,
+    #[msg("No entry at this index")]
+    InvalidIndex,
+    #[msg("Only the entry's author can remove it")]
+    NotEntryAuthor,
+}
@@ -0,0 +1,51 @@
+// ❌ VULNERABLE - Anyone can grow the list forever
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use crate::secure::{Entry, EntryList};
+
+pub fn initialize_unbounded_list(ctx: Context<InitializeUnboundedList>) -> Result<()> {
+    ctx.accounts.list.entries = Vec::new();
+    Ok(())
+}
+
+pub fn append_insecure(ctx: Context<AppendInsecure>, value: u64) -> Result<()> {
+    // ⚠️ DANGER: no cap on the list, no cap per user. Every instruction that
+    // loads this account deserializes ALL entries onto a 32 KiB heap within a
+    // fixed compute budget - past a few hundred entries, nothing that touches
+    // the list can run at all, including this one.
+    ctx.accounts.list.entries.push(Entry {
+        author: ctx.accounts.user.key(),
+        value,
+    });
+    
+    msg!("Appended entry {} (INSECURE)", ctx.accounts.list.entries.len());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeUnboundedList<'info> {
+    #[account(init, payer = payer, space = EntryList::space(0))]
+    pub list: Account<'info, EntryList>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AppendInsecure<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// ⚠️ Grows by one entry per call, paid by whoever appends
+    #[account(
+        mut,
+        realloc = EntryList::space(list.entries.len() + 1),
+        realloc::payer = user,
+        realloc::zero = true
+    )]
+    pub list: Account<'info, EntryList>,
+    
+    pub system_program: Program<'info, System>,
+}
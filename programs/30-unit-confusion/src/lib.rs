@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+pub mod vulnerable;
+pub mod secure;
+pub use vulnerable::*;
+pub use secure::*;
+
+declare_id!("J7xz8cRnyQWG8DirAVbPGv4b8Ncrk9QSHs4uUgfgPmct");
+
+#[program]
+pub mod unit_confusion {
+    use super::*;
+    
+    pub fn quote_payout_insecure(ctx: Context<QuotePayout>, stake_sol: u64, rate_bps: u16) -> Result<u64> {
+        vulnerable::quote_payout_insecure(ctx, stake_sol, rate_bps)
+    }
+    
+    pub fn quote_payout_secure(ctx: Context<QuotePayout>, stake_sol: u64, rate_bps: u16) -> Result<u64> {
+        secure::quote_payout_secure(ctx, stake_sol, rate_bps)
+    }
+}
@@ -0,0 +1,40 @@
+// ✅ SECURE - Every amount is converted to lamports exactly once, explicitly
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use security_common::SecurityError;
+
+pub const BPS_DENOMINATOR: u128 = 10_000;
+
+pub fn quote_payout_secure(_ctx: Context<QuotePayout>, stake_sol: u64, rate_bps: u16) -> Result<u64> {
+    // ✅ Convert at the boundary; everything below works in lamports
+    let stake_lamports = sol_to_lamports(stake_sol)?;
+    let payout = payout_lamports(stake_lamports, rate_bps)?;
+    
+    msg!(
+        "Payout: {} lamports (~{} SOL) (SECURE)",
+        payout,
+        lamports_to_sol(payout)
+    );
+    Ok(payout)
+}
+
+/// Whole SOL to lamports, failing instead of wrapping
+pub fn sol_to_lamports(sol: u64) -> Result<u64> {
+    Ok(sol.checked_mul(LAMPORTS_PER_SOL).ok_or(SecurityError::MathOverflow)?)
+}
+
+/// Lamports to whole SOL, rounded down - for display only
+pub fn lamports_to_sol(lamports: u64) -> u64 {
+    lamports / LAMPORTS_PER_SOL
+}
+
+/// `rate_bps` of a lamport amount, rounded down
+///
+/// ⚠️ Takes lamports. The name says so because the type can't.
+pub fn payout_lamports(stake_lamports: u64, rate_bps: u16) -> Result<u64> {
+    let payout = (stake_lamports as u128) * (rate_bps as u128) / BPS_DENOMINATOR;
+    Ok(u64::try_from(payout).map_err(|_| SecurityError::MathOverflow)?)
+}
+
+#[derive(Accounts)]
+pub struct QuotePayout {}
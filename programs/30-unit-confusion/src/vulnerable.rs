@@ -0,0 +1,15 @@
+// ❌ VULNERABLE - A SOL amount fed to lamport math
+// ⚠️ EDUCATIONAL ONLY: never ship code like this
+use anchor_lang::prelude::*;
+use crate::secure::{payout_lamports, QuotePayout};
+
+pub fn quote_payout_insecure(_ctx: Context<QuotePayout>, stake_sol: u64, rate_bps: u16) -> Result<u64> {
+    // ⚠️ DANGER: `payout_lamports` expects lamports, `stake_sol` is whole SOL.
+    // Both are plain u64, so the compiler is happy and the payout comes out
+    // 1,000,000,000x too small. Swap the mistake around (lamports read as
+    // SOL) and it comes out 1,000,000,000x too large.
+    let payout = payout_lamports(stake_sol, rate_bps)?;
+    
+    msg!("Payout: {} lamports (INSECURE)", payout);
+    Ok(payout)
+}
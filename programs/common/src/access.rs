@@ -0,0 +1,93 @@
+// Role-based access control shared by the example programs
+use anchor_lang::prelude::*;
+
+use crate::SecurityError;
+
+/// A privileged job that a single key is appointed to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// Assigns every role, including its own
+    Admin,
+    /// Day-to-day switches such as pausing
+    Operator,
+    /// Emergency brake only - can halt, never move funds
+    Guardian,
+}
+
+/// Who holds each role, stored inside the account the roles protect
+///
+/// Anchor has no way to declare an account type outside a program (it needs
+/// the program's id), so programs embed this struct in their own account:
+///
+/// ```ignore
+/// #[account]
+/// #[derive(InitSpace)]
+/// pub struct Vault {
+///     pub authority: Pubkey,
+///     pub roles: Roles,
+/// }
+/// ```
+///
+/// An unassigned role holds `Pubkey::default()` and matches nobody.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq, Eq,
+)]
+pub struct Roles {
+    pub admin: Pubkey,
+    pub operator: Pubkey,
+    pub guardian: Pubkey,
+}
+
+impl Roles {
+    /// The key appointed to `role` (`Pubkey::default()` if unassigned)
+    pub fn holder(&self, role: Role) -> Pubkey {
+        match role {
+            Role::Admin => self.admin,
+            Role::Operator => self.operator,
+            Role::Guardian => self.guardian,
+        }
+    }
+
+    /// Appoint `holder` to `role`, replacing whoever held it
+    pub fn assign(&mut self, role: Role, holder: Pubkey) {
+        match role {
+            Role::Admin => self.admin = holder,
+            Role::Operator => self.operator = holder,
+            Role::Guardian => self.guardian = holder,
+        }
+    }
+
+    /// Whether `key` holds `role` - never true for an unassigned role
+    pub fn has_role(&self, key: &Pubkey, role: Role) -> bool {
+        let holder = self.holder(role);
+        holder != Pubkey::default() && holder == *key
+    }
+
+    /// Hand every role held by `old` over to `new`
+    ///
+    /// For key rotation: when a key is replaced, the roles it held must not
+    /// stay behind with the key being retired.
+    pub fn rotate_key(&mut self, old: Pubkey, new: Pubkey) {
+        for role in [Role::Admin, Role::Operator, Role::Guardian] {
+            if self.holder(role) == old {
+                self.assign(role, new);
+            }
+        }
+    }
+}
+
+/// ✅ Fail with `MissingRole` unless `signer` signed and holds `role`
+///
+/// Checks the signature as well as the key, so it is safe to call with a
+/// plain `AccountInfo` - a matching key that didn't sign is still rejected.
+///
+/// ```ignore
+/// require_role(&vault.roles, &ctx.accounts.operator, Role::Operator)?;
+/// ```
+pub fn require_role(roles: &Roles, signer: &AccountInfo, role: Role) -> Result<()> {
+    require!(
+        signer.is_signer && roles.has_role(signer.key, role),
+        SecurityError::MissingRole
+    );
+    Ok(())
+}
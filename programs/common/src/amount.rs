@@ -0,0 +1,22 @@
+// Input validation shared by every deposit and withdrawal
+use anchor_lang::prelude::*;
+
+use crate::SecurityError;
+
+/// Reject a zero `amount` with `InvalidAmount`
+///
+/// A zero deposit or withdrawal moves nothing but still passes every
+/// balance check, bumps counters and emits logs - at best noise, at worst a
+/// way to touch state (timestamps, rate-limit windows) for free. Call this
+/// first thing in the handler so the failure is the same in every program.
+///
+/// ```ignore
+/// pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+///     require_nonzero_amount(amount)?;
+///     ...
+/// }
+/// ```
+pub fn require_nonzero_amount(amount: u64) -> Result<()> {
+    require!(amount > 0, SecurityError::InvalidAmount);
+    Ok(())
+}
@@ -0,0 +1,53 @@
+// Checked balance bookkeeping
+use anchor_lang::prelude::*;
+
+use crate::SecurityError;
+
+/// An account that tracks a single u64 balance
+///
+/// Implementors only point at the field; crediting and debiting go through
+/// the default methods so every program gets the same overflow/underflow
+/// handling instead of re-writing `checked_add(..).ok_or(..)` by hand.
+///
+/// ```ignore
+/// impl Balance for Vault {
+///     fn balance(&self) -> u64 {
+///         self.total_deposited
+///     }
+///
+///     fn balance_mut(&mut self) -> &mut u64 {
+///         &mut self.total_deposited
+///     }
+/// }
+///
+/// vault.checked_credit(amount)?;
+/// ```
+pub trait Balance {
+    fn balance(&self) -> u64;
+
+    fn balance_mut(&mut self) -> &mut u64;
+
+    /// ✅ Add `amount`, failing with `MathOverflow` instead of wrapping
+    ///
+    /// Returns the new balance. On error the balance is left untouched.
+    fn checked_credit(&mut self, amount: u64) -> Result<u64> {
+        let updated = self
+            .balance()
+            .checked_add(amount)
+            .ok_or(SecurityError::MathOverflow)?;
+        *self.balance_mut() = updated;
+        Ok(updated)
+    }
+
+    /// ✅ Subtract `amount`, failing with `MathUnderflow` instead of wrapping
+    ///
+    /// Returns the new balance. On error the balance is left untouched.
+    fn checked_debit(&mut self, amount: u64) -> Result<u64> {
+        let updated = self
+            .balance()
+            .checked_sub(amount)
+            .ok_or(SecurityError::MathUnderflow)?;
+        *self.balance_mut() = updated;
+        Ok(updated)
+    }
+}
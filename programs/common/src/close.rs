@@ -0,0 +1,41 @@
+// Closing program-owned accounts by hand
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::{checked_transfer_lamports, SecurityError};
+
+/// Close `account`, refunding all of its lamports to `destination`
+///
+/// Does what Anchor's `close = destination` constraint does, for code that
+/// has to close an account it holds as a plain `AccountInfo`:
+///
+/// 1. Moves every lamport to `destination` (checked, like
+///    [`checked_transfer_lamports`])
+/// 2. Zeroes the data, so nothing the program wrote survives
+/// 3. Assigns the account to the System Program
+///
+/// If the account is re-funded later in the same transaction it survives
+/// garbage collection as an empty system account - no longer owned by this
+/// program, so `Account<'info, T>` rejects it outright.
+///
+/// ```ignore
+/// close_account(&ctx.accounts.account, &ctx.accounts.destination)?;
+/// ```
+///
+/// ⚠️ Only the lamports' destination is checked here (it can't be the
+/// account itself). Who may close, and where the rent may go, are still the
+/// caller's checks to make.
+pub fn close_account(account: &AccountInfo, destination: &AccountInfo) -> Result<()> {
+    // Closing into itself would zero and disown an account that keeps its rent
+    require_keys_neq!(
+        *account.key,
+        *destination.key,
+        SecurityError::InvalidCloseDestination
+    );
+
+    checked_transfer_lamports(account, destination, account.lamports())?;
+    account.try_borrow_mut_data()?.fill(0);
+    account.assign(&system_program::ID);
+
+    Ok(())
+}
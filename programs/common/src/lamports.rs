@@ -0,0 +1,36 @@
+// Checked lamport transfers between accounts this program may debit
+use anchor_lang::prelude::*;
+
+use crate::SecurityError;
+
+/// Move `amount` lamports from `from` to `to` by editing balances directly
+///
+/// Only valid when this program owns `from` (the runtime rejects debits of
+/// anyone else's account) - for system-owned sources use a System Program
+/// transfer instead. Both sides are checked, so a short source fails with
+/// `InsufficientFunds` and an overflowing destination with `MathOverflow`,
+/// rather than the raw `-=` / `+=` panicking or wrapping.
+///
+/// ```ignore
+/// checked_transfer_lamports(&vault.to_account_info(), &authority, amount)?;
+/// ```
+///
+/// ⚠️ This does not keep `from` rent-exempt - check that separately if the
+/// account must survive the transfer.
+pub fn checked_transfer_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    let debited = from
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(SecurityError::InsufficientFunds)?;
+    **from.try_borrow_mut_lamports()? = debited;
+
+    // Read `to` only after the debit, so passing the same account twice
+    // nets out to zero instead of minting `amount`
+    let credited = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(SecurityError::MathOverflow)?;
+    **to.try_borrow_mut_lamports()? = credited;
+
+    Ok(())
+}
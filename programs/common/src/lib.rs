@@ -0,0 +1,62 @@
+// Shared building blocks for the example programs
+//
+// Not a program: no entrypoint, no `declare_id!`. Programs depend on it as a
+// plain library and re-export what they need next to their own `ErrorCode`.
+use anchor_lang::prelude::*;
+
+pub mod access;
+pub mod amount;
+pub mod balance;
+pub mod close;
+pub mod lamports;
+pub mod math;
+pub mod prelude;
+pub mod space;
+
+pub use access::*;
+pub use amount::*;
+pub use balance::*;
+pub use close::*;
+pub use lamports::*;
+
+/// Errors every example program can run into
+///
+/// Offset to 7000 so the codes never collide with a program's own
+/// `ErrorCode` (which Anchor numbers from 6000). The variant name still
+/// appears in the transaction logs, so clients can match on it as usual.
+///
+/// Programs return these directly instead of declaring look-alike variants,
+/// so the same failure has the same number in every program:
+///
+/// | Variant                   | Code |
+/// | ------------------------- | ---- |
+/// | `MathOverflow`            | 7000 |
+/// | `MathUnderflow`           | 7001 |
+/// | `Unauthorized`            | 7002 |
+/// | `InsufficientFunds`       | 7003 |
+/// | `DivisionByZero`          | 7004 |
+/// | `InvalidAmount`           | 7005 |
+/// | `MissingRole`             | 7006 |
+/// | `InvalidCloseDestination` | 7007 |
+///
+/// ⚠️ Clients key on these numbers. The discriminants are pinned: never
+/// reorder or reuse one - append new variants at the end.
+#[error_code(offset = 7000)]
+pub enum SecurityError {
+    #[msg("Arithmetic overflow")]
+    MathOverflow = 0,
+    #[msg("Arithmetic underflow")]
+    MathUnderflow = 1,
+    #[msg("Unauthorized: signer is not the account authority")]
+    Unauthorized = 2,
+    #[msg("Insufficient funds")]
+    InsufficientFunds = 3,
+    #[msg("Division by zero")]
+    DivisionByZero = 4,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount = 5,
+    #[msg("Signer does not hold the required role")]
+    MissingRole = 6,
+    #[msg("Account cannot be closed into itself")]
+    InvalidCloseDestination = 7,
+}
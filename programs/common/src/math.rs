@@ -0,0 +1,58 @@
+// Checked arithmetic that fails with SecurityError
+
+/// Checked binary arithmetic that reads like the unchecked version
+///
+/// `try_math!(a + b)` expands to `(a).checked_add(b)` and maps `None` to the
+/// matching `SecurityError` variant:
+///
+/// | Expression | Expands to    | Error on failure |
+/// | ---------- | ------------- | ---------------- |
+/// | `a + b`    | `checked_add` | `MathOverflow`   |
+/// | `a - b`    | `checked_sub` | `MathUnderflow`  |
+/// | `a * b`    | `checked_mul` | `MathOverflow`   |
+/// | `a / b`    | `checked_div` | `DivisionByZero` |
+///
+/// The result is a `Result<T, SecurityError>`, so propagate it with `?`:
+///
+/// ```ignore
+/// vault.total_deposited = try_math!(vault.total_deposited + amount)?;
+/// ```
+///
+/// ⚠️ Each invocation checks exactly ONE operator: the input is split at the
+/// first top-level `+ - * /` and everything after it becomes the right-hand
+/// operand. Nest calls for compound expressions, otherwise `a + b * c` would
+/// only check the addition:
+///
+/// ```ignore
+/// let interest = try_math!(try_math!(amount * rate)? / denominator)?;
+/// ```
+#[macro_export]
+macro_rules! try_math {
+    (@lhs [$($lhs:tt)+] + $($rhs:tt)+) => {
+        ($($lhs)+)
+            .checked_add($($rhs)+)
+            .ok_or($crate::SecurityError::MathOverflow)
+    };
+    (@lhs [$($lhs:tt)+] - $($rhs:tt)+) => {
+        ($($lhs)+)
+            .checked_sub($($rhs)+)
+            .ok_or($crate::SecurityError::MathUnderflow)
+    };
+    (@lhs [$($lhs:tt)+] * $($rhs:tt)+) => {
+        ($($lhs)+)
+            .checked_mul($($rhs)+)
+            .ok_or($crate::SecurityError::MathOverflow)
+    };
+    (@lhs [$($lhs:tt)+] / $($rhs:tt)+) => {
+        ($($lhs)+)
+            .checked_div($($rhs)+)
+            .ok_or($crate::SecurityError::DivisionByZero)
+    };
+    // Not an operator yet - move the next token onto the left-hand side
+    (@lhs [$($lhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::try_math!(@lhs [$($lhs)* $next] $($rest)*)
+    };
+    ($($expr:tt)+) => {
+        $crate::try_math!(@lhs [] $($expr)+)
+    };
+}
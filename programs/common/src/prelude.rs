@@ -0,0 +1,13 @@
+// One import for code built on these examples
+//
+// `use security_common::prelude::*;` brings in Anchor's prelude together with
+// the shared error type, the `Balance` trait, checked lamport transfers,
+// account closing, the zero-amount guard, role checks and the checked-math
+// macros. Each program still declares its own id, `#[program]` module and
+// `ErrorCode` - only the building blocks every lesson repeats live here.
+pub use anchor_lang::prelude::*;
+
+pub use crate::{
+    checked_transfer_lamports, close_account, require_nonzero_amount, require_role, space_of,
+    try_math, Balance, Role, Roles, SecurityError,
+};
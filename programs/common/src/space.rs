@@ -0,0 +1,30 @@
+// Account size calculation
+
+/// Bytes to allocate for an `#[account]` struct: discriminator + fields
+///
+/// Hand-counted sizes (`8 + 32 + 8 + ...`) silently desync the moment a
+/// field is added. `space_of!` instead reads the size Anchor derives from the
+/// struct definition itself, so the two can't drift apart:
+///
+/// ```ignore
+/// #[account]
+/// #[derive(InitSpace)]
+/// pub struct Vault {
+///     pub authority: Pubkey,
+///     pub balance: u64,
+/// }
+///
+/// impl Vault {
+///     pub const LEN: usize = space_of!(Vault); // 8 + 32 + 8 = 48
+/// }
+/// ```
+///
+/// ⚠️ `Vec`/`String` fields still need `#[max_len(..)]` - InitSpace can only
+/// reserve what it is told the upper bound is.
+#[macro_export]
+macro_rules! space_of {
+    ($account:ty) => {
+        <$account as ::anchor_lang::Discriminator>::DISCRIMINATOR.len()
+            + <$account as ::anchor_lang::Space>::INIT_SPACE
+    };
+}
@@ -0,0 +1,126 @@
+// require_role: each role is accepted only from its own, signing holder
+use security_common::prelude::*;
+
+const ALL: [Role; 3] = [Role::Admin, Role::Operator, Role::Guardian];
+
+fn roles(admin: Pubkey, operator: Pubkey, guardian: Pubkey) -> Roles {
+    Roles {
+        admin,
+        operator,
+        guardian,
+    }
+}
+
+/// Run `f` with an account for `key`, signed or not
+fn with_signer(key: Pubkey, is_signer: bool, f: impl FnOnce(&AccountInfo)) {
+    let owner = Pubkey::default();
+    let mut lamports = 0;
+    let mut data = [0u8; 0];
+    let account = AccountInfo::new(
+        &key,
+        is_signer,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    f(&account);
+}
+
+fn assert_missing_role(result: Result<()>) {
+    assert_eq!(result.unwrap_err(), SecurityError::MissingRole.into());
+}
+
+#[test]
+fn each_holder_is_allowed_only_its_own_role() {
+    let keys = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let roles = roles(keys[0], keys[1], keys[2]);
+
+    for (key, held) in keys.into_iter().zip(ALL) {
+        with_signer(key, true, |signer| {
+            for role in ALL {
+                let result = require_role(&roles, signer, role);
+                if role == held {
+                    assert!(result.is_ok(), "{role:?} denied to its holder");
+                } else {
+                    assert_missing_role(result);
+                }
+            }
+        });
+    }
+}
+
+#[test]
+fn a_stranger_is_denied_every_role() {
+    let roles = roles(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    with_signer(Pubkey::new_unique(), true, |signer| {
+        for role in ALL {
+            assert_missing_role(require_role(&roles, signer, role));
+        }
+    });
+}
+
+#[test]
+fn the_holder_must_sign() {
+    let admin = Pubkey::new_unique();
+    let roles = roles(admin, admin, admin);
+
+    with_signer(admin, false, |signer| {
+        for role in ALL {
+            assert_missing_role(require_role(&roles, signer, role));
+        }
+    });
+}
+
+#[test]
+fn an_unassigned_role_matches_nobody() {
+    let roles = Roles::default();
+
+    with_signer(Pubkey::default(), true, |signer| {
+        for role in ALL {
+            assert_missing_role(require_role(&roles, signer, role));
+        }
+    });
+}
+
+#[test]
+fn assign_replaces_the_previous_holder() {
+    let (old, new) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut roles = roles(old, old, old);
+
+    roles.assign(Role::Guardian, new);
+
+    assert!(roles.has_role(&new, Role::Guardian));
+    assert!(!roles.has_role(&old, Role::Guardian));
+    assert!(roles.has_role(&old, Role::Admin));
+}
+
+#[test]
+fn rotate_key_moves_every_role_of_the_old_key() {
+    let (old, new, guardian) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let mut roles = roles(old, old, guardian);
+
+    roles.rotate_key(old, new);
+
+    assert_eq!(roles, self::roles(new, new, guardian));
+}
+
+#[test]
+fn missing_role_keeps_its_code() {
+    assert_eq!(u32::from(SecurityError::MissingRole), 7006);
+}
@@ -0,0 +1,22 @@
+// require_nonzero_amount accepts anything but zero
+use security_common::prelude::*;
+
+#[test]
+fn zero_is_rejected_with_invalid_amount() {
+    assert_eq!(
+        require_nonzero_amount(0).unwrap_err(),
+        SecurityError::InvalidAmount.into()
+    );
+}
+
+#[test]
+fn any_positive_amount_passes() {
+    for amount in [1, 1_000, u64::MAX] {
+        assert!(require_nonzero_amount(amount).is_ok());
+    }
+}
+
+#[test]
+fn invalid_amount_keeps_its_code() {
+    assert_eq!(u32::from(SecurityError::InvalidAmount), 7005);
+}
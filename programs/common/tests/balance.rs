@@ -0,0 +1,73 @@
+// Balance::checked_credit / checked_debit at and past the u64 boundaries
+use security_common::prelude::*;
+
+/// Smallest possible implementor: the balance is the whole account
+struct Ledger(u64);
+
+impl Balance for Ledger {
+    fn balance(&self) -> u64 {
+        self.0
+    }
+
+    fn balance_mut(&mut self) -> &mut u64 {
+        &mut self.0
+    }
+}
+
+#[test]
+fn credit_returns_and_stores_the_new_balance() {
+    let mut ledger = Ledger(600);
+
+    assert_eq!(ledger.checked_credit(400).unwrap(), 1_000);
+    assert_eq!(ledger.balance(), 1_000);
+}
+
+#[test]
+fn credit_up_to_exactly_u64_max_succeeds() {
+    let mut ledger = Ledger(u64::MAX - 1);
+
+    assert_eq!(ledger.checked_credit(1).unwrap(), u64::MAX);
+    assert_eq!(ledger.checked_credit(0).unwrap(), u64::MAX);
+}
+
+#[test]
+fn credit_past_u64_max_overflows_and_leaves_the_balance() {
+    for (start, amount) in [(u64::MAX, 1), (1, u64::MAX), (u64::MAX, u64::MAX)] {
+        let mut ledger = Ledger(start);
+
+        assert_eq!(
+            ledger.checked_credit(amount).unwrap_err(),
+            SecurityError::MathOverflow.into()
+        );
+        assert_eq!(ledger.balance(), start);
+    }
+}
+
+#[test]
+fn debit_returns_and_stores_the_new_balance() {
+    let mut ledger = Ledger(1_000);
+
+    assert_eq!(ledger.checked_debit(400).unwrap(), 600);
+    assert_eq!(ledger.balance(), 600);
+}
+
+#[test]
+fn debit_down_to_exactly_zero_succeeds() {
+    let mut ledger = Ledger(u64::MAX);
+
+    assert_eq!(ledger.checked_debit(u64::MAX).unwrap(), 0);
+    assert_eq!(ledger.checked_debit(0).unwrap(), 0);
+}
+
+#[test]
+fn debit_below_zero_underflows_and_leaves_the_balance() {
+    for (start, amount) in [(0, 1), (999, 1_000), (0, u64::MAX)] {
+        let mut ledger = Ledger(start);
+
+        assert_eq!(
+            ledger.checked_debit(amount).unwrap_err(),
+            SecurityError::MathUnderflow.into()
+        );
+        assert_eq!(ledger.balance(), start);
+    }
+}
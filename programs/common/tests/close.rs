@@ -0,0 +1,73 @@
+// close_account against hand-built accounts
+use anchor_lang::system_program;
+use security_common::prelude::*;
+
+/// Run `f` with a program-owned account holding `data` and a destination
+fn with_accounts(
+    lamports: u64,
+    destination_lamports: u64,
+    data: &mut [u8],
+    f: impl FnOnce(&AccountInfo, &AccountInfo),
+) {
+    let (key, destination_key, program) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let (mut balance, mut destination_balance) = (lamports, destination_lamports);
+    let mut destination_data = [0u8; 0];
+
+    let account = AccountInfo::new(&key, false, true, &mut balance, data, &program, false, 0);
+    let destination = AccountInfo::new(
+        &destination_key,
+        false,
+        true,
+        &mut destination_balance,
+        &mut destination_data,
+        &system_program::ID,
+        false,
+        0,
+    );
+    f(&account, &destination);
+}
+
+#[test]
+fn drains_lamports_zeroes_data_and_hands_to_system_program() {
+    let mut data = [0xAB; 48];
+    with_accounts(1_461_600, 5_000, &mut data, |account, destination| {
+        close_account(account, destination).unwrap();
+
+        assert_eq!(account.lamports(), 0);
+        assert_eq!(destination.lamports(), 1_461_600 + 5_000);
+        assert!(account.try_borrow_data().unwrap().iter().all(|b| *b == 0));
+        assert_eq!(*account.owner, system_program::ID);
+    });
+}
+
+#[test]
+fn closing_into_itself_is_rejected() {
+    let mut data = [0xAB; 8];
+    with_accounts(1_000, 0, &mut data, |account, _| {
+        let error = close_account(account, account).unwrap_err();
+
+        assert_eq!(error, SecurityError::InvalidCloseDestination.into());
+        assert_eq!(account.lamports(), 1_000);
+        assert!(account
+            .try_borrow_data()
+            .unwrap()
+            .iter()
+            .all(|b| *b == 0xAB));
+    });
+}
+
+#[test]
+fn overflowing_destination_fails_with_math_overflow() {
+    let mut data = [0xAB; 8];
+    with_accounts(10, u64::MAX, &mut data, |account, destination| {
+        let error = close_account(account, destination).unwrap_err();
+
+        // The transaction aborts on error, so the runtime discards any writes
+        assert_eq!(error, SecurityError::MathOverflow.into());
+        assert_eq!(destination.lamports(), u64::MAX);
+    });
+}
@@ -0,0 +1,20 @@
+// Clients key on these numbers, so every variant is pinned to its code
+use security_common::SecurityError;
+
+#[test]
+fn every_variant_keeps_its_code() {
+    let codes = [
+        (SecurityError::MathOverflow, 7000),
+        (SecurityError::MathUnderflow, 7001),
+        (SecurityError::Unauthorized, 7002),
+        (SecurityError::InsufficientFunds, 7003),
+        (SecurityError::DivisionByZero, 7004),
+        (SecurityError::InvalidAmount, 7005),
+        (SecurityError::MissingRole, 7006),
+        (SecurityError::InvalidCloseDestination, 7007),
+    ];
+
+    for (error, code) in codes {
+        assert_eq!(u32::from(error), code, "{error:?}");
+    }
+}
@@ -0,0 +1,76 @@
+// checked_transfer_lamports against hand-built accounts
+use security_common::prelude::*;
+
+/// Run `f` with two writable accounts holding the given balances
+fn with_accounts(from_lamports: u64, to_lamports: u64, f: impl FnOnce(&AccountInfo, &AccountInfo)) {
+    let (from_key, to_key, owner) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let (mut from_balance, mut to_balance) = (from_lamports, to_lamports);
+    let (mut from_data, mut to_data) = ([0u8; 0], [0u8; 0]);
+
+    let from = AccountInfo::new(
+        &from_key,
+        false,
+        true,
+        &mut from_balance,
+        &mut from_data,
+        &owner,
+        false,
+        0,
+    );
+    let to = AccountInfo::new(
+        &to_key,
+        false,
+        true,
+        &mut to_balance,
+        &mut to_data,
+        &owner,
+        false,
+        0,
+    );
+    f(&from, &to);
+}
+
+#[test]
+fn moves_lamports_between_accounts() {
+    with_accounts(1_000, 500, |from, to| {
+        checked_transfer_lamports(from, to, 400).unwrap();
+
+        assert_eq!(from.lamports(), 600);
+        assert_eq!(to.lamports(), 900);
+    });
+}
+
+#[test]
+fn short_source_fails_with_insufficient_funds() {
+    with_accounts(100, 0, |from, to| {
+        let error = checked_transfer_lamports(from, to, 101).unwrap_err();
+
+        assert_eq!(error, SecurityError::InsufficientFunds.into());
+        assert_eq!(from.lamports(), 100);
+        assert_eq!(to.lamports(), 0);
+    });
+}
+
+#[test]
+fn overflowing_destination_fails_with_math_overflow() {
+    with_accounts(10, u64::MAX, |from, to| {
+        let error = checked_transfer_lamports(from, to, 1).unwrap_err();
+
+        // The transaction aborts on error, so the runtime discards the debit
+        assert_eq!(error, SecurityError::MathOverflow.into());
+        assert_eq!(to.lamports(), u64::MAX);
+    });
+}
+
+#[test]
+fn same_account_on_both_sides_nets_out() {
+    with_accounts(1_000, 0, |from, _| {
+        checked_transfer_lamports(from, from, 400).unwrap();
+
+        assert_eq!(from.lamports(), 1_000);
+    });
+}
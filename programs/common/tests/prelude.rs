@@ -0,0 +1,41 @@
+// Smoke test: a downstream crate needs nothing beyond the prelude
+use security_common::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+#[account]
+#[derive(InitSpace)]
+pub struct Vault {
+    pub authority: Pubkey,
+    pub total_deposited: u64,
+}
+
+impl Balance for Vault {
+    fn balance(&self) -> u64 {
+        self.total_deposited
+    }
+
+    fn balance_mut(&mut self) -> &mut u64 {
+        &mut self.total_deposited
+    }
+}
+
+fn fee(amount: u64, fee_bps: u64) -> Result<u64> {
+    Ok(try_math!(try_math!(amount * fee_bps)? / 10_000)?)
+}
+
+#[test]
+fn checked_operations_compile_from_the_prelude() {
+    let mut vault = Vault {
+        authority: Pubkey::default(),
+        total_deposited: 0,
+    };
+
+    assert_eq!(vault.checked_credit(1_000).unwrap(), 1_000);
+    assert_eq!(fee(vault.balance(), 30).unwrap(), 3);
+    assert_eq!(space_of!(Vault), 8 + 32 + 8);
+
+    let overflow: Error = try_math!(u64::MAX + 1).unwrap_err().into();
+    assert_eq!(overflow, SecurityError::MathOverflow.into());
+    assert!(vault.checked_debit(1_001).is_err());
+}
@@ -2,7 +2,26 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { MissingOwnerCheck } from "../target/types/missing_owner_check";
 import { expect } from "chai";
-import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
+import {
+  ACCOUNT_SIZE,
+  AccountLayout,
+  AccountState,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createAccount,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  mintTo,
+} from "@solana/spl-token";
+import { ownedAccount, setupProgram, systemAccount } from "./helpers/harness";
 
 describe("02-missing-owner-check", () => {
   const provider = anchor.AnchorProvider.env();
@@ -25,6 +44,106 @@ describe("02-missing-owner-check", () => {
     await provider.connection.confirmTransaction(airdrop);
   });
 
+  // Creates `authority`'s payment vault PDA accepting payments in `mint`
+  const initializePaymentVault = async (
+    mint: PublicKey,
+    authority: Keypair = user,
+  ): Promise<PublicKey> => {
+    const [vaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("payment_vault"), authority.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializePaymentVault()
+      .accounts({
+        authority: authority.publicKey,
+        vault: vaultPda,
+        mint,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    return vaultPda;
+  };
+
+  // Creates a mint under `tokenProgram`, a payment vault for it, a funded
+  // user token account and the vault's token account
+  const setupPayment = async (tokenProgram: PublicKey = TOKEN_PROGRAM_ID) => {
+    const mint = await createMint(
+      provider.connection,
+      user,
+      user.publicKey,
+      null,
+      6,
+      Keypair.generate(),
+      undefined,
+      tokenProgram,
+    );
+    const vaultPda = await initializePaymentVault(mint);
+    const userTokenAccount = await createAccount(
+      provider.connection,
+      user,
+      mint,
+      user.publicKey,
+      undefined,
+      undefined,
+      tokenProgram,
+    );
+    const vaultTokenAccount = await createAccount(
+      provider.connection,
+      user,
+      mint,
+      vaultPda,
+      Keypair.generate(),
+      undefined,
+      tokenProgram,
+    );
+    await mintTo(
+      provider.connection,
+      user,
+      mint,
+      userTokenAccount,
+      user,
+      1_000_000,
+      [],
+      undefined,
+      tokenProgram,
+    );
+
+    return { mint, vaultPda, userTokenAccount, vaultTokenAccount };
+  };
+
+  // Creates `payer`'s receipt PDA for `vault` - the first step of paying
+  const initializeReceipt = async (
+    payer: Keypair,
+    vault: PublicKey,
+  ): Promise<PublicKey> => {
+    const [receiptPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("receipt"), vault.toBuffer(), payer.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initializeReceipt()
+      .accounts({ user: payer.publicKey, vault })
+      .signers([payer])
+      .rpc();
+
+    return receiptPda;
+  };
+
+  describe("📏 Account size (space_of!)", () => {
+    it("Allocates exactly discriminator + serialized fields", async () => {
+      const { vaultPda } = await setupPayment();
+      const info = await provider.connection.getAccountInfo(vaultPda);
+
+      expect(info!.data.length).to.equal(program.account.paymentVault.size);
+      expect(info!.data.length).to.equal(8 + 32 + 8 + 32 + 32);
+    });
+  });
+
   describe("❌ VULNERABLE: missing owner check", () => {
     it("Demonstrates owner check vulnerability", async () => {
       console.log("    ⚠️  VULNERABILITY: No owner verification on accounts");
@@ -63,4 +182,712 @@ describe("02-missing-owner-check", () => {
       console.log("    ❌ Avoid AccountInfo without owner checks");
     });
   });
+
+  describe("✅ SECURE: process_payment_secure", () => {
+    let mint: PublicKey;
+    let vaultPda: PublicKey;
+    let userTokenAccount: PublicKey;
+    let vaultTokenAccount: PublicKey;
+
+    beforeEach(async () => {
+      ({ mint, vaultPda, userTokenAccount, vaultTokenAccount } =
+        await setupPayment());
+      await initializeReceipt(user, vaultPda);
+    });
+
+    it("Moves tokens into the vault and increments total_collected", async () => {
+      await program.methods
+        .processPaymentSecure(new anchor.BN(250_000))
+        .accounts({
+          user: user.publicKey,
+          userTokenAccount,
+          vault: vaultPda,
+          vaultTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+
+      const userAccount = await getAccount(
+        provider.connection,
+        userTokenAccount,
+      );
+      const vaultAccount = await getAccount(
+        provider.connection,
+        vaultTokenAccount,
+      );
+      const vault = await program.account.paymentVault.fetch(vaultPda);
+
+      expect(Number(userAccount.amount)).to.equal(750_000);
+      expect(Number(vaultAccount.amount)).to.equal(250_000);
+      expect(vault.totalCollected.toNumber()).to.equal(250_000);
+    });
+
+    it("Rejects a token account holding the wrong mint", async () => {
+      const otherMint = await createMint(
+        provider.connection,
+        user,
+        user.publicKey,
+        null,
+        6,
+      );
+      const otherTokenAccount = await createAccount(
+        provider.connection,
+        user,
+        otherMint,
+        user.publicKey,
+      );
+      await mintTo(
+        provider.connection,
+        user,
+        otherMint,
+        otherTokenAccount,
+        user,
+        1_000_000,
+      );
+
+      try {
+        await program.methods
+          .processPaymentSecure(new anchor.BN(250_000))
+          .accounts({
+            user: user.publicKey,
+            userTokenAccount: otherTokenAccount,
+            vault: vaultPda,
+            vaultTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user])
+          .rpc();
+
+        throw new Error("Expected wrong-mint payment to fail");
+      } catch (error: any) {
+        console.log(`    ✅ Wrong mint rejected`);
+        expect(error.message).to.include("WrongMint");
+      }
+    });
+  });
+
+  describe("🎯 Associated token account only", () => {
+    let mint: PublicKey;
+    let vaultPda: PublicKey;
+    let userTokenAccount: PublicKey;
+    let vaultTokenAccount: PublicKey;
+
+    beforeEach(async () => {
+      ({ mint, vaultPda, userTokenAccount, vaultTokenAccount } =
+        await setupPayment());
+    });
+
+    const payFromAta = (tokenAccount: PublicKey) =>
+      program.methods
+        .processPaymentAta(new anchor.BN(250_000))
+        .accounts({
+          user: user.publicKey,
+          mint,
+          userTokenAccount: tokenAccount,
+          vault: vaultPda,
+          vaultTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+
+    it("Accepts the user's associated token account", async () => {
+      // setupPayment creates the user's account at the ATA address
+      expect(userTokenAccount.toBase58()).to.equal(
+        getAssociatedTokenAddressSync(mint, user.publicKey).toBase58(),
+      );
+
+      await payFromAta(userTokenAccount);
+
+      const vault = await program.account.paymentVault.fetch(vaultPda);
+      expect(vault.totalCollected.toNumber()).to.equal(250_000);
+    });
+
+    it("Rejects another token account the user owns", async () => {
+      // Same owner, same mint, real balance - just not at the ATA address
+      const otherTokenAccount = await createAccount(
+        provider.connection,
+        user,
+        mint,
+        user.publicKey,
+        Keypair.generate(),
+      );
+      await mintTo(
+        provider.connection,
+        user,
+        mint,
+        otherTokenAccount,
+        user,
+        1_000_000,
+      );
+
+      try {
+        await payFromAta(otherTokenAccount);
+        throw new Error("Expected non-ATA token account to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("ConstraintAssociated");
+        console.log("    ✅ Non-ATA token account rejected");
+      }
+
+      const otherAccount = await getAccount(
+        provider.connection,
+        otherTokenAccount,
+      );
+      expect(Number(otherAccount.amount)).to.equal(1_000_000);
+    });
+  });
+
+  describe("🎯 Per-depositor receipts", () => {
+    let mint: PublicKey;
+    let vaultPda: PublicKey;
+    let userTokenAccount: PublicKey;
+    let vaultTokenAccount: PublicKey;
+    let other: Keypair;
+    let otherTokenAccount: PublicKey;
+
+    beforeEach(async () => {
+      ({ mint, vaultPda, userTokenAccount, vaultTokenAccount } =
+        await setupPayment());
+
+      other = Keypair.generate();
+      const airdrop = await provider.connection.requestAirdrop(
+        other.publicKey,
+        LAMPORTS_PER_SOL,
+      );
+      await provider.connection.confirmTransaction(airdrop);
+
+      otherTokenAccount = await createAccount(
+        provider.connection,
+        user,
+        mint,
+        other.publicKey,
+      );
+      await mintTo(
+        provider.connection,
+        user,
+        mint,
+        otherTokenAccount,
+        user,
+        1_000_000,
+      );
+    });
+
+    const pay = (
+      payer: Keypair,
+      payerTokenAccount: PublicKey,
+      amount: number,
+    ) =>
+      program.methods
+        .processPaymentSecure(new anchor.BN(amount))
+        .accounts({
+          user: payer.publicKey,
+          userTokenAccount: payerTokenAccount,
+          vault: vaultPda,
+          vaultTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([payer])
+        .rpc();
+
+    it("Each receipt reflects only its own user's payments", async () => {
+      const userReceipt = await initializeReceipt(user, vaultPda);
+      const otherReceipt = await initializeReceipt(other, vaultPda);
+
+      await pay(user, userTokenAccount, 100_000);
+      await pay(other, otherTokenAccount, 300_000);
+      await pay(user, userTokenAccount, 150_000);
+
+      const mine = await program.account.paymentReceipt.fetch(userReceipt);
+      const theirs = await program.account.paymentReceipt.fetch(otherReceipt);
+      const vault = await program.account.paymentVault.fetch(vaultPda);
+
+      expect(mine.user.toBase58()).to.equal(user.publicKey.toBase58());
+      expect(mine.totalPaid.toNumber()).to.equal(250_000);
+      expect(theirs.user.toBase58()).to.equal(other.publicKey.toBase58());
+      expect(theirs.totalPaid.toNumber()).to.equal(300_000);
+      expect(vault.totalCollected.toNumber()).to.equal(550_000);
+    });
+
+    it("Rejects a payment before the receipt exists", async () => {
+      try {
+        await pay(other, otherTokenAccount, 100_000);
+
+        throw new Error("Expected payment without a receipt to fail");
+      } catch (error: any) {
+        console.log(`    ✅ Payment without a receipt rejected`);
+        expect(error.message).to.include("AccountNotInitialized");
+      }
+    });
+  });
+
+  describe("🎯 Refunds", () => {
+    let mint: PublicKey;
+    let vaultPda: PublicKey;
+    let userTokenAccount: PublicKey;
+    let vaultTokenAccount: PublicKey;
+    let receipt: PublicKey;
+    let otherPayer: Keypair;
+    let otherTokenAccount: PublicKey;
+
+    const pay = (payer: Keypair, from: PublicKey, amount: number) =>
+      program.methods
+        .processPaymentSecure(new anchor.BN(amount))
+        .accounts({
+          user: payer.publicKey,
+          userTokenAccount: from,
+          vault: vaultPda,
+          vaultTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([payer])
+        .rpc();
+
+    // The user pays 400_000 and a second payer 300_000 into the same vault
+    beforeEach(async () => {
+      ({ mint, vaultPda, userTokenAccount, vaultTokenAccount } =
+        await setupPayment());
+      receipt = await initializeReceipt(user, vaultPda);
+      await pay(user, userTokenAccount, 400_000);
+
+      otherPayer = Keypair.generate();
+      const airdrop = await provider.connection.requestAirdrop(
+        otherPayer.publicKey,
+        LAMPORTS_PER_SOL,
+      );
+      await provider.connection.confirmTransaction(airdrop);
+      await initializeReceipt(otherPayer, vaultPda);
+
+      otherTokenAccount = await createAccount(
+        provider.connection,
+        otherPayer,
+        mint,
+        otherPayer.publicKey,
+      );
+      await mintTo(
+        provider.connection,
+        user,
+        mint,
+        otherTokenAccount,
+        user,
+        1_000_000,
+      );
+      await pay(otherPayer, otherTokenAccount, 300_000);
+    });
+
+    const refund = (amount: number, to: PublicKey = userTokenAccount) =>
+      program.methods
+        .refund(new anchor.BN(amount))
+        .accounts({
+          authority: user.publicKey,
+          vaultTokenAccount,
+          receipt,
+          userTokenAccount: to,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+
+    it("Authority refunds part of a payment", async () => {
+      await refund(150_000);
+
+      const userAccount = await getAccount(
+        provider.connection,
+        userTokenAccount,
+      );
+      const vaultAccount = await getAccount(
+        provider.connection,
+        vaultTokenAccount,
+      );
+      const vault = await program.account.paymentVault.fetch(vaultPda);
+      const paid = await program.account.paymentReceipt.fetch(receipt);
+
+      expect(Number(userAccount.amount)).to.equal(750_000);
+      expect(Number(vaultAccount.amount)).to.equal(550_000);
+      expect(vault.totalCollected.toNumber()).to.equal(550_000);
+      expect(paid.totalPaid.toNumber()).to.equal(250_000);
+    });
+
+    it("Rejects refunding more than the payer paid", async () => {
+      // 🚨 The vault holds 700_000, but only 400_000 of it is this payer's
+      try {
+        await refund(400_001);
+
+        throw new Error("Expected over-refund to fail");
+      } catch (error: any) {
+        console.log(`    ✅ Over-refund rejected`);
+        expect(error.message).to.include("RefundExceedsPaid");
+      }
+
+      const vault = await program.account.paymentVault.fetch(vaultPda);
+      const paid = await program.account.paymentReceipt.fetch(receipt);
+      expect(vault.totalCollected.toNumber()).to.equal(700_000);
+      expect(paid.totalPaid.toNumber()).to.equal(400_000);
+    });
+
+    it("Rejects a refund paid to someone other than the payer", async () => {
+      // 🚨 The user's receipt, but another payer's token account
+      try {
+        await refund(100_000, otherTokenAccount);
+
+        throw new Error("Expected misdirected refund to fail");
+      } catch (error: any) {
+        console.log(`    ✅ Misdirected refund rejected`);
+        expect(error.message).to.include("InvalidTokenOwner");
+      }
+
+      const otherAccount = await getAccount(
+        provider.connection,
+        otherTokenAccount,
+      );
+      expect(Number(otherAccount.amount)).to.equal(700_000);
+    });
+
+    it("Rejects a receipt from another vault", async () => {
+      // The user also pays 200_000 into a vault the second payer runs
+      const otherVault = await initializePaymentVault(mint, otherPayer);
+      const otherVaultTokenAccount = await createAccount(
+        provider.connection,
+        user,
+        mint,
+        otherVault,
+        Keypair.generate(),
+      );
+      const otherReceipt = await initializeReceipt(user, otherVault);
+      await program.methods
+        .processPaymentSecure(new anchor.BN(200_000))
+        .accounts({
+          user: user.publicKey,
+          userTokenAccount,
+          vault: otherVault,
+          vaultTokenAccount: otherVaultTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+
+      // 🚨 This vault's authority refunds against that other receipt
+      try {
+        await program.methods
+          .refund(new anchor.BN(200_000))
+          .accounts({
+            authority: user.publicKey,
+            vaultTokenAccount,
+            receipt: otherReceipt,
+            userTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user])
+          .rpc();
+
+        throw new Error("Expected a foreign receipt to fail");
+      } catch (error: any) {
+        console.log(`    ✅ Receipt from another vault rejected`);
+        expect(error.message).to.include("ConstraintSeeds");
+      }
+
+      const paid = await program.account.paymentReceipt.fetch(otherReceipt);
+      expect(paid.totalPaid.toNumber()).to.equal(200_000);
+    });
+  });
+
+  describe("🎯 has_one vs constraint", () => {
+    let mint: PublicKey;
+    let vaultPda: PublicKey;
+    let userTokenAccount: PublicKey;
+    let vaultTokenAccount: PublicKey;
+    let intruder: Keypair;
+    let intruderTokenAccount: PublicKey;
+
+    beforeEach(async () => {
+      ({ mint, vaultPda, userTokenAccount, vaultTokenAccount } =
+        await setupPayment());
+      await initializeReceipt(user, vaultPda);
+
+      intruder = Keypair.generate();
+      const airdrop = await provider.connection.requestAirdrop(
+        intruder.publicKey,
+        LAMPORTS_PER_SOL,
+      );
+      await provider.connection.confirmTransaction(airdrop);
+      await initializeReceipt(intruder, vaultPda);
+
+      intruderTokenAccount = await createAccount(
+        provider.connection,
+        intruder,
+        mint,
+        intruder.publicKey,
+      );
+      await mintTo(
+        provider.connection,
+        user,
+        mint,
+        intruderTokenAccount,
+        user,
+        1_000_000,
+      );
+    });
+
+    const payHasOne = (authority: Keypair, from: PublicKey) =>
+      program.methods
+        .processPaymentHasOne(new anchor.BN(100_000))
+        .accounts({
+          authority: authority.publicKey,
+          vault: vaultPda,
+          userTokenAccount: from,
+          vaultTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([authority])
+        .rpc();
+
+    const payConstraint = (payer: Keypair, from: PublicKey) =>
+      program.methods
+        .processPaymentSecure(new anchor.BN(100_000))
+        .accounts({
+          user: payer.publicKey,
+          userTokenAccount: from,
+          vault: vaultPda,
+          vaultTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([payer])
+        .rpc();
+
+    const collected = async () => {
+      const vault = await program.account.paymentVault.fetch(vaultPda);
+      return vault.totalCollected.toNumber();
+    };
+
+    it("has_one accepts the vault's authority", async () => {
+      await payHasOne(user, userTokenAccount);
+
+      expect(await collected()).to.equal(100_000);
+    });
+
+    it("has_one rejects a signer who isn't vault.authority", async () => {
+      try {
+        await payHasOne(intruder, intruderTokenAccount);
+
+        throw new Error("Expected non-authority payment to fail");
+      } catch (error: any) {
+        console.log(`    ✅ has_one: vault.authority != authority.key()`);
+        expect(error.message).to.include("InvalidVaultAuthority");
+      }
+
+      expect(await collected()).to.equal(0);
+    });
+
+    it("constraint accepts a token account the signer owns", async () => {
+      await payConstraint(intruder, intruderTokenAccount);
+
+      expect(await collected()).to.equal(100_000);
+    });
+
+    it("constraint rejects a token account owned by someone else", async () => {
+      try {
+        await payConstraint(intruder, userTokenAccount);
+
+        throw new Error("Expected mismatched token owner to fail");
+      } catch (error: any) {
+        console.log(`    ✅ constraint: user_token_account.owner != user`);
+        expect(error.message).to.include("InvalidTokenOwner");
+      }
+
+      expect(await collected()).to.equal(0);
+    });
+  });
+
+  describe("🎯 Short account data", () => {
+    let mint: PublicKey;
+    let vaultPda: PublicKey;
+    let shortAccount: Keypair;
+
+    beforeEach(async () => {
+      mint = await createMint(
+        provider.connection,
+        user,
+        user.publicKey,
+        null,
+        6,
+      );
+      vaultPda = await initializePaymentVault(mint);
+
+      // A 10-byte account owned by the Token Program - passes the owner
+      // check but is far too short to be a token account
+      shortAccount = Keypair.generate();
+      const lamports =
+        await provider.connection.getMinimumBalanceForRentExemption(10);
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: user.publicKey,
+            newAccountPubkey: shortAccount.publicKey,
+            lamports,
+            space: 10,
+            programId: TOKEN_PROGRAM_ID,
+          }),
+        ),
+        [user, shortAccount],
+      );
+    });
+
+    it("Manual path returns a clean error instead of panicking", async () => {
+      try {
+        await program.methods
+          .processPaymentManual(new anchor.BN(1))
+          .accounts({
+            user: user.publicKey,
+            userTokenAccount: shortAccount.publicKey,
+            vault: vaultPda,
+          })
+          .signers([user])
+          .rpc();
+
+        throw new Error("Expected short account to be rejected");
+      } catch (error: any) {
+        console.log(`    ✅ Short account rejected cleanly`);
+        expect(error.message).to.include("InvalidTokenAccount");
+      }
+    });
+
+    it("Insecure path also fails cleanly on short data", async () => {
+      try {
+        await program.methods
+          .processPaymentInsecure(new anchor.BN(1))
+          .accounts({
+            user: user.publicKey,
+            userTokenAccount: shortAccount.publicKey,
+            vault: vaultPda,
+          })
+          .signers([user])
+          .rpc();
+
+        throw new Error("Expected short account to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidTokenAccount");
+      }
+    });
+  });
+
+  describe("✅ SECURE: process_payment_token2022", () => {
+    const payThroughInterface = async (tokenProgram: PublicKey) => {
+      const { mint, vaultPda, userTokenAccount, vaultTokenAccount } =
+        await setupPayment(tokenProgram);
+
+      await program.methods
+        .processPaymentToken2022(new anchor.BN(250_000))
+        .accounts({
+          user: user.publicKey,
+          mint,
+          userTokenAccount,
+          vault: vaultPda,
+          vaultTokenAccount,
+          tokenProgram,
+        })
+        .signers([user])
+        .rpc();
+
+      const vaultAccount = await getAccount(
+        provider.connection,
+        vaultTokenAccount,
+        undefined,
+        tokenProgram,
+      );
+      const vault = await program.account.paymentVault.fetch(vaultPda);
+
+      expect(Number(vaultAccount.amount)).to.equal(250_000);
+      expect(vault.totalCollected.toNumber()).to.equal(250_000);
+    };
+
+    it("Accepts a classic SPL Token account", async () => {
+      await payThroughInterface(TOKEN_PROGRAM_ID);
+      console.log("    ✅ Classic token account accepted");
+    });
+
+    it("Accepts a Token-2022 account through the same instruction", async () => {
+      await payThroughInterface(TOKEN_2022_PROGRAM_ID);
+      console.log("    ✅ Token-2022 account accepted");
+    });
+  });
+
+  describe("🚨 Fake token account exploit", () => {
+    // A live validator won't let us write arbitrary bytes into an account
+    // owned by some other program, so the attack is staged in bankrun
+    let bankrunProgram: Program<MissingOwnerCheck>;
+    const maliciousProgram = Keypair.generate().publicKey;
+    const fakeTokenAccount = Keypair.generate().publicKey;
+    const mint = Keypair.generate().publicKey;
+    const vault = Keypair.generate().publicKey;
+
+    beforeEach(async () => {
+      // Byte-for-byte a token account holding u64::MAX - but owned by the
+      // attacker's program instead of the Token Program
+      const fakeData = Buffer.alloc(ACCOUNT_SIZE);
+      AccountLayout.encode(
+        {
+          mint,
+          owner: user.publicKey,
+          amount: BigInt("18446744073709551615"),
+          delegateOption: 0,
+          delegate: PublicKey.default,
+          state: AccountState.Initialized,
+          isNativeOption: 0,
+          isNative: BigInt(0),
+          delegatedAmount: BigInt(0),
+          closeAuthorityOption: 0,
+          closeAuthority: PublicKey.default,
+        },
+        fakeData,
+      );
+
+      const vaultData = await program.coder.accounts.encode("paymentVault", {
+        authority: user.publicKey,
+        totalCollected: new anchor.BN(0),
+        expectedMint: mint,
+      });
+
+      ({ program: bankrunProgram } = await setupProgram(program, [
+        systemAccount(user.publicKey, 2 * LAMPORTS_PER_SOL),
+        ownedAccount(fakeTokenAccount, maliciousProgram, fakeData),
+        ownedAccount(vault, program.programId, vaultData),
+      ]));
+    });
+
+    it("Insecure path accepts the fake balance", async () => {
+      // ❌ VULNERABLE: the program reads u64::MAX from attacker bytes
+      await bankrunProgram.methods
+        .processPaymentInsecure(new anchor.BN(1_000_000_000))
+        .accounts({
+          user: user.publicKey,
+          userTokenAccount: fakeTokenAccount,
+          vault,
+        })
+        .signers([user])
+        .rpc();
+
+      console.log("    🚨 EXPLOIT SUCCESSFUL: fake balance accepted");
+    });
+
+    it("Secure path rejects the fake account on owner", async () => {
+      try {
+        await bankrunProgram.methods
+          .processPaymentSecure(new anchor.BN(1_000_000_000))
+          .accounts({
+            user: user.publicKey,
+            userTokenAccount: fakeTokenAccount,
+            vault,
+            vaultTokenAccount: fakeTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user])
+          .rpc();
+
+        throw new Error("Expected fake token account to be rejected");
+      } catch (error: any) {
+        console.log(`    ✅ Fake account rejected: wrong owner`);
+        expect(error.message).to.include("AccountOwnedByWrongProgram");
+      }
+    });
+  });
 });
@@ -1,8 +1,29 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { ArbitraryCpi } from "../target/types/arbitrary_cpi";
+import { RogueCaller } from "../target/types/rogue_caller";
 import { expect } from "chai";
-import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SystemProgram,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createAccount,
+  createInitializeMintInstruction,
+  createInitializeTransferHookInstruction,
+  createMint,
+  getAccount,
+  getMintLen,
+  mintTo,
+} from "@solana/spl-token";
 
 describe("05-arbitrary-cpi", () => {
   const provider = anchor.AnchorProvider.env();
@@ -10,6 +31,68 @@ describe("05-arbitrary-cpi", () => {
 
   const program = anchor.workspace.ArbitraryCpi as Program<ArbitraryCpi>;
 
+  // SPL Memo v1 is preloaded on the test validator and accepts any
+  // accounts. It stands in for a malicious program that "succeeds"
+  // without moving a single token.
+  const FAKE_TOKEN_PROGRAM = new PublicKey(
+    "Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo",
+  );
+
+  // Singleton initializers only accept the program's upgrade authority,
+  // which the test validator sets to the provider wallet
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"),
+  );
+
+  let authority: Keypair;
+  let mint: PublicKey;
+  let from: PublicKey;
+  let to: PublicKey;
+
+  beforeEach(async () => {
+    authority = Keypair.generate();
+
+    const airdrop = await provider.connection.requestAirdrop(
+      authority.publicKey,
+      2 * LAMPORTS_PER_SOL,
+    );
+    await provider.connection.confirmTransaction(airdrop);
+
+    mint = await createMint(
+      provider.connection,
+      authority,
+      authority.publicKey,
+      null,
+      6,
+    );
+    from = await createAccount(
+      provider.connection,
+      authority,
+      mint,
+      authority.publicKey,
+      Keypair.generate(),
+    );
+    to = await createAccount(
+      provider.connection,
+      authority,
+      mint,
+      Keypair.generate().publicKey,
+      Keypair.generate(),
+    );
+    await mintTo(provider.connection, authority, mint, from, authority, 1_000);
+
+    // transfer_secure only accepts the authority configured for `from`
+    await program.methods
+      .initializeAuthorityConfig()
+      .accounts({ authority: authority.publicKey, from })
+      .signers([authority])
+      .rpc();
+  });
+
+  const tokenBalance = async (account: PublicKey) =>
+    Number((await getAccount(provider.connection, account)).amount);
+
   describe("❌ VULNERABLE: Arbitrary CPI", () => {
     it("Demonstrates arbitrary CPI vulnerability", async () => {
       console.log("    ⚠️  VULNERABILITY: Accepts any program ID for CPI");
@@ -45,4 +128,697 @@ describe("05-arbitrary-cpi", () => {
       console.log("    ❌ Don't use raw invoke() without validation");
     });
   });
+
+  describe("🚨 Fake token program exploit", () => {
+    // Amount chosen so the packed instruction data is valid UTF-8 for Memo
+    const AMOUNT = 100;
+
+    it("Insecure path reports success without moving tokens", async () => {
+      // ❌ VULNERABLE: the "token program" is whatever the caller passes
+      await program.methods
+        .transferInsecure(new anchor.BN(AMOUNT))
+        .accounts({
+          tokenProgram: FAKE_TOKEN_PROGRAM,
+          from,
+          to,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      expect(await tokenBalance(from)).to.equal(1_000);
+      expect(await tokenBalance(to)).to.equal(0);
+      console.log("    🚨 EXPLOIT: 'Transfer completed' but no tokens moved");
+    });
+
+    it("Insecure path does transfer with the real Token Program", async () => {
+      await program.methods
+        .transferInsecure(new anchor.BN(AMOUNT))
+        .accounts({
+          tokenProgram: TOKEN_PROGRAM_ID,
+          from,
+          to,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      expect(await tokenBalance(to)).to.equal(AMOUNT);
+    });
+
+    it("Secure path transfers with spl_token::ID", async () => {
+      await program.methods
+        .transferSecure(new anchor.BN(AMOUNT))
+        .accounts({
+          tokenProgram: TOKEN_PROGRAM_ID,
+          from,
+          to,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      expect(await tokenBalance(from)).to.equal(1_000 - AMOUNT);
+      expect(await tokenBalance(to)).to.equal(AMOUNT);
+    });
+
+    it("Secure path rejects any other program id", async () => {
+      try {
+        await program.methods
+          .transferSecure(new anchor.BN(AMOUNT))
+          .accounts({
+            tokenProgram: FAKE_TOKEN_PROGRAM,
+            from,
+            to,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+        throw new Error("Expected fake token program to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidProgramId");
+        console.log("    ✅ Program<'info, Token> rejected the fake program");
+      }
+    });
+  });
+
+  describe("🎯 Configured authority", () => {
+    it("Records the token account owner as the authority", async () => {
+      const [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("authority_config"), from.toBuffer()],
+        program.programId,
+      );
+      const state = await program.account.authorityConfig.fetch(configPda);
+
+      expect(state.authority.toBase58()).to.equal(
+        authority.publicKey.toBase58(),
+      );
+    });
+
+    it("Rejects an unrelated signer moving tokens out of from", async () => {
+      const intruder = Keypair.generate();
+
+      try {
+        await program.methods
+          .transferSecure(new anchor.BN(100))
+          .accounts({
+            tokenProgram: TOKEN_PROGRAM_ID,
+            from,
+            to,
+            authority: intruder.publicKey,
+          })
+          .signers([intruder])
+          .rpc();
+
+        throw new Error("Expected unrelated signer to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+        console.log("    ✅ Only the configured authority can move tokens");
+      }
+
+      expect(await tokenBalance(from)).to.equal(1_000);
+    });
+
+    it("Rejects configuring an account the signer doesn't own", async () => {
+      const intruder = Keypair.generate();
+      const airdrop = await provider.connection.requestAirdrop(
+        intruder.publicKey,
+        LAMPORTS_PER_SOL,
+      );
+      await provider.connection.confirmTransaction(airdrop);
+
+      const victimAccount = await createAccount(
+        provider.connection,
+        authority,
+        mint,
+        authority.publicKey,
+        Keypair.generate(),
+      );
+
+      try {
+        await program.methods
+          .initializeAuthorityConfig()
+          .accounts({ authority: intruder.publicKey, from: victimAccount })
+          .signers([intruder])
+          .rpc();
+
+        throw new Error("Expected config squatting to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+  });
+
+  // Runs before the allowlist tests create the singleton
+  describe("🎯 Allowlist initialization", () => {
+    it("Rejects initialization by anyone but the upgrade authority", async () => {
+      const intruder = Keypair.generate();
+      const airdrop = await provider.connection.requestAirdrop(
+        intruder.publicKey,
+        LAMPORTS_PER_SOL,
+      );
+      await provider.connection.confirmTransaction(airdrop);
+
+      try {
+        await program.methods
+          .initializeAllowlist([
+            intruder.publicKey,
+            PublicKey.default,
+            PublicKey.default,
+            PublicKey.default,
+          ])
+          .accounts({ admin: intruder.publicKey, programData })
+          .signers([intruder])
+          .rpc();
+
+        throw new Error("Expected a non-upgrade-authority init to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+        console.log("    ✅ Only the upgrade authority can create it");
+      }
+    });
+  });
+
+  describe("🎯 CPI allowlist", () => {
+    const [allowlist] = PublicKey.findProgramAddressSync(
+      [Buffer.from("cpi_allowlist")],
+      program.programId,
+    );
+
+    // The allowlist is a program-wide singleton, so only create it once
+    const ensureAllowlist = async () => {
+      if (await provider.connection.getAccountInfo(allowlist)) {
+        return;
+      }
+      await program.methods
+        .initializeAllowlist([
+          TOKEN_PROGRAM_ID,
+          TOKEN_2022_PROGRAM_ID,
+          PublicKey.default,
+          PublicKey.default,
+        ])
+        .accounts({ admin: provider.wallet.publicKey, programData })
+        .rpc();
+    };
+
+    const transferAllowlisted = (
+      tokenProgram: PublicKey,
+      accounts: { mint: PublicKey; from: PublicKey; to: PublicKey },
+    ) =>
+      program.methods
+        .transferAllowlisted(new anchor.BN(100))
+        .accounts({
+          tokenProgram,
+          ...accounts,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    beforeEach(async () => {
+      await ensureAllowlist();
+    });
+
+    it("Accepts the classic Token program", async () => {
+      await transferAllowlisted(TOKEN_PROGRAM_ID, { mint, from, to });
+      expect(await tokenBalance(to)).to.equal(100);
+    });
+
+    it("Accepts Token-2022", async () => {
+      const mint2022 = await createMint(
+        provider.connection,
+        authority,
+        authority.publicKey,
+        null,
+        6,
+        Keypair.generate(),
+        undefined,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      const create2022Account = (owner: PublicKey) =>
+        createAccount(
+          provider.connection,
+          authority,
+          mint2022,
+          owner,
+          Keypair.generate(),
+          undefined,
+          TOKEN_2022_PROGRAM_ID,
+        );
+      const from2022 = await create2022Account(authority.publicKey);
+      const to2022 = await create2022Account(Keypair.generate().publicKey);
+      await mintTo(
+        provider.connection,
+        authority,
+        mint2022,
+        from2022,
+        authority,
+        1_000,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      await transferAllowlisted(TOKEN_2022_PROGRAM_ID, {
+        mint: mint2022,
+        from: from2022,
+        to: to2022,
+      });
+
+      const received = await getAccount(
+        provider.connection,
+        to2022,
+        undefined,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      expect(Number(received.amount)).to.equal(100);
+      console.log("    ✅ Both approved token programs accepted");
+    });
+
+    it("Rejects a program that isn't listed", async () => {
+      try {
+        await transferAllowlisted(FAKE_TOKEN_PROGRAM, { mint, from, to });
+        throw new Error("Expected unlisted program to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("ProgramNotAllowed");
+        console.log("    ✅ Unlisted program rejected before the CPI");
+      }
+
+      expect(await tokenBalance(to)).to.equal(0);
+    });
+
+    it("Never matches an empty slot (the System Program's ID)", async () => {
+      try {
+        await transferAllowlisted(SystemProgram.programId, { mint, from, to });
+        throw new Error("Expected empty slot match to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("ProgramNotAllowed");
+      }
+    });
+
+    it("Lets only the stored admin update the list", async () => {
+      const intruder = Keypair.generate();
+
+      try {
+        await program.methods
+          .updateAllowlist([
+            intruder.publicKey,
+            PublicKey.default,
+            PublicKey.default,
+            PublicKey.default,
+          ])
+          .accounts({ admin: intruder.publicKey })
+          .signers([intruder])
+          .rpc();
+
+        throw new Error("Expected a stranger's update to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("ConstraintHasOne");
+      }
+
+      // The admin re-applies the same list so later tests are unaffected
+      await program.methods
+        .updateAllowlist([
+          TOKEN_PROGRAM_ID,
+          TOKEN_2022_PROGRAM_ID,
+          PublicKey.default,
+          PublicKey.default,
+        ])
+        .accounts({ admin: provider.wallet.publicKey })
+        .rpc();
+
+      const state = await program.account.cpiAllowlist.fetch(allowlist);
+      expect(state.admin.toBase58()).to.equal(
+        provider.wallet.publicKey.toBase58(),
+      );
+    });
+
+    describe("🪝 Transfer hooks", () => {
+      // Creates a Token-2022 mint, optionally with a transfer hook, and a
+      // funded source account plus an empty destination
+      const setup2022 = async (hookProgram?: PublicKey) => {
+        const mintKeypair = Keypair.generate();
+        const extensions = hookProgram ? [ExtensionType.TransferHook] : [];
+        const mintLen = getMintLen(extensions);
+        const lamports =
+          await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+
+        const tx = new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: authority.publicKey,
+            newAccountPubkey: mintKeypair.publicKey,
+            space: mintLen,
+            lamports,
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+        );
+        if (hookProgram) {
+          tx.add(
+            createInitializeTransferHookInstruction(
+              mintKeypair.publicKey,
+              authority.publicKey,
+              hookProgram,
+              TOKEN_2022_PROGRAM_ID,
+            ),
+          );
+        }
+        tx.add(
+          createInitializeMintInstruction(
+            mintKeypair.publicKey,
+            6,
+            authority.publicKey,
+            null,
+            TOKEN_2022_PROGRAM_ID,
+          ),
+        );
+        await sendAndConfirmTransaction(provider.connection, tx, [
+          authority,
+          mintKeypair,
+        ]);
+
+        const hookMint = mintKeypair.publicKey;
+        const createHookAccount = (owner: PublicKey) =>
+          createAccount(
+            provider.connection,
+            authority,
+            hookMint,
+            owner,
+            Keypair.generate(),
+            undefined,
+            TOKEN_2022_PROGRAM_ID,
+          );
+        const hookFrom = await createHookAccount(authority.publicKey);
+        const hookTo = await createHookAccount(Keypair.generate().publicKey);
+        await mintTo(
+          provider.connection,
+          authority,
+          hookMint,
+          hookFrom,
+          authority,
+          1_000,
+          [],
+          undefined,
+          TOKEN_2022_PROGRAM_ID,
+        );
+        return { mint: hookMint, from: hookFrom, to: hookTo };
+      };
+
+      const transferWithHookChecks = (accounts: {
+        mint: PublicKey;
+        from: PublicKey;
+        to: PublicKey;
+      }) =>
+        program.methods
+          .transferWithHookChecks(new anchor.BN(100))
+          .accounts({
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            ...accounts,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+      const balance2022 = async (account: PublicKey) =>
+        Number(
+          (
+            await getAccount(
+              provider.connection,
+              account,
+              undefined,
+              TOKEN_2022_PROGRAM_ID,
+            )
+          ).amount,
+        );
+
+      it("Transfers a Token-2022 mint without a hook", async () => {
+        const accounts = await setup2022();
+
+        await transferWithHookChecks(accounts);
+
+        expect(await balance2022(accounts.to)).to.equal(100);
+      });
+
+      it("Rejects a mint whose hook program isn't listed", async () => {
+        // The mint creator picks the hook - here, a program nobody approved
+        const accounts = await setup2022(Keypair.generate().publicKey);
+
+        try {
+          await transferWithHookChecks(accounts);
+          throw new Error("Expected unlisted hook program to be rejected");
+        } catch (error: any) {
+          expect(error.message).to.include("HookNotAllowed");
+          console.log("    ✅ Hook program rejected before the token CPI");
+        }
+
+        expect(await balance2022(accounts.to)).to.equal(0);
+      });
+    });
+  });
+
+  describe("🎯 Manual program id check", () => {
+    const transferManual = (tokenProgram: PublicKey) =>
+      program.methods
+        .transferManual(new anchor.BN(100))
+        .accounts({
+          tokenProgram,
+          from,
+          to,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    it("Transfers when the Token Program is passed", async () => {
+      await transferManual(TOKEN_PROGRAM_ID);
+      expect(await tokenBalance(to)).to.equal(100);
+    });
+
+    it("Rejects a wrong program id with InvalidProgram", async () => {
+      try {
+        await transferManual(FAKE_TOKEN_PROGRAM);
+        throw new Error("Expected wrong program id to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidProgram");
+        console.log("    ✅ require_keys_eq! caught the wrong program");
+      }
+    });
+  });
+
+  describe("🎯 PDA-signed CPI", () => {
+    const [vaultAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("authority")],
+      program.programId,
+    );
+
+    // The vault authority is a program-wide singleton, so only create it once
+    const ensureVaultAuthority = async () => {
+      if (await provider.connection.getAccountInfo(vaultAuthority)) {
+        return;
+      }
+      await program.methods
+        .initializeVaultAuthority()
+        .accounts({ admin: provider.wallet.publicKey, programData })
+        .rpc();
+    };
+
+    // Token account whose authority is the program's PDA
+    const fundedPdaTokenAccount = async () => {
+      const account = await createAccount(
+        provider.connection,
+        authority,
+        mint,
+        vaultAuthority,
+        Keypair.generate(),
+      );
+      await mintTo(
+        provider.connection,
+        authority,
+        mint,
+        account,
+        authority,
+        500,
+      );
+      return account;
+    };
+
+    // Runs first, while the singleton doesn't exist yet
+    it("Rejects initialization by anyone but the upgrade authority", async () => {
+      const intruder = Keypair.generate();
+      const airdrop = await provider.connection.requestAirdrop(
+        intruder.publicKey,
+        LAMPORTS_PER_SOL,
+      );
+      await provider.connection.confirmTransaction(airdrop);
+
+      try {
+        await program.methods
+          .initializeVaultAuthority()
+          .accounts({ admin: intruder.publicKey, programData })
+          .signers([intruder])
+          .rpc();
+
+        throw new Error("Expected a non-upgrade-authority init to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+        console.log("    ✅ Only the upgrade authority can claim the vault");
+      }
+    });
+
+    it("Moves tokens out of a PDA-owned token account", async () => {
+      await ensureVaultAuthority();
+      const pdaTokenAccount = await fundedPdaTokenAccount();
+
+      await program.methods
+        .transferWithPdaAuthority(new anchor.BN(200))
+        .accounts({
+          tokenProgram: TOKEN_PROGRAM_ID,
+          from: pdaTokenAccount,
+          to,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      expect(await tokenBalance(pdaTokenAccount)).to.equal(300);
+      expect(await tokenBalance(to)).to.equal(200);
+      console.log("    ✅ PDA signed the transfer via signer seeds");
+    });
+
+    it("Rejects a caller who isn't the vault admin", async () => {
+      await ensureVaultAuthority();
+      const pdaTokenAccount = await fundedPdaTokenAccount();
+      const intruder = Keypair.generate();
+
+      try {
+        await program.methods
+          .transferWithPdaAuthority(new anchor.BN(500))
+          .accounts({
+            tokenProgram: TOKEN_PROGRAM_ID,
+            from: pdaTokenAccount,
+            to,
+            admin: intruder.publicKey,
+          })
+          .signers([intruder])
+          .rpc();
+
+        throw new Error("Expected a stranger to be refused the PDA signature");
+      } catch (error: any) {
+        expect(error.message).to.include("ConstraintHasOne");
+        console.log("    ✅ Only the stored admin can trigger the PDA");
+      }
+
+      expect(await tokenBalance(pdaTokenAccount)).to.equal(500);
+    });
+
+    it("Rejects a source account the PDA doesn't hold", async () => {
+      await ensureVaultAuthority();
+
+      try {
+        await program.methods
+          .transferWithPdaAuthority(new anchor.BN(100))
+          .accounts({
+            tokenProgram: TOKEN_PROGRAM_ID,
+            from,
+            to,
+            admin: provider.wallet.publicKey,
+          })
+          .rpc();
+
+        throw new Error("Expected a foreign source account to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+  });
+
+  describe("🎯 Mint matching", () => {
+    const transferSecure = (destination: PublicKey) =>
+      program.methods
+        .transferSecure(new anchor.BN(100))
+        .accounts({
+          tokenProgram: TOKEN_PROGRAM_ID,
+          from,
+          to: destination,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    it("Allows a transfer between accounts of the same mint", async () => {
+      await transferSecure(to);
+      expect(await tokenBalance(to)).to.equal(100);
+    });
+
+    it("Rejects a transfer to an account of another mint", async () => {
+      const otherMint = await createMint(
+        provider.connection,
+        authority,
+        authority.publicKey,
+        null,
+        6,
+      );
+      const otherAccount = await createAccount(
+        provider.connection,
+        authority,
+        otherMint,
+        Keypair.generate().publicKey,
+        Keypair.generate(),
+      );
+
+      try {
+        await transferSecure(otherAccount);
+        throw new Error("Expected cross-mint transfer to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("MintMismatch");
+        console.log("    ✅ Cross-mint transfer rejected");
+      }
+    });
+  });
+
+  describe("🎯 Top-level only (instruction introspection)", () => {
+    const rogueProgram = anchor.workspace.RogueCaller as Program<RogueCaller>;
+
+    const transferAccounts = () => ({
+      tokenProgram: TOKEN_PROGRAM_ID,
+      from,
+      to,
+      authority: authority.publicKey,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    });
+
+    it("Transfers when called directly", async () => {
+      await program.methods
+        .transferWithIntrospection(new anchor.BN(100))
+        .accounts(transferAccounts())
+        .signers([authority])
+        .rpc();
+
+      expect(await tokenBalance(to)).to.equal(100);
+    });
+
+    it("Rejects the same transfer relayed through a rogue program", async () => {
+      try {
+        // The user signed for the rogue program; it forwards that
+        // signature into 05 through a CPI
+        await rogueProgram.methods
+          .relayTransfer(new anchor.BN(100))
+          .accounts({
+            arbitraryCpiProgram: program.programId,
+            ...transferAccounts(),
+          })
+          .signers([authority])
+          .rpc();
+
+        throw new Error("Expected the relayed transfer to be rejected");
+      } catch (error: any) {
+        const logs = (error.logs ?? []).join("\n");
+        expect(logs).to.include("Relaying 100 tokens");
+        expect(error.message + logs).to.include("CpiNotAllowed");
+        console.log("    ✅ Top-level instruction belongs to the rogue program");
+      }
+
+      expect(await tokenBalance(from)).to.equal(1_000);
+      expect(await tokenBalance(to)).to.equal(0);
+    });
+  });
 });
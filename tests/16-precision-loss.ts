@@ -0,0 +1,106 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { PrecisionLoss } from "../target/types/precision_loss";
+import { expect } from "chai";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+
+describe("16-precision-loss", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.PrecisionLoss as Program<PrecisionLoss>;
+
+  // A 1_000 stake is one millionth of the pool, so it is owed 1_000
+  const TOTAL_STAKED = 1_000_000_000;
+  const REWARD_POOL = 1_000_000;
+  const STAKE = 1_000;
+  const EXPECTED_REWARD = (STAKE * REWARD_POOL) / TOTAL_STAKED;
+
+  let owner: Keypair;
+  let pool: Keypair;
+  let position: PublicKey;
+
+  beforeEach(async () => {
+    owner = Keypair.generate();
+    pool = Keypair.generate();
+
+    const airdrop = await provider.connection.requestAirdrop(
+      owner.publicKey,
+      2 * LAMPORTS_PER_SOL,
+    );
+    await provider.connection.confirmTransaction(airdrop);
+
+    await program.methods
+      .initializePool(new anchor.BN(TOTAL_STAKED), new anchor.BN(REWARD_POOL))
+      .accounts({
+        pool: pool.publicKey,
+        authority: owner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner, pool])
+      .rpc();
+
+    [position] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("position"),
+        pool.publicKey.toBuffer(),
+        owner.publicKey.toBuffer(),
+      ],
+      program.programId,
+    );
+
+    await program.methods
+      .openPosition(new anchor.BN(STAKE))
+      .accounts({
+        owner: owner.publicKey,
+        pool: pool.publicKey,
+        position,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+  });
+
+  describe("❌ VULNERABLE: Divide then multiply", () => {
+    it("Rounds a small staker's reward to zero", async () => {
+      await program.methods
+        .accrueInsecure()
+        .accounts({
+          owner: owner.publicKey,
+          pool: pool.publicKey,
+          position,
+        })
+        .signers([owner])
+        .rpc();
+
+      const account = await program.account.position.fetch(position);
+      expect(account.reward.toNumber()).to.equal(0);
+      console.log(
+        `    🚨 EXPLOIT SUCCESSFUL: reward 0, owed ${EXPECTED_REWARD}`,
+      );
+    });
+  });
+
+  describe("✅ SECURE: Multiply first in u128", () => {
+    it("Pays the correct proportional reward", async () => {
+      await program.methods
+        .accrueSecure()
+        .accounts({
+          owner: owner.publicKey,
+          pool: pool.publicKey,
+          position,
+        })
+        .signers([owner])
+        .rpc();
+
+      const account = await program.account.position.fetch(position);
+      expect(account.reward.toNumber()).to.equal(EXPECTED_REWARD);
+      console.log(`    ✅ Reward ${EXPECTED_REWARD} accrued correctly`);
+    });
+  });
+});
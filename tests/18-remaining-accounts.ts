@@ -0,0 +1,169 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { RemainingAccounts } from "../target/types/remaining_accounts";
+import { expect } from "chai";
+import { ProgramTestContext } from "solana-bankrun";
+import {
+  AccountMeta,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { ownedAccount, setupProgram, systemAccount } from "./helpers/harness";
+
+describe("18-remaining-accounts", () => {
+  const program = anchor.workspace
+    .RemainingAccounts as Program<RemainingAccounts>;
+
+  const REWARD = LAMPORTS_PER_SOL / 10;
+  const ROGUE_REWARD = 5 * LAMPORTS_PER_SOL;
+
+  // The rogue account is staged with bankrun: it is owned by an
+  // "attacker program" and holds bytes shaped like a Reward
+  let context: ProgramTestContext;
+  let bankrunProgram: Program<RemainingAccounts>;
+  const attacker = Keypair.generate();
+  const user = Keypair.generate();
+  let admin: PublicKey;
+  const rogueReward = Keypair.generate().publicKey;
+
+  const [treasury] = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    program.programId,
+  );
+
+  const rewardPda = (owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("reward"),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+        owner.toBuffer(),
+      ],
+      program.programId,
+    );
+
+  const pair = (reward: PublicKey, recipient: PublicKey): AccountMeta[] => [
+    { pubkey: reward, isSigner: false, isWritable: false },
+    { pubkey: recipient, isSigner: false, isWritable: true },
+  ];
+
+  const balance = async (account: PublicKey) =>
+    Number(await context.banksClient.getBalance(account));
+
+  beforeEach(async () => {
+    const rogueData = await program.coder.accounts.encode("reward", {
+      owner: attacker.publicKey,
+      epoch: new anchor.BN(0),
+      amount: new anchor.BN(ROGUE_REWARD),
+      bump: 255,
+    });
+
+    ({ context, program: bankrunProgram } = await setupProgram(program, [
+      ownedAccount(rogueReward, Keypair.generate().publicKey, rogueData),
+      systemAccount(attacker.publicKey),
+      systemAccount(user.publicKey),
+    ]));
+
+    admin = context.payer.publicKey;
+
+    await bankrunProgram.methods
+      .initializeTreasury(new anchor.BN(10 * LAMPORTS_PER_SOL))
+      .accounts({ admin, treasury, systemProgram: SystemProgram.programId })
+      .rpc();
+
+    await bankrunProgram.methods
+      .createReward(user.publicKey, new anchor.BN(REWARD))
+      .accounts({
+        admin,
+        treasury,
+        reward: rewardPda(user.publicKey)[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  describe("❌ VULNERABLE: Unchecked remaining_accounts", () => {
+    it("Pays out an injected rogue reward", async () => {
+      const before = await balance(attacker.publicKey);
+
+      await bankrunProgram.methods
+        .distributeInsecure()
+        .accounts({ treasury })
+        .remainingAccounts(pair(rogueReward, attacker.publicKey))
+        .rpc();
+
+      expect((await balance(attacker.publicKey)) - before).to.equal(
+        ROGUE_REWARD,
+      );
+      console.log("    🚨 EXPLOIT SUCCESSFUL: rogue reward drained 5 SOL");
+    });
+  });
+
+  describe("✅ SECURE: Validated remaining_accounts", () => {
+    it("Rejects an account not owned by the program", async () => {
+      try {
+        await bankrunProgram.methods
+          .distributeSecure()
+          .accounts({ admin, treasury })
+          .remainingAccounts(pair(rogueReward, attacker.publicKey))
+          .rpc();
+
+        throw new Error("Expected rogue reward to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidRewardAccount");
+        console.log("    ✅ Rogue reward rejected");
+      }
+    });
+
+    it("Rejects the same reward listed twice", async () => {
+      const [reward] = rewardPda(user.publicKey);
+
+      try {
+        await bankrunProgram.methods
+          .distributeSecure()
+          .accounts({ admin, treasury })
+          .remainingAccounts([
+            ...pair(reward, user.publicKey),
+            ...pair(reward, user.publicKey),
+          ])
+          .rpc();
+
+        throw new Error("Expected duplicate reward to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("DuplicateReward");
+      }
+    });
+
+    it("Pays a genuine reward", async () => {
+      const [reward] = rewardPda(user.publicKey);
+      const before = await balance(user.publicKey);
+
+      await bankrunProgram.methods
+        .distributeSecure()
+        .accounts({ admin, treasury })
+        .remainingAccounts(pair(reward, user.publicKey))
+        .rpc();
+
+      expect((await balance(user.publicKey)) - before).to.equal(REWARD);
+    });
+
+    it("Rejects a crank from anyone but the admin", async () => {
+      // 🚨 An empty crank would end the epoch and strand the user's reward
+      try {
+        await bankrunProgram.methods
+          .distributeSecure()
+          .accounts({ admin: attacker.publicKey, treasury })
+          .signers([attacker])
+          .rpc();
+
+        throw new Error("Expected a stranger's crank to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+
+      const account = await bankrunProgram.account.treasury.fetch(treasury);
+      expect(account.epoch.toNumber()).to.equal(0);
+    });
+  });
+});
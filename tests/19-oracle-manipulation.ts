@@ -0,0 +1,171 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { OracleManipulation } from "../target/types/oracle_manipulation";
+import { expect } from "chai";
+import { ProgramTestContext } from "solana-bankrun";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { setupProgram } from "./helpers/harness";
+
+describe("19-oracle-manipulation", () => {
+  const program = anchor.workspace
+    .OracleManipulation as Program<OracleManipulation>;
+
+  // 100 units of collateral at $100 (6 decimals) allows borrowing 5_000
+  const CURRENT_SLOT = 1_000;
+  const PRICE = 100_000_000;
+  const COLLATERAL = 100;
+  const BORROW = 4_000;
+
+  // Bankrun lets the tests warp the clock to a known slot
+  let context: ProgramTestContext;
+  let bankrunProgram: Program<OracleManipulation>;
+  let owner: PublicKey;
+  let market: PublicKey;
+  let loan: PublicKey;
+  let feed: Keypair;
+
+  const initializeFeed = async (priceFeed: Keypair) =>
+    bankrunProgram.methods
+      .initializeFeed()
+      .accounts({
+        priceFeed: priceFeed.publicKey,
+        authority: owner,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([priceFeed])
+      .rpc();
+
+  const publish = async (
+    price: number,
+    conf: number,
+    slot: number,
+    priceFeed: PublicKey = feed.publicKey,
+  ) =>
+    bankrunProgram.methods
+      .updatePrice(new anchor.BN(price), new anchor.BN(conf), new anchor.BN(slot))
+      .accounts({ authority: owner, priceFeed })
+      .rpc();
+
+  const borrow = (secure: boolean, priceFeed: PublicKey = feed.publicKey) =>
+    secure
+      ? bankrunProgram.methods
+          .borrowSecure(new anchor.BN(BORROW))
+          .accounts({ owner, loan, market, priceFeed })
+          .rpc()
+      : bankrunProgram.methods
+          .borrowInsecure(new anchor.BN(BORROW))
+          .accounts({ owner, loan, priceFeed })
+          .rpc();
+
+  beforeEach(async () => {
+    ({ context, program: bankrunProgram } = await setupProgram(program));
+    context.warpToSlot(BigInt(CURRENT_SLOT));
+    owner = context.payer.publicKey;
+    feed = Keypair.generate();
+    await initializeFeed(feed);
+
+    [market] = PublicKey.findProgramAddressSync(
+      [Buffer.from("market"), owner.toBuffer()],
+      program.programId,
+    );
+    await bankrunProgram.methods
+      .initializeMarket()
+      .accounts({
+        admin: owner,
+        market,
+        priceFeed: feed.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    [loan] = PublicKey.findProgramAddressSync(
+      [Buffer.from("loan"), market.toBuffer(), owner.toBuffer()],
+      program.programId,
+    );
+    await bankrunProgram.methods
+      .openLoan(new anchor.BN(COLLATERAL))
+      .accounts({ owner, market, loan, systemProgram: SystemProgram.programId })
+      .rpc();
+  });
+
+  describe("❌ VULNERABLE: Raw oracle price", () => {
+    it("Borrows against a stale price", async () => {
+      await publish(PRICE, PRICE / 1_000, 0);
+      await borrow(false);
+
+      const account = await bankrunProgram.account.loan.fetch(loan);
+      expect(account.borrowed.toNumber()).to.equal(BORROW);
+      console.log("    🚨 EXPLOIT SUCCESSFUL: borrowed on a 1000-slot-old price");
+    });
+
+    it("Borrows against a ±30% price", async () => {
+      await publish(PRICE, (PRICE * 30) / 100, CURRENT_SLOT);
+      await borrow(false);
+
+      const account = await bankrunProgram.account.loan.fetch(loan);
+      expect(account.borrowed.toNumber()).to.equal(BORROW);
+      console.log("    🚨 EXPLOIT SUCCESSFUL: borrowed on an unreliable price");
+    });
+  });
+
+  describe("✅ SECURE: Staleness and confidence checks", () => {
+    it("Rejects a stale price", async () => {
+      await publish(PRICE, PRICE / 1_000, 0);
+
+      try {
+        await borrow(true);
+        throw new Error("Expected stale price to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("StalePrice");
+        console.log("    ✅ Stale price rejected");
+      }
+    });
+
+    it("Rejects a wide confidence interval", async () => {
+      await publish(PRICE, (PRICE * 30) / 100, CURRENT_SLOT);
+
+      try {
+        await borrow(true);
+        throw new Error("Expected wide confidence to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("WideConfidence");
+        console.log("    ✅ Wide confidence rejected");
+      }
+    });
+
+    it("Rejects a price published in the future", async () => {
+      await publish(PRICE, PRICE / 1_000, CURRENT_SLOT + 1_000);
+
+      try {
+        await borrow(true);
+        throw new Error("Expected future price to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("FuturePrice");
+        console.log("    ✅ Future publish slot rejected");
+      }
+    });
+
+    it("Rejects a feed other than the market's", async () => {
+      // 🚨 A feed anyone can create: fresh, tight, and 1000x the price
+      const rogueFeed = Keypair.generate();
+      await initializeFeed(rogueFeed);
+      await publish(PRICE * 1_000, PRICE, CURRENT_SLOT, rogueFeed.publicKey);
+
+      try {
+        await borrow(true, rogueFeed.publicKey);
+        throw new Error("Expected a foreign feed to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("WrongPriceFeed");
+        console.log("    ✅ Feed outside the market rejected");
+      }
+    });
+
+    it("Accepts a fresh, precise price", async () => {
+      await publish(PRICE, PRICE / 1_000, CURRENT_SLOT);
+      await borrow(true);
+
+      const account = await bankrunProgram.account.loan.fetch(loan);
+      expect(account.borrowed.toNumber()).to.equal(BORROW);
+    });
+  });
+});
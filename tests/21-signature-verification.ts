@@ -0,0 +1,193 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { SignatureVerification } from "../target/types/signature_verification";
+import { expect } from "chai";
+import {
+  Ed25519Program,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SystemProgram,
+} from "@solana/web3.js";
+
+describe("21-signature-verification", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace
+    .SignatureVerification as Program<SignatureVerification>;
+
+  const AMOUNT = 1_000;
+
+  // The config is a program-wide singleton, so every test shares its signer
+  const voucherSigner = Keypair.generate();
+  const [config] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId,
+  );
+  // Only the upgrade authority (the provider wallet) may create the config
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"),
+  );
+
+  let claimant: Keypair;
+  let voucher: PublicKey;
+
+  // Must match voucher_message: claimant pubkey || amount (u64 LE)
+  const voucherMessage = (claimant: PublicKey, amount: number) =>
+    Buffer.concat([
+      claimant.toBuffer(),
+      new anchor.BN(amount).toArrayLike(Buffer, "le", 8),
+    ]);
+
+  const verifyIx = (signer: Keypair, message: Buffer) =>
+    Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: signer.secretKey,
+      message,
+    });
+
+  const redeemSecure = (amount: number) =>
+    program.methods.redeemSecure(new anchor.BN(amount)).accounts({
+      claimant: claimant.publicKey,
+      voucher,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    });
+
+  const ensureConfig = async () => {
+    if (await provider.connection.getAccountInfo(config)) {
+      return;
+    }
+    await program.methods
+      .initializeConfig(voucherSigner.publicKey)
+      .accounts({ admin: provider.wallet.publicKey, programData })
+      .rpc();
+  };
+
+  beforeEach(async () => {
+    claimant = Keypair.generate();
+
+    const airdrop = await provider.connection.requestAirdrop(
+      claimant.publicKey,
+      LAMPORTS_PER_SOL,
+    );
+    await provider.connection.confirmTransaction(airdrop);
+
+    [voucher] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("voucher"),
+        config.toBuffer(),
+        claimant.publicKey.toBuffer(),
+      ],
+      program.programId,
+    );
+  });
+
+  // Runs first, while the singleton doesn't exist yet
+  describe("🔐 Config initialization", () => {
+    it("Rejects a config created by anyone but the upgrade authority", async () => {
+      try {
+        await program.methods
+          .initializeConfig(claimant.publicKey)
+          .accounts({ admin: claimant.publicKey, programData })
+          .signers([claimant])
+          .rpc();
+
+        throw new Error("Expected a non-upgrade-authority init to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+        console.log("    ✅ A claimant can't name their own voucher signer");
+      }
+    });
+
+    it("Stores the voucher signer in the singleton config", async () => {
+      await ensureConfig();
+
+      const state = await program.account.config.fetch(config);
+      expect(state.signer.toBase58()).to.equal(
+        voucherSigner.publicKey.toBase58(),
+      );
+    });
+  });
+
+  describe("❌ VULNERABLE: Signature never verified", () => {
+    before(ensureConfig);
+
+    it("Redeems with 64 random bytes", async () => {
+      const fakeSignature = Array.from(Keypair.generate().secretKey);
+
+      await program.methods
+        .redeemInsecure(new anchor.BN(1_000_000), fakeSignature)
+        .accounts({
+          claimant: claimant.publicKey,
+          voucher,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([claimant])
+        .rpc();
+
+      const account = await program.account.voucher.fetch(voucher);
+      expect(account.amount.toNumber()).to.equal(1_000_000);
+      console.log("    🚨 EXPLOIT SUCCESSFUL: forged voucher redeemed");
+    });
+  });
+
+  describe("✅ SECURE: Bound to Ed25519 verification", () => {
+    before(ensureConfig);
+
+    it("Redeems with a real verification of the exact message", async () => {
+      await redeemSecure(AMOUNT)
+        .preInstructions([
+          verifyIx(voucherSigner, voucherMessage(claimant.publicKey, AMOUNT)),
+        ])
+        .signers([claimant])
+        .rpc();
+
+      const account = await program.account.voucher.fetch(voucher);
+      expect(account.amount.toNumber()).to.equal(AMOUNT);
+      console.log("    ✅ Voucher bound to verified signature");
+    });
+
+    it("Rejects a redemption with no verification instruction", async () => {
+      try {
+        await redeemSecure(AMOUNT).signers([claimant]).rpc();
+
+        throw new Error("Expected missing verification to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("MissingSignatureVerification");
+      }
+    });
+
+    it("Rejects a verification for a different amount", async () => {
+      try {
+        await redeemSecure(1_000_000)
+          .preInstructions([
+            verifyIx(voucherSigner, voucherMessage(claimant.publicKey, AMOUNT)),
+          ])
+          .signers([claimant])
+          .rpc();
+
+        throw new Error("Expected message mismatch to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("MessageMismatch");
+      }
+    });
+
+    it("Rejects a message signed by the wrong key", async () => {
+      try {
+        await redeemSecure(AMOUNT)
+          .preInstructions([
+            verifyIx(claimant, voucherMessage(claimant.publicKey, AMOUNT)),
+          ])
+          .signers([claimant])
+          .rpc();
+
+        throw new Error("Expected wrong signer to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("WrongSigner");
+      }
+    });
+  });
+});
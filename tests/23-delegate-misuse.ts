@@ -0,0 +1,186 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { DelegateMisuse } from "../target/types/delegate_misuse";
+import { expect } from "chai";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  createAccount,
+  createMint,
+  getAccount,
+  mintTo,
+} from "@solana/spl-token";
+
+describe("23-delegate-misuse", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.DelegateMisuse as Program<DelegateMisuse>;
+
+  const VAULT_BALANCE = 1_000;
+  const ALLOWANCE = 100;
+
+  let user: Keypair;
+  let spender: Keypair;
+  let vaultAuthority: PublicKey;
+  let vaultToken: PublicKey;
+  let destination: PublicKey;
+
+  const spendAccounts = () => ({
+    spender: spender.publicKey,
+    user: user.publicKey,
+    vaultAuthority,
+    vaultToken,
+    destination,
+    tokenProgram: TOKEN_PROGRAM_ID,
+  });
+
+  const vault = () => getAccount(provider.connection, vaultToken);
+
+  beforeEach(async () => {
+    user = Keypair.generate();
+    spender = Keypair.generate();
+
+    const airdrop = await provider.connection.requestAirdrop(
+      user.publicKey,
+      2 * LAMPORTS_PER_SOL,
+    );
+    await provider.connection.confirmTransaction(airdrop);
+
+    const mint = await createMint(
+      provider.connection,
+      user,
+      user.publicKey,
+      null,
+      0,
+    );
+
+    [vaultAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_authority"), user.publicKey.toBuffer()],
+      program.programId,
+    );
+    [vaultToken] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_token"), user.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .openVault()
+      .accounts({
+        user: user.publicKey,
+        mint,
+        vaultAuthority,
+        vaultToken,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    await mintTo(provider.connection, user, mint, vaultToken, user, VAULT_BALANCE);
+
+    destination = await createAccount(
+      provider.connection,
+      user,
+      mint,
+      spender.publicKey,
+      Keypair.generate(),
+    );
+
+    await program.methods
+      .approveSpender(new anchor.BN(ALLOWANCE))
+      .accounts({
+        user: user.publicKey,
+        spender: spender.publicKey,
+        vaultAuthority,
+        vaultToken,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+  });
+
+  describe("❌ VULNERABLE: Owner authority for delegate", () => {
+    it("Lets the delegate spend past its allowance", async () => {
+      await program.methods
+        .spendInsecure(new anchor.BN(VAULT_BALANCE))
+        .accounts(spendAccounts())
+        .signers([spender])
+        .rpc();
+
+      const account = await vault();
+      expect(Number(account.amount)).to.equal(0);
+      expect(Number(account.delegatedAmount)).to.equal(ALLOWANCE);
+      console.log(
+        `    🚨 EXPLOIT SUCCESSFUL: delegate spent ${VAULT_BALANCE} on a ${ALLOWANCE} allowance`,
+      );
+    });
+  });
+
+  describe("✅ SECURE: Allowance enforced", () => {
+    it("Rejects spending past the allowance", async () => {
+      try {
+        await program.methods
+          .spendSecure(new anchor.BN(ALLOWANCE + 1))
+          .accounts(spendAccounts())
+          .signers([spender])
+          .rpc();
+
+        throw new Error("Expected allowance to be enforced");
+      } catch (error: any) {
+        expect(error.message).to.include("ExceedsDelegatedAmount");
+        console.log("    ✅ Delegated limit enforced");
+      }
+    });
+
+    it("Decrements the allowance and revokes it when used up", async () => {
+      await program.methods
+        .spendSecure(new anchor.BN(60))
+        .accounts(spendAccounts())
+        .signers([spender])
+        .rpc();
+
+      expect(Number((await vault()).delegatedAmount)).to.equal(ALLOWANCE - 60);
+
+      await program.methods
+        .spendSecure(new anchor.BN(ALLOWANCE - 60))
+        .accounts(spendAccounts())
+        .signers([spender])
+        .rpc();
+
+      const account = await vault();
+      expect(account.delegate).to.be.null;
+      expect(Number(account.amount)).to.equal(VAULT_BALANCE - ALLOWANCE);
+    });
+
+    it("Rejects a revoked delegate", async () => {
+      await program.methods
+        .revokeSpender()
+        .accounts({
+          user: user.publicKey,
+          vaultAuthority,
+          vaultToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+
+      try {
+        await program.methods
+          .spendSecure(new anchor.BN(1))
+          .accounts(spendAccounts())
+          .signers([spender])
+          .rpc();
+
+        throw new Error("Expected revoked delegate to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("NotDelegate");
+      }
+    });
+  });
+});
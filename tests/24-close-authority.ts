@@ -0,0 +1,170 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { CloseAuthority } from "../target/types/close_authority";
+import { expect } from "chai";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  createAccount,
+  createMint,
+  getAccount,
+  mintTo,
+} from "@solana/spl-token";
+
+describe("24-close-authority", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.CloseAuthority as Program<CloseAuthority>;
+
+  const ESCROWED = 500;
+
+  let maker: Keypair;
+  let attacker: Keypair;
+  let escrow: PublicKey;
+  let escrowToken: PublicKey;
+  let makerToken: PublicKey;
+  let attackerToken: PublicKey;
+
+  const tokenBalance = async (account: PublicKey) =>
+    Number((await getAccount(provider.connection, account)).amount);
+
+  const lamports = (account: PublicKey) =>
+    provider.connection.getBalance(account);
+
+  const closeAccounts = (refundToken: PublicKey, rentDestination: PublicKey) => ({
+    closer: attacker.publicKey,
+    escrow,
+    escrowToken,
+    refundToken,
+    rentDestination,
+    tokenProgram: TOKEN_PROGRAM_ID,
+  });
+
+  beforeEach(async () => {
+    maker = Keypair.generate();
+    attacker = Keypair.generate();
+
+    for (const wallet of [maker, attacker]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        wallet.publicKey,
+        2 * LAMPORTS_PER_SOL,
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    const mint = await createMint(
+      provider.connection,
+      maker,
+      maker.publicKey,
+      null,
+      0,
+    );
+
+    [escrow] = PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow"), maker.publicKey.toBuffer()],
+      program.programId,
+    );
+    [escrowToken] = PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow_token"), maker.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    // unlock_at = 0: already expired, so anyone may crank the close
+    await program.methods
+      .openEscrow(new anchor.BN(0))
+      .accounts({
+        maker: maker.publicKey,
+        mint,
+        escrow,
+        escrowToken,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([maker])
+      .rpc();
+
+    await mintTo(provider.connection, maker, mint, escrowToken, maker, ESCROWED);
+
+    makerToken = await createAccount(
+      provider.connection,
+      maker,
+      mint,
+      maker.publicKey,
+      Keypair.generate(),
+    );
+    attackerToken = await createAccount(
+      provider.connection,
+      attacker,
+      mint,
+      attacker.publicKey,
+      Keypair.generate(),
+    );
+  });
+
+  describe("❌ VULNERABLE: Unchecked close destinations", () => {
+    it("Sends the escrow's tokens and rent to the attacker", async () => {
+      const before = await lamports(attacker.publicKey);
+
+      await program.methods
+        .closeEscrowInsecure()
+        .accounts(closeAccounts(attackerToken, attacker.publicKey))
+        .signers([attacker])
+        .rpc();
+
+      expect(await tokenBalance(attackerToken)).to.equal(ESCROWED);
+      expect(await lamports(attacker.publicKey)).to.be.greaterThan(before);
+      console.log("    🚨 EXPLOIT SUCCESSFUL: attacker took tokens and rent");
+    });
+  });
+
+  describe("✅ SECURE: Destinations pinned to maker", () => {
+    it("Rejects an attacker rent destination", async () => {
+      try {
+        await program.methods
+          .closeEscrowSecure()
+          .accounts(closeAccounts(makerToken, attacker.publicKey))
+          .signers([attacker])
+          .rpc();
+
+        throw new Error("Expected attacker destination to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidDestination");
+        console.log("    ✅ Attacker rent destination rejected");
+      }
+    });
+
+    it("Rejects an attacker refund token account", async () => {
+      try {
+        await program.methods
+          .closeEscrowSecure()
+          .accounts(closeAccounts(attackerToken, maker.publicKey))
+          .signers([attacker])
+          .rpc();
+
+        throw new Error("Expected attacker token account to be rejected");
+      } catch (error: any) {
+        expect(error.message).to.include("ConstraintTokenOwner");
+      }
+    });
+
+    it("Refunds the maker whoever cranks the close", async () => {
+      const before = await lamports(maker.publicKey);
+
+      await program.methods
+        .closeEscrowSecure()
+        .accounts(closeAccounts(makerToken, maker.publicKey))
+        .signers([attacker])
+        .rpc();
+
+      expect(await tokenBalance(makerToken)).to.equal(ESCROWED);
+      expect(await lamports(maker.publicKey)).to.be.greaterThan(before);
+      expect(await provider.connection.getAccountInfo(escrowToken)).to.be.null;
+    });
+  });
+});
@@ -0,0 +1,151 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Slippage } from "../target/types/slippage";
+import { expect } from "chai";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+
+describe("25-slippage", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Slippage as Program<Slippage>;
+
+  const RESERVE = 1_000_000;
+  const VICTIM_IN = 10_000;
+  const ATTACKER_IN = 100_000;
+  const TOLERANCE_BPS = 100;
+
+  let pool: Keypair;
+  let victim: Keypair;
+  let attacker: Keypair;
+
+  const trader = (owner: Keypair) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("trader"), pool.publicKey.toBuffer(), owner.publicKey.toBuffer()],
+      program.programId,
+    )[0];
+
+  // Same formula as execute_swap
+  const quote = async (amountIn: number) => {
+    const { reserveA, reserveB } = await program.account.pool.fetch(
+      pool.publicKey,
+    );
+    return Math.floor(
+      (reserveB.toNumber() * amountIn) / (reserveA.toNumber() + amountIn),
+    );
+  };
+
+  const swapAccounts = (owner: Keypair) => ({
+    owner: owner.publicKey,
+    pool: pool.publicKey,
+    trader: trader(owner),
+  });
+
+  const frontRun = () =>
+    program.methods
+      .swapInsecure(new anchor.BN(ATTACKER_IN))
+      .accounts(swapAccounts(attacker))
+      .signers([attacker])
+      .rpc();
+
+  beforeEach(async () => {
+    pool = Keypair.generate();
+    victim = Keypair.generate();
+    attacker = Keypair.generate();
+
+    for (const wallet of [victim, attacker]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        wallet.publicKey,
+        LAMPORTS_PER_SOL,
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    await program.methods
+      .initializePool(new anchor.BN(RESERVE), new anchor.BN(RESERVE))
+      .accounts({
+        pool: pool.publicKey,
+        payer: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([pool])
+      .rpc();
+
+    for (const owner of [victim, attacker]) {
+      await program.methods
+        .openTrader(new anchor.BN(RESERVE))
+        .accounts({
+          owner: owner.publicKey,
+          pool: pool.publicKey,
+          trader: trader(owner),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+    }
+  });
+
+  describe("❌ VULNERABLE: No minimum output", () => {
+    it("Fills the victim at the manipulated price", async () => {
+      const quoted = await quote(VICTIM_IN);
+      await frontRun();
+
+      await program.methods
+        .swapInsecure(new anchor.BN(VICTIM_IN))
+        .accounts(swapAccounts(victim))
+        .signers([victim])
+        .rpc();
+
+      const received = (
+        await program.account.trader.fetch(trader(victim))
+      ).balanceB.toNumber();
+      expect(received).to.be.lessThan(quoted);
+      console.log(
+        `    🚨 EXPLOIT SUCCESSFUL: quoted ${quoted} B, received ${received} B`,
+      );
+    });
+  });
+
+  describe("✅ SECURE: min_amount_out", () => {
+    it("Reverts when the price moves past the tolerance", async () => {
+      const quoted = await quote(VICTIM_IN);
+      const minOut = Math.floor((quoted * (10_000 - TOLERANCE_BPS)) / 10_000);
+      await frontRun();
+
+      try {
+        await program.methods
+          .swapSecure(new anchor.BN(VICTIM_IN), new anchor.BN(minOut))
+          .accounts(swapAccounts(victim))
+          .signers([victim])
+          .rpc();
+
+        throw new Error("Expected slippage guard to trip");
+      } catch (error: any) {
+        expect(error.message).to.include("SlippageExceeded");
+        console.log("    ✅ Sandwiched swap reverted");
+      }
+
+      const account = await program.account.trader.fetch(trader(victim));
+      expect(account.balanceA.toNumber()).to.equal(RESERVE);
+    });
+
+    it("Fills at the quote when the price hasn't moved", async () => {
+      const quoted = await quote(VICTIM_IN);
+      const minOut = Math.floor((quoted * (10_000 - TOLERANCE_BPS)) / 10_000);
+
+      await program.methods
+        .swapSecure(new anchor.BN(VICTIM_IN), new anchor.BN(minOut))
+        .accounts(swapAccounts(victim))
+        .signers([victim])
+        .rpc();
+
+      const account = await program.account.trader.fetch(trader(victim));
+      expect(account.balanceB.toNumber()).to.equal(quoted);
+    });
+  });
+});
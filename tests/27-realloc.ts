@@ -0,0 +1,129 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Realloc } from "../target/types/realloc";
+import { expect } from "chai";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+
+describe("27-realloc", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Realloc as Program<Realloc>;
+
+  // Discriminator + authority + Vec length prefix
+  const HEADER = 8 + 32 + 4;
+  const ITEM_SIZE = 8;
+  const RESERVE = 4;
+
+  let authority: Keypair;
+  let list: PublicKey;
+
+  beforeEach(async () => {
+    authority = Keypair.generate();
+    const airdrop = await provider.connection.requestAirdrop(
+      authority.publicKey,
+      LAMPORTS_PER_SOL,
+    );
+    await provider.connection.confirmTransaction(airdrop);
+
+    const listKeypair = Keypair.generate();
+    await program.methods
+      .initializeList()
+      .accounts({
+        list: listKeypair.publicKey,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority, listKeypair])
+      .rpc();
+    list = listKeypair.publicKey;
+  });
+
+  const pushItem = (item: number) =>
+    program.methods
+      .pushItem(new anchor.BN(item))
+      .accounts({ authority: authority.publicKey, list })
+      .signers([authority])
+      .rpc();
+
+  describe("❌ VULNERABLE: realloc without rent or zeroing", () => {
+    it("Leaves the grown account below the rent-exempt minimum", async () => {
+      try {
+        await program.methods
+          .reserveInsecure(RESERVE)
+          .accounts({ authority: authority.publicKey, list })
+          .signers([authority])
+          .rpc();
+
+        throw new Error("Expected the runtime to reject the growth");
+      } catch (error: any) {
+        // Nobody paid for the extra bytes - the runtime refuses the result
+        expect(error.message).to.include("insufficient funds for rent");
+        console.log("    🚨 Growth without a rent top-up is rejected");
+      }
+
+      const info = await provider.connection.getAccountInfo(list);
+      expect(info!.data.length).to.equal(HEADER);
+    });
+  });
+
+  describe("✅ SECURE: resize + rent top-up", () => {
+    it("Grows the list with a zeroed, rent-exempt region", async () => {
+      const before = await provider.connection.getAccountInfo(list);
+
+      await program.methods
+        .reserveSecure(RESERVE)
+        .accounts({
+          authority: authority.publicKey,
+          list,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      const after = await provider.connection.getAccountInfo(list);
+      const newLen = HEADER + RESERVE * ITEM_SIZE;
+      expect(after!.data.length).to.equal(newLen);
+      expect(after!.data.subarray(HEADER).every((byte) => byte === 0)).to.be
+        .true;
+
+      const rentExempt =
+        await provider.connection.getMinimumBalanceForRentExemption(newLen);
+      expect(after!.lamports).to.be.at.least(rentExempt);
+      expect(after!.lamports).to.be.greaterThan(before!.lamports);
+      console.log("    ✅ New bytes zeroed, rent paid by the authority");
+    });
+
+    it("Fills reserved capacity and refuses to overflow it", async () => {
+      await program.methods
+        .reserveSecure(2)
+        .accounts({
+          authority: authority.publicKey,
+          list,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      await pushItem(7);
+      await pushItem(11);
+
+      const account = await program.account.itemList.fetch(list);
+      expect(account.items.map((item) => item.toNumber())).to.deep.equal([
+        7, 11,
+      ]);
+
+      try {
+        await pushItem(13);
+        throw new Error("Expected push past capacity to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("ListFull");
+      }
+    });
+  });
+});
@@ -0,0 +1,125 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Vesting } from "../target/types/vesting";
+import { expect } from "chai";
+import { Clock, ProgramTestContext } from "solana-bankrun";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { setupProgram, systemAccount } from "./helpers/harness";
+
+describe("28-vesting", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Vesting as Program<Vesting>;
+
+  const TOTAL = 1_000_000;
+  const CLIFF = 250;
+  const DURATION = 1_000;
+
+  // Vesting is all about time, so every test runs against bankrun where the
+  // Clock sysvar can be set to any point of the schedule
+  let context: ProgramTestContext;
+  let bankrunProgram: Program<Vesting>;
+  let beneficiary: Keypair;
+  let schedule: PublicKey;
+  let start: number;
+
+  const setTime = async (unixTimestamp: number) => {
+    const clock = await context.banksClient.getClock();
+    context.setClock(
+      new Clock(
+        clock.slot,
+        clock.epochStartTimestamp,
+        clock.epoch,
+        clock.leaderScheduleEpoch,
+        BigInt(unixTimestamp),
+      ),
+    );
+  };
+
+  const releaseSecure = () =>
+    bankrunProgram.methods
+      .releaseSecure()
+      .accounts({ beneficiary: beneficiary.publicKey, schedule })
+      .signers([beneficiary])
+      .rpc();
+
+  const released = async () =>
+    (
+      await bankrunProgram.account.vestingSchedule.fetch(schedule)
+    ).released.toNumber();
+
+  beforeEach(async () => {
+    beneficiary = Keypair.generate();
+    ({ context, program: bankrunProgram } = await setupProgram(program, [
+      systemAccount(beneficiary.publicKey),
+    ]));
+
+    [schedule] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vesting"), beneficiary.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    start = Number((await context.banksClient.getClock()).unixTimestamp);
+
+    await bankrunProgram.methods
+      .createSchedule(
+        new anchor.BN(TOTAL),
+        new anchor.BN(start),
+        new anchor.BN(CLIFF),
+        new anchor.BN(DURATION),
+      )
+      .accounts({
+        creator: context.payer.publicKey,
+        beneficiary: beneficiary.publicKey,
+        schedule,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  describe("❌ VULNERABLE: Caller-supplied timestamp", () => {
+    it("Releases the whole grant before the cliff", async () => {
+      // The real clock is barely past the start
+      await setTime(start + 10);
+
+      await bankrunProgram.methods
+        .releaseInsecure(new anchor.BN(start + 10 * DURATION))
+        .accounts({ beneficiary: beneficiary.publicKey, schedule })
+        .signers([beneficiary])
+        .rpc();
+
+      expect(await released()).to.equal(TOTAL);
+      console.log("    🚨 EXPLOIT SUCCESSFUL: spoofed timestamp unlocked 100%");
+    });
+  });
+
+  describe("✅ SECURE: Clock sysvar", () => {
+    it("Vests nothing before the cliff", async () => {
+      await setTime(start + CLIFF - 1);
+
+      try {
+        await releaseSecure();
+        throw new Error("Expected release before the cliff to fail");
+      } catch (error: any) {
+        expect(error.message).to.include("NothingToRelease");
+      }
+      expect(await released()).to.equal(0);
+    });
+
+    it("Vests linearly mid-schedule", async () => {
+      await setTime(start + DURATION / 2);
+      await releaseSecure();
+
+      expect(await released()).to.equal(TOTAL / 2);
+    });
+
+    it("Caps the release at the total past the end", async () => {
+      await setTime(start + 10 * DURATION);
+      await releaseSecure();
+
+      expect(await released()).to.equal(TOTAL);
+      console.log("    ✅ Clamped to total_amount");
+    });
+  });
+});
@@ -0,0 +1,184 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { DosUnbounded } from "../target/types/dos_unbounded";
+import { expect } from "chai";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { ownedAccount, setupProgram, systemAccount } from "./helpers/harness";
+
+describe("29-dos-unbounded", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.DosUnbounded as Program<DosUnbounded>;
+
+  const MAX_ENTRIES = 16;
+  const MAX_ENTRIES_PER_USER = 4;
+  const ENTRY_SIZE = 32 + 8;
+
+  let users: Keypair[];
+  let nextValue = 0;
+
+  const airdrop = async (wallet: Keypair) => {
+    const signature = await provider.connection.requestAirdrop(
+      wallet.publicKey,
+      LAMPORTS_PER_SOL,
+    );
+    await provider.connection.confirmTransaction(signature);
+  };
+
+  beforeEach(async () => {
+    users = [];
+    for (let i = 0; i < MAX_ENTRIES / MAX_ENTRIES_PER_USER + 1; i++) {
+      const user = Keypair.generate();
+      await airdrop(user);
+      users.push(user);
+    }
+  });
+
+  describe("❌ VULNERABLE: Unbounded append", () => {
+    it("Grows the list by one entry per append", async () => {
+      const list = Keypair.generate();
+      await program.methods
+        .initializeUnboundedList()
+        .accounts({
+          list: list.publicKey,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([list])
+        .rpc();
+
+      for (let i = 0; i < 3; i++) {
+        await program.methods
+          .appendInsecure(new anchor.BN(nextValue++))
+          .accounts({
+            user: users[0].publicKey,
+            list: list.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([users[0]])
+          .rpc();
+      }
+
+      const info = await provider.connection.getAccountInfo(list.publicKey);
+      expect(info!.data.length).to.equal(8 + 4 + 3 * ENTRY_SIZE);
+    });
+
+    it("Bricks the list once an attacker has grown it", async () => {
+      // Thousands of appends can't run in a test, so the attacker-grown
+      // list is staged directly: 2,000 entries is ~80 KB to deserialize
+      // on a 32 KB heap
+      const attacker = Keypair.generate();
+      const victim = Keypair.generate();
+      const list = Keypair.generate();
+      const data = await program.coder.accounts.encode("entryList", {
+        entries: Array.from({ length: 2_000 }, (_, i) => ({
+          author: attacker.publicKey,
+          value: new anchor.BN(i),
+        })),
+      });
+
+      const { program: bankrunProgram } = await setupProgram(program, [
+        systemAccount(victim.publicKey),
+        ownedAccount(
+          list.publicKey,
+          program.programId,
+          data,
+          10 * LAMPORTS_PER_SOL,
+        ),
+      ]);
+
+      try {
+        await bankrunProgram.methods
+          .appendInsecure(new anchor.BN(1))
+          .accounts({
+            user: victim.publicKey,
+            list: list.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([victim])
+          .rpc();
+
+        throw new Error("Expected the oversized list to be unusable");
+      } catch (error: any) {
+        // Out of heap or out of compute - whichever limit is hit first
+        expect(error.message).to.match(
+          /failed to complete|out of memory|budget exceeded/i,
+        );
+        console.log("    🚨 EXPLOIT SUCCESSFUL: list can no longer be loaded");
+      }
+    });
+  });
+
+  describe("✅ SECURE: MAX_ENTRIES and per-user limits", () => {
+    let list: PublicKey;
+
+    const appendSecure = (user: Keypair) =>
+      program.methods
+        .appendSecure(new anchor.BN(nextValue++))
+        .accounts({ user: user.publicKey, list })
+        .signers([user])
+        .rpc();
+
+    beforeEach(async () => {
+      const listKeypair = Keypair.generate();
+      await program.methods
+        .initializeList()
+        .accounts({
+          list: listKeypair.publicKey,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([listKeypair])
+        .rpc();
+      list = listKeypair.publicKey;
+    });
+
+    it("Caps a single user's share of the list", async () => {
+      for (let i = 0; i < MAX_ENTRIES_PER_USER; i++) {
+        await appendSecure(users[0]);
+      }
+
+      try {
+        await appendSecure(users[0]);
+        throw new Error("Expected per-user limit to trip");
+      } catch (error: any) {
+        expect(error.message).to.include("UserLimitReached");
+      }
+    });
+
+    it("Fills to MAX_ENTRIES, then returns ListFull", async () => {
+      const fillers = users.slice(0, MAX_ENTRIES / MAX_ENTRIES_PER_USER);
+      for (const user of fillers) {
+        for (let i = 0; i < MAX_ENTRIES_PER_USER; i++) {
+          await appendSecure(user);
+        }
+      }
+
+      const latecomer = users[users.length - 1];
+      try {
+        await appendSecure(latecomer);
+        throw new Error("Expected a full list to reject the append");
+      } catch (error: any) {
+        expect(error.message).to.include("ListFull");
+        console.log("    ✅ Full list rejected the append cleanly");
+      }
+
+      // Still loadable and usable: free a slot and append again
+      await program.methods
+        .removeEntry(0)
+        .accounts({ user: fillers[0].publicKey, list })
+        .signers([fillers[0]])
+        .rpc();
+      await appendSecure(latecomer);
+
+      const account = await program.account.entryList.fetch(list);
+      expect(account.entries.length).to.equal(MAX_ENTRIES);
+    });
+  });
+});
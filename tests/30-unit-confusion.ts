@@ -0,0 +1,66 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { UnitConfusion } from "../target/types/unit_confusion";
+import { expect } from "chai";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+
+describe("30-unit-confusion", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.UnitConfusion as Program<UnitConfusion>;
+
+  // 1% of a 100 SOL stake = 1 SOL
+  const STAKE_SOL = 100;
+  const RATE_BPS = 100;
+  const EXPECTED_LAMPORTS = 1 * LAMPORTS_PER_SOL;
+
+  const quoteInsecure = async (stakeSol: anchor.BN, rateBps: number) =>
+    (
+      await program.methods.quotePayoutInsecure(stakeSol, rateBps).view()
+    ).toString();
+
+  const quoteSecure = async (stakeSol: anchor.BN, rateBps: number) =>
+    (
+      await program.methods.quotePayoutSecure(stakeSol, rateBps).view()
+    ).toString();
+
+  describe("❌ VULNERABLE: SOL treated as lamports", () => {
+    it("Quotes a payout 1e9x too small", async () => {
+      const payout = Number(
+        await quoteInsecure(new anchor.BN(STAKE_SOL), RATE_BPS),
+      );
+
+      expect(payout).to.equal(EXPECTED_LAMPORTS / LAMPORTS_PER_SOL);
+      expect(EXPECTED_LAMPORTS / payout).to.equal(1_000_000_000);
+      console.log(
+        `    🚨 Paid ${payout} lamport instead of ${EXPECTED_LAMPORTS}`,
+      );
+    });
+  });
+
+  describe("✅ SECURE: Explicit LAMPORTS_PER_SOL conversion", () => {
+    it("Quotes the correct payout in lamports", async () => {
+      const payout = Number(
+        await quoteSecure(new anchor.BN(STAKE_SOL), RATE_BPS),
+      );
+
+      expect(payout).to.equal(EXPECTED_LAMPORTS);
+      console.log(`    ✅ ${payout} lamports = 1 SOL`);
+    });
+
+    it("Rejects a SOL amount that overflows in lamports", async () => {
+      // Largest SOL amount that still fits in u64 lamports, plus one
+      const tooMuchSol = new anchor.BN("18446744073709551615")
+        .div(new anchor.BN(LAMPORTS_PER_SOL))
+        .addn(1);
+
+      try {
+        await quoteSecure(tooMuchSol, RATE_BPS);
+        throw new Error("Expected the conversion to overflow");
+      } catch (error: any) {
+        expect(error.message).to.include("MathOverflow");
+      }
+    });
+  });
+});
@@ -0,0 +1,349 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Idl, Program } from "@coral-xyz/anchor";
+import { MissingSignerCheck } from "../target/types/missing_signer_check";
+import { MissingOwnerCheck } from "../target/types/missing_owner_check";
+import { AccountDataMatching } from "../target/types/account_data_matching";
+import { TypeCosplay } from "../target/types/type_cosplay";
+import { expect } from "chai";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { ACCOUNT_SIZE, AccountLayout, AccountState } from "@solana/spl-token";
+import { ownedAccount, systemAccount } from "./helpers/harness";
+import {
+  AttackFixture,
+  hasAttackFixture,
+  loadAttackFixture,
+  recordAccount,
+  recordKeypairs,
+  recordKeys,
+  recordTransaction,
+  replayAttack,
+  saveAttackFixture,
+} from "./helpers/replay";
+
+// Each lesson's exploit is replayed from tests/fixtures/attacks/. A missing
+// fixture is recorded on the spot; RECORD_ATTACKS=1 re-records all of them,
+// e.g. after a lesson's accounts or arguments change.
+describe("🎬 Attack replay", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const signerCheck = anchor.workspace
+    .MissingSignerCheck as Program<MissingSignerCheck>;
+  const ownerCheck = anchor.workspace
+    .MissingOwnerCheck as Program<MissingOwnerCheck>;
+  const dataMatching = anchor.workspace
+    .AccountDataMatching as Program<AccountDataMatching>;
+  const typeCosplay = anchor.workspace.TypeCosplay as Program<TypeCosplay>;
+
+  // 01: anyone can withdraw by naming the authority without its signature
+  const recordMissingSignerCheck = async (): Promise<AttackFixture> => {
+    const authority = Keypair.generate();
+    const attacker = Keypair.generate();
+    const [vault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), authority.publicKey.toBuffer()],
+      signerCheck.programId,
+    );
+
+    const initialize = await signerCheck.methods
+      .initializeVaultSecure(new anchor.BN(1_000_000))
+      .accounts({
+        initializer: authority.publicKey,
+        vault,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+    const fund = SystemProgram.transfer({
+      fromPubkey: authority.publicKey,
+      toPubkey: vault,
+      lamports: LAMPORTS_PER_SOL,
+    });
+    // 🚨 authority is named, but only the attacker signs
+    const exploit = await signerCheck.methods
+      .withdrawInsecure(new anchor.BN(0.5 * LAMPORTS_PER_SOL))
+      .accounts({ vault, authority: authority.publicKey })
+      .instruction();
+
+    return {
+      lesson: "01-missing-signer-check",
+      description:
+        "Attacker withdraws half the vault to its authority without the authority's signature",
+      keypairs: recordKeypairs({ authority, attacker }),
+      addresses: { vault: vault.toBase58() },
+      accounts: [
+        systemAccount(authority.publicKey, 2 * LAMPORTS_PER_SOL),
+        systemAccount(attacker.publicKey),
+      ].map(recordAccount),
+      setup: [recordTransaction(["authority"], [initialize, fund])],
+      exploit: recordTransaction(["attacker"], [exploit]),
+      vulnerable: "withdrawInsecure",
+      secure: { instruction: "withdrawSecure" },
+      outcome: {
+        lamports: {
+          vault: -0.5 * LAMPORTS_PER_SOL,
+          authority: 0.5 * LAMPORTS_PER_SOL,
+        },
+        logs: ["Withdrawn 500000000 lamports from vault (INSECURE)"],
+      },
+      rejection: "AccountNotSigner",
+    };
+  };
+
+  // 02: a token account forged by another program is trusted for its balance
+  const recordMissingOwnerCheck = async (): Promise<AttackFixture> => {
+    const attacker = Keypair.generate();
+    const attackerProgram = Keypair.generate().publicKey;
+    const fakeTokenAccount = Keypair.generate().publicKey;
+    const vault = Keypair.generate().publicKey;
+    const mint = Keypair.generate().publicKey;
+
+    const fakeData = Buffer.alloc(ACCOUNT_SIZE);
+    AccountLayout.encode(
+      {
+        mint,
+        owner: attacker.publicKey,
+        amount: BigInt("18446744073709551615"),
+        delegateOption: 0,
+        delegate: PublicKey.default,
+        state: AccountState.Initialized,
+        isNativeOption: 0,
+        isNative: BigInt(0),
+        delegatedAmount: BigInt(0),
+        closeAuthorityOption: 0,
+        closeAuthority: PublicKey.default,
+      },
+      fakeData,
+    );
+    const vaultData = await ownerCheck.coder.accounts.encode("paymentVault", {
+      authority: attacker.publicKey,
+      totalCollected: new anchor.BN(0),
+      expectedMint: mint,
+    });
+
+    const exploit = await ownerCheck.methods
+      .processPaymentInsecure(new anchor.BN(1_000_000_000))
+      .accounts({
+        user: attacker.publicKey,
+        userTokenAccount: fakeTokenAccount,
+        vault,
+      })
+      .instruction();
+
+    return {
+      lesson: "02-missing-owner-check",
+      description:
+        "Attacker pays with a token account its own program forged to hold u64::MAX",
+      keypairs: recordKeypairs({ attacker }),
+      addresses: {
+        attackerProgram: attackerProgram.toBase58(),
+        fakeTokenAccount: fakeTokenAccount.toBase58(),
+        vault: vault.toBase58(),
+        mint: mint.toBase58(),
+      },
+      accounts: [
+        systemAccount(attacker.publicKey, 2 * LAMPORTS_PER_SOL),
+        ownedAccount(fakeTokenAccount, attackerProgram, fakeData),
+        ownedAccount(vault, ownerCheck.programId, vaultData),
+      ].map(recordAccount),
+      setup: [],
+      exploit: recordTransaction(["attacker"], [exploit]),
+      vulnerable: "processPaymentInsecure",
+      secure: { instruction: "processPaymentManual" },
+      outcome: {
+        logs: ["Processing payment of 1000000000 tokens (INSECURE)"],
+      },
+      rejection: "InvalidOwner",
+    };
+  };
+
+  // 04: stats that aren't derived from the signer let anyone spend them
+  const recordAccountDataMatching = async (): Promise<AttackFixture> => {
+    const victim = Keypair.generate();
+    const attacker = Keypair.generate();
+    const pda = (...seeds: Buffer[]) =>
+      PublicKey.findProgramAddressSync(seeds, dataMatching.programId)[0];
+    const vault = pda(Buffer.from("vault"));
+    const programStats = pda(Buffer.from("program_stats"));
+    const victimStats = pda(
+      Buffer.from("user_stats"),
+      victim.publicKey.toBuffer(),
+    );
+
+    const setup = [
+      await dataMatching.methods
+        .initializeVault()
+        .accounts({
+          payer: victim.publicKey,
+          vault,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction(),
+      await dataMatching.methods
+        .initializeProgramStats()
+        .accounts({ payer: victim.publicKey })
+        .instruction(),
+      await dataMatching.methods
+        .initializeUserStats()
+        .accounts({
+          user: victim.publicKey,
+          userStats: victimStats,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction(),
+      await dataMatching.methods
+        .deposit(new anchor.BN(0.5 * LAMPORTS_PER_SOL))
+        .accounts({
+          user: victim.publicKey,
+          userStats: victimStats,
+          vault,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction(),
+    ];
+
+    // 🚨 the attacker signs, but spends the victim's recorded balance
+    const amount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
+    const withdraw = {
+      user: attacker.publicKey,
+      userStats: victimStats,
+      vault,
+    };
+    const exploit = await dataMatching.methods
+      .withdrawInsecure(amount)
+      .accounts(withdraw)
+      .instruction();
+    // withdraw_secure also takes the program stats
+    const secure = await dataMatching.methods
+      .withdrawSecure(amount)
+      .accounts(withdraw)
+      .instruction();
+
+    return {
+      lesson: "04-account-data-matching",
+      description:
+        "Attacker withdraws the victim's deposit by passing the victim's user_stats",
+      keypairs: recordKeypairs({ victim, attacker }),
+      addresses: {
+        vault: vault.toBase58(),
+        programStats: programStats.toBase58(),
+        victimStats: victimStats.toBase58(),
+      },
+      accounts: [
+        systemAccount(victim.publicKey, 2 * LAMPORTS_PER_SOL),
+        systemAccount(attacker.publicKey),
+      ].map(recordAccount),
+      setup: [recordTransaction(["victim"], setup)],
+      exploit: recordTransaction(["attacker"], [exploit]),
+      vulnerable: "withdrawInsecure",
+      secure: { instruction: "withdrawSecure", keys: recordKeys(secure.keys) },
+      outcome: {
+        lamports: { vault: -0.5 * LAMPORTS_PER_SOL },
+      },
+      rejection: "ConstraintSeeds",
+    };
+  };
+
+  // 07: a UserProfile passes for a Config, so its owner reads as the admin
+  const recordTypeCosplay = async (): Promise<AttackFixture> => {
+    const attacker = Keypair.generate();
+    const profile = Keypair.generate();
+
+    const initialize = await typeCosplay.methods
+      .initializeUserProfile()
+      .accounts({
+        profile: profile.publicKey,
+        owner: attacker.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+    const exploit = await typeCosplay.methods
+      .processVulnerable()
+      .accounts({ configAccount: profile.publicKey })
+      .instruction();
+
+    return {
+      lesson: "07-type-cosplay",
+      description:
+        "Attacker passes its own UserProfile where a Config is expected and is read as admin",
+      keypairs: recordKeypairs({ attacker, profile }),
+      addresses: {},
+      accounts: [systemAccount(attacker.publicKey)].map(recordAccount),
+      setup: [recordTransaction(["attacker", "profile"], [initialize])],
+      exploit: recordTransaction(["attacker"], [exploit]),
+      vulnerable: "processVulnerable",
+      secure: { instruction: "processSecure" },
+      outcome: {
+        logs: [`Processing with admin: ${attacker.publicKey.toBase58()}`],
+      },
+      rejection: "AccountDiscriminatorMismatch",
+    };
+  };
+
+  const lessons: {
+    lesson: string;
+    idl: Idl;
+    record: () => Promise<AttackFixture>;
+  }[] = [
+    {
+      lesson: "01-missing-signer-check",
+      idl: signerCheck.idl as Idl,
+      record: recordMissingSignerCheck,
+    },
+    {
+      lesson: "02-missing-owner-check",
+      idl: ownerCheck.idl as Idl,
+      record: recordMissingOwnerCheck,
+    },
+    {
+      lesson: "04-account-data-matching",
+      idl: dataMatching.idl as Idl,
+      record: recordAccountDataMatching,
+    },
+    {
+      lesson: "07-type-cosplay",
+      idl: typeCosplay.idl as Idl,
+      record: recordTypeCosplay,
+    },
+  ];
+
+  for (const { lesson, idl, record } of lessons) {
+    describe(lesson, () => {
+      let fixture: AttackFixture;
+
+      before(async () => {
+        if (process.env.RECORD_ATTACKS || !hasAttackFixture(lesson)) {
+          saveAttackFixture(await record());
+        }
+        fixture = loadAttackFixture(lesson);
+      });
+
+      it("Replays the exploit with its documented outcome", async () => {
+        const replay = await replayAttack(idl, fixture, "vulnerable");
+
+        // ❌ VULNERABLE: the recorded transaction still goes through
+        expect(replay.error).to.be.null;
+        expect(replay.lamports).to.deep.equal(fixture.outcome.lamports ?? {});
+        for (const log of fixture.outcome.logs ?? []) {
+          expect(replay.logs.join("\n")).to.include(log);
+        }
+        console.log(`    🚨 ${fixture.description}`);
+      });
+
+      it("Secure instruction rejects the same transaction", async () => {
+        const replay = await replayAttack(idl, fixture, "secure");
+
+        // ✅ SECURE: same accounts, signers and arguments - and nothing moves
+        expect(replay.error).to.not.be.null;
+        expect(replay.logs.join("\n")).to.include(fixture.rejection);
+        for (const delta of Object.values(replay.lamports)) {
+          expect(delta).to.equal(0);
+        }
+        console.log(`    ✅ Rejected: ${fixture.rejection}`);
+      });
+    });
+  }
+});
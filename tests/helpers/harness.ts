@@ -0,0 +1,138 @@
+// Shared bankrun harness for the lesson tests
+//
+// Lessons that need state the program could never create on its own
+// (forged accounts, accounts at arbitrary addresses, a warped clock) boot an
+// in-process bank with every workspace program deployed. `setupProgram`
+// wraps that boilerplate so each test only describes the accounts it injects.
+import { Idl, Program } from "@coral-xyz/anchor";
+import { BankrunProvider } from "anchor-bankrun";
+import {
+  AddedAccount,
+  BanksClient,
+  ProgramTestContext,
+  startAnchor,
+} from "solana-bankrun";
+import {
+  ComputeBudgetProgram,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SendOptions,
+  Signer,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+  VersionedTransaction,
+} from "@solana/web3.js";
+
+// Without a compute-budget instruction the runtime allows 200k CU per
+// instruction; a transaction can ask for at most 1.4M
+export const DEFAULT_COMPUTE_UNIT_LIMIT = 200_000;
+export const MAX_COMPUTE_UNIT_LIMIT = 1_400_000;
+
+export type Harness<T extends Idl> = {
+  context: ProgramTestContext;
+  banksClient: BanksClient;
+  payer: Keypair;
+  blockhash: string;
+  // Same program as the workspace one, but sending through the bank
+  program: Program<T>;
+};
+
+export type HarnessOptions = {
+  // Compute-unit limit prepended to every transaction the harness program
+  // sends. Omit it to run under the runtime default.
+  computeUnitLimit?: number;
+};
+
+/** Instruction that sets its transaction's compute-unit limit */
+export const computeUnitLimit = (units: number): TransactionInstruction =>
+  ComputeBudgetProgram.setComputeUnitLimit({ units });
+
+const setsComputeUnitLimit = (tx: Transaction) =>
+  tx.instructions.some((ix) =>
+    ix.programId.equals(ComputeBudgetProgram.programId),
+  );
+
+// Prepends the harness-wide limit unless the transaction already sets one,
+// so a single call can still override it
+class BudgetedProvider extends BankrunProvider {
+  constructor(
+    context: ProgramTestContext,
+    private readonly units: number,
+  ) {
+    super(context);
+  }
+
+  async sendAndConfirm(
+    tx: Transaction | VersionedTransaction,
+    signers?: Signer[],
+    opts?: SendOptions,
+  ): Promise<string> {
+    if (tx instanceof Transaction && !setsComputeUnitLimit(tx)) {
+      tx.instructions.unshift(computeUnitLimit(this.units));
+    }
+    return super.sendAndConfirm(tx, signers, opts);
+  }
+}
+
+/**
+ * Deploy the workspace programs into a fresh bank seeded with `accounts`
+ * and return a client for `program` bound to it.
+ */
+export const setupProgram = async <T extends Idl>(
+  program: Program<T>,
+  accounts: AddedAccount[] = [],
+  options: HarnessOptions = {},
+): Promise<Harness<T>> => {
+  const context = await startAnchor("", [], accounts);
+  const provider =
+    options.computeUnitLimit === undefined
+      ? new BankrunProvider(context)
+      : new BudgetedProvider(context, options.computeUnitLimit);
+
+  return {
+    context,
+    banksClient: context.banksClient,
+    payer: context.payer,
+    blockhash: context.lastBlockhash,
+    program: new Program<T>(program.idl, provider),
+  };
+};
+
+/**
+ * Raise (or lower) the compute-unit limit for one call only:
+ *
+ *   await withComputeUnitLimit(program.methods.heavy(), 600_000).rpc();
+ *
+ * Takes precedence over the harness-wide `computeUnitLimit`.
+ */
+export const withComputeUnitLimit = <
+  B extends { preInstructions(ixs: TransactionInstruction[]): B },
+>(
+  builder: B,
+  units: number,
+): B => builder.preInstructions([computeUnitLimit(units)]);
+
+/** A plain wallet holding `lamports` */
+export const systemAccount = (
+  address: PublicKey,
+  lamports: number = LAMPORTS_PER_SOL,
+): AddedAccount =>
+  ownedAccount(address, SystemProgram.programId, Buffer.alloc(0), lamports);
+
+/** Arbitrary bytes owned by `owner` - how forged accounts get injected */
+export const ownedAccount = (
+  address: PublicKey,
+  owner: PublicKey,
+  data: Buffer,
+  lamports: number = LAMPORTS_PER_SOL,
+): AddedAccount => ({
+  address,
+  info: {
+    lamports,
+    data,
+    owner,
+    executable: false,
+  },
+});
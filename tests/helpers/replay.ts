@@ -0,0 +1,326 @@
+// Attack replay: exploit transactions recorded as fixtures
+//
+// Each fixture in tests/fixtures/attacks/ is one lesson's exploit, stored as
+// the exact bytes that went on the wire: the keypairs that sign, the
+// accounts the bank starts with, the setup transactions, and the exploit
+// itself. Replaying it in a fresh bank must reproduce the documented bad
+// outcome. Replaying it with the secure instruction swapped in - same
+// accounts, same signers, same arguments - must fail.
+import * as fs from "fs";
+import * as path from "path";
+import { Idl } from "@coral-xyz/anchor";
+import { AddedAccount, BanksClient, startAnchor } from "solana-bankrun";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+
+export const ATTACK_FIXTURES = path.join(
+  __dirname,
+  "..",
+  "fixtures",
+  "attacks",
+);
+
+export type RecordedMeta = {
+  pubkey: string;
+  isSigner: boolean;
+  isWritable: boolean;
+};
+
+export type RecordedInstruction = {
+  programId: string;
+  keys: RecordedMeta[];
+  // base64
+  data: string;
+};
+
+export type RecordedTransaction = {
+  // Names from `keypairs`; the first one pays the fee
+  signers: string[];
+  instructions: RecordedInstruction[];
+};
+
+export type RecordedAccount = {
+  address: string;
+  owner: string;
+  lamports: number;
+  // base64
+  data: string;
+};
+
+export type AttackFixture = {
+  lesson: string;
+  description: string;
+  // Secret keys of everyone who signs, by name
+  keypairs: Record<string, number[]>;
+  // Other accounts worth naming in `outcome`
+  addresses: Record<string, string>;
+  // State the bank starts from: funded wallets and any forged accounts
+  accounts: RecordedAccount[];
+  setup: RecordedTransaction[];
+  exploit: RecordedTransaction;
+  // IDL name of the instruction the exploit calls
+  vulnerable: string;
+  secure: {
+    // IDL name of the instruction swapped in for `vulnerable`
+    instruction: string;
+    // Account list for the secure instruction, when it takes more accounts
+    // than the vulnerable one. Omitted means the exploit's own accounts.
+    keys?: RecordedMeta[];
+  };
+  // The documented bad outcome of the exploit
+  outcome: {
+    // Lamport change per named account
+    lamports?: Record<string, number>;
+    // Text that must appear in the program logs
+    logs?: string[];
+  };
+  // Text the secure replay's logs must contain
+  rejection: string;
+};
+
+export type Replay = {
+  // Transaction error, or null if the exploit went through
+  error: string | null;
+  logs: string[];
+  // Lamport change for every account named in `outcome.lamports`
+  lamports: Record<string, number>;
+};
+
+export const recordInstruction = (
+  ix: TransactionInstruction,
+): RecordedInstruction => ({
+  programId: ix.programId.toBase58(),
+  keys: recordKeys(ix.keys),
+  data: ix.data.toString("base64"),
+});
+
+export const recordKeys = (
+  keys: TransactionInstruction["keys"],
+): RecordedMeta[] =>
+  keys.map(({ pubkey, isSigner, isWritable }) => ({
+    pubkey: pubkey.toBase58(),
+    isSigner,
+    isWritable,
+  }));
+
+export const recordTransaction = (
+  signers: string[],
+  instructions: TransactionInstruction[],
+): RecordedTransaction => ({
+  signers,
+  instructions: instructions.map(recordInstruction),
+});
+
+export const recordAccount = ({
+  address,
+  info,
+}: AddedAccount): RecordedAccount => ({
+  address: address.toBase58(),
+  owner: info.owner.toBase58(),
+  lamports: info.lamports,
+  data: Buffer.from(info.data).toString("base64"),
+});
+
+export const recordKeypairs = (
+  keypairs: Record<string, Keypair>,
+): Record<string, number[]> =>
+  Object.fromEntries(
+    Object.entries(keypairs).map(([name, keypair]) => [
+      name,
+      Array.from(keypair.secretKey),
+    ]),
+  );
+
+const fixturePath = (lesson: string) =>
+  path.join(ATTACK_FIXTURES, `${lesson}.json`);
+
+export const hasAttackFixture = (lesson: string) =>
+  fs.existsSync(fixturePath(lesson));
+
+export const saveAttackFixture = (fixture: AttackFixture) => {
+  fs.mkdirSync(ATTACK_FIXTURES, { recursive: true });
+  fs.writeFileSync(
+    fixturePath(fixture.lesson),
+    JSON.stringify(fixture, null, 2) + "\n",
+  );
+};
+
+export const loadAttackFixture = (lesson: string): AttackFixture =>
+  JSON.parse(fs.readFileSync(fixturePath(lesson), "utf8"));
+
+const keypairsOf = (fixture: AttackFixture): Record<string, Keypair> =>
+  Object.fromEntries(
+    Object.entries(fixture.keypairs).map(([name, secretKey]) => [
+      name,
+      Keypair.fromSecretKey(Uint8Array.from(secretKey)),
+    ]),
+  );
+
+const addressOf = (
+  fixture: AttackFixture,
+  keypairs: Record<string, Keypair>,
+  name: string,
+): PublicKey => {
+  if (keypairs[name]) {
+    return keypairs[name].publicKey;
+  }
+  if (fixture.addresses[name]) {
+    return new PublicKey(fixture.addresses[name]);
+  }
+  throw new Error(`${fixture.lesson}: no keypair or address named ${name}`);
+};
+
+const discriminatorOf = (idl: Idl, name: string): Buffer => {
+  const ix = idl.instructions.find((ix) => ix.name === name);
+  if (!ix) {
+    throw new Error(`${idl.metadata.name} has no instruction ${name}`);
+  }
+  return Buffer.from(ix.discriminator);
+};
+
+// The exploit with the vulnerable instruction replaced by the secure one.
+// Everything else - signers, other instructions, arguments - is untouched.
+const retarget = (idl: Idl, fixture: AttackFixture): RecordedTransaction => {
+  const vulnerable = discriminatorOf(idl, fixture.vulnerable);
+  const secure = discriminatorOf(idl, fixture.secure.instruction);
+
+  return {
+    ...fixture.exploit,
+    instructions: fixture.exploit.instructions.map((ix) => {
+      const data = Buffer.from(ix.data, "base64");
+      if (
+        ix.programId !== idl.address ||
+        !data.subarray(0, 8).equals(vulnerable)
+      ) {
+        return ix;
+      }
+      return {
+        ...ix,
+        keys: fixture.secure.keys ?? ix.keys,
+        data: Buffer.concat([secure, data.subarray(8)]).toString("base64"),
+      };
+    }),
+  };
+};
+
+// A fixture recorded against an older build would replay something other
+// than the documented attack - refuse it instead
+const assertCallsVulnerable = (idl: Idl, fixture: AttackFixture) => {
+  const vulnerable = discriminatorOf(idl, fixture.vulnerable);
+  const calls = fixture.exploit.instructions.some(
+    (ix) =>
+      ix.programId === idl.address &&
+      Buffer.from(ix.data, "base64").subarray(0, 8).equals(vulnerable),
+  );
+  if (!calls) {
+    throw new Error(
+      `${fixture.lesson}: exploit no longer calls ${fixture.vulnerable} - ` +
+        "re-record it with RECORD_ATTACKS=1",
+    );
+  }
+};
+
+const toTransaction = (
+  recorded: RecordedTransaction,
+  keypairs: Record<string, Keypair>,
+  blockhash: string,
+): Transaction => {
+  const signers = recorded.signers.map((name) => keypairs[name]);
+  const tx = new Transaction({
+    feePayer: signers[0].publicKey,
+    recentBlockhash: blockhash,
+  });
+  for (const ix of recorded.instructions) {
+    tx.add(
+      new TransactionInstruction({
+        programId: new PublicKey(ix.programId),
+        keys: ix.keys.map(({ pubkey, isSigner, isWritable }) => ({
+          pubkey: new PublicKey(pubkey),
+          isSigner,
+          isWritable,
+        })),
+        data: Buffer.from(ix.data, "base64"),
+      }),
+    );
+  }
+  tx.sign(...signers);
+  return tx;
+};
+
+const balances = async (
+  client: BanksClient,
+  accounts: Record<string, PublicKey>,
+): Promise<Record<string, number>> => {
+  const result: Record<string, number> = {};
+  for (const [name, address] of Object.entries(accounts)) {
+    result[name] = Number(await client.getBalance(address));
+  }
+  return result;
+};
+
+/**
+ * Replay `fixture` in a fresh bank, against either the vulnerable
+ * instruction it was recorded with or the secure one that replaces it.
+ */
+export const replayAttack = async (
+  idl: Idl,
+  fixture: AttackFixture,
+  against: "vulnerable" | "secure",
+): Promise<Replay> => {
+  assertCallsVulnerable(idl, fixture);
+
+  const keypairs = keypairsOf(fixture);
+  const context = await startAnchor(
+    "",
+    [],
+    fixture.accounts.map((account) => ({
+      address: new PublicKey(account.address),
+      info: {
+        lamports: account.lamports,
+        data: Buffer.from(account.data, "base64"),
+        owner: new PublicKey(account.owner),
+        executable: false,
+      },
+    })),
+  );
+  const client = context.banksClient;
+
+  for (const [i, setup] of fixture.setup.entries()) {
+    const result = await client.tryProcessTransaction(
+      toTransaction(setup, keypairs, context.lastBlockhash),
+    );
+    if (result.result) {
+      const logs = result.meta?.logMessages.join("\n") ?? "";
+      throw new Error(
+        `${fixture.lesson}: setup transaction ${i} failed: ${result.result}\n${logs}`,
+      );
+    }
+  }
+
+  const watched = Object.fromEntries(
+    Object.keys(fixture.outcome.lamports ?? {}).map((name) => [
+      name,
+      addressOf(fixture, keypairs, name),
+    ]),
+  );
+  const exploit =
+    against === "vulnerable" ? fixture.exploit : retarget(idl, fixture);
+
+  const before = await balances(client, watched);
+  const result = await client.tryProcessTransaction(
+    toTransaction(exploit, keypairs, context.lastBlockhash),
+  );
+  const after = await balances(client, watched);
+
+  return {
+    error: result.result,
+    logs: result.meta?.logMessages ?? [],
+    lamports: Object.fromEntries(
+      Object.keys(watched).map((name) => [name, after[name] - before[name]]),
+    ),
+  };
+};
@@ -0,0 +1,340 @@
+//! solana-program-test harness shared by every lesson's Rust tests
+//!
+//! Each test deploys the lesson's compiled program (`anchor build` output in
+//! `target/deploy/`) into a fresh in-process bank, runs the exploit against
+//! the vulnerable instruction and the same attack against the secure one.
+//! `cargo test -p lesson-tests` runs the whole vulnerable-vs-secure matrix.
+//!
+//! The helpers mirror `tests/helpers/harness.ts`: `setup_program` boots the
+//! bank, `system_account` / `owned_account` describe accounts to inject
+//! before it starts, and `send` signs as the payer plus whoever else is
+//! named - never as anyone the attacker doesn't hold a key for.
+use std::sync::Once;
+
+use anchor_lang::{AccountDeserialize, AccountSerialize, InstructionData, Owner, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use anchor_spl::token::spl_token::solana_program::program_pack::Pack;
+use solana_program_test::{BanksClient, BanksClientError, ProgramTest};
+use solana_sdk::account::Account;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::program_option::COption;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use solana_system_interface::instruction as system_instruction;
+
+/// Every deployable program in the workspace, by the `.so` name
+/// `anchor build` gives it
+pub const PROGRAMS: &[(&str, Pubkey)] = &[
+    ("missing_signer_check", missing_signer_check::ID),
+    ("missing_owner_check", missing_owner_check::ID),
+    ("arithmetic_overflow", arithmetic_overflow::ID),
+    ("account_data_matching", account_data_matching::ID),
+    ("arbitrary_cpi", arbitrary_cpi::ID),
+    ("rogue_caller", rogue_caller::ID),
+    ("reentrance_attack", reentrance_attack::ID),
+    ("reentrance_callback", reentrance_callback::ID),
+    ("type_cosplay", type_cosplay::ID),
+    ("closing_accounts", closing_accounts::ID),
+    ("duplicate_mutable_accounts", duplicate_mutable_accounts::ID),
+    ("bump_canonicalization", bump_canonicalization::ID),
+    ("pda_seed_collision", pda_seed_collision::ID),
+    ("sysvar_spoofing", sysvar_spoofing::ID),
+    ("reload_after_cpi", reload_after_cpi::ID),
+    ("rent_exemption", rent_exemption::ID),
+    ("integer_truncation", integer_truncation::ID),
+    ("precision_loss", precision_loss::ID),
+    ("init_if_needed", init_if_needed::ID),
+    ("remaining_accounts", remaining_accounts::ID),
+    ("oracle_manipulation", oracle_manipulation::ID),
+    ("flash_loan", flash_loan::ID),
+    ("signature_verification", signature_verification::ID),
+    ("freeze_authority", freeze_authority::ID),
+    ("delegate_misuse", delegate_misuse::ID),
+    ("close_authority", close_authority::ID),
+    ("slippage", slippage::ID),
+    ("nested_has_one", nested_has_one::ID),
+    ("realloc", realloc::ID),
+    ("vesting", vesting::ID),
+    ("dos_unbounded", dos_unbounded::ID),
+    ("unit_confusion", unit_confusion::ID),
+];
+
+static DEPLOY_DIR: Once = Once::new();
+
+/// Point solana-program-test at `target/deploy/` unless the caller already
+/// chose a directory
+fn use_deploy_dir() {
+    DEPLOY_DIR.call_once(|| {
+        if std::env::var_os("SBF_OUT_DIR").is_none() {
+            std::env::set_var(
+                "SBF_OUT_DIR",
+                concat!(env!("CARGO_MANIFEST_DIR"), "/../../target/deploy"),
+            );
+        }
+    });
+}
+
+fn program_name(program_id: &Pubkey) -> &'static str {
+    PROGRAMS
+        .iter()
+        .find(|(_, id)| id == program_id)
+        .map(|(name, _)| *name)
+        .unwrap_or_else(|| panic!("{program_id} is not a workspace program"))
+}
+
+/// A bank with `program_ids` deployed and `accounts` injected, not yet
+/// started - for tests that need `start_with_context` to move the clock
+pub fn program_test(program_ids: &[Pubkey], accounts: Vec<(Pubkey, Account)>) -> ProgramTest {
+    use_deploy_dir();
+
+    let mut test = ProgramTest::default();
+    // Run the real SBF build, not a native re-compile of the crate
+    test.prefer_bpf(true);
+    for program_id in program_ids {
+        test.add_program(program_name(program_id), *program_id, None);
+    }
+    for (address, account) in accounts {
+        test.add_account(address, account);
+    }
+    test
+}
+
+/// Deploy one lesson program into a fresh bank
+pub async fn setup_program(program_id: Pubkey) -> (BanksClient, Keypair, Hash) {
+    setup_programs(&[program_id], vec![]).await
+}
+
+/// Deploy `program_ids` into a fresh bank seeded with `accounts`
+///
+/// Lessons whose attack needs a second program (05's rogue caller, 06's
+/// callback) or state the program could never create on its own (forged
+/// accounts, accounts at arbitrary addresses) go through here.
+pub async fn setup_programs(
+    program_ids: &[Pubkey],
+    accounts: Vec<(Pubkey, Account)>,
+) -> (BanksClient, Keypair, Hash) {
+    program_test(program_ids, accounts).start().await
+}
+
+/// A plain wallet holding `lamports`
+pub fn system_account(lamports: u64) -> Account {
+    Account {
+        lamports,
+        data: vec![],
+        owner: anchor_lang::system_program::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Arbitrary bytes owned by `owner` - how forged accounts get injected
+pub fn owned_account(owner: Pubkey, data: Vec<u8>) -> Account {
+    Account {
+        lamports: LAMPORTS_PER_SOL,
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// An Anchor account with `value` in it, owned by the program that declares it
+pub fn anchor_account<T: AccountSerialize + Owner>(value: &T) -> Account {
+    owned_account(T::owner(), anchor_data(value))
+}
+
+/// Discriminator + Borsh fields, as the program itself would write them
+pub fn anchor_data<T: AccountSerialize>(value: &T) -> Vec<u8> {
+    let mut data = Vec::new();
+    value.try_serialize(&mut data).expect("account serializes");
+    data
+}
+
+/// An initialized SPL mint
+pub fn mint_account(mint_authority: Pubkey, freeze_authority: Option<Pubkey>) -> Account {
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        mint_authority: COption::Some(mint_authority),
+        supply: 0,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: freeze_authority.into(),
+    }
+    .pack_into_slice(&mut data);
+    owned_account(spl_token::ID, data)
+}
+
+/// An SPL token account, initialized, holding `amount` of `mint`
+pub fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> Account {
+    token_account_with(spl_token::state::Account {
+        mint,
+        owner,
+        amount,
+        state: spl_token::state::AccountState::Initialized,
+        ..Default::default()
+    })
+}
+
+/// An SPL token account with every field spelled out - frozen, delegated,
+/// or with a close authority
+pub fn token_account_with(state: spl_token::state::Account) -> Account {
+    let mut data = vec![0; spl_token::state::Account::LEN];
+    state.pack_into_slice(&mut data);
+    owned_account(spl_token::ID, data)
+}
+
+/// An Anchor instruction: `accounts` in declaration order, `args` after the
+/// discriminator
+pub fn instruction(
+    program_id: Pubkey,
+    accounts: impl ToAccountMetas,
+    args: impl InstructionData,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+/// Clear the signer flag on every meta for `key`
+///
+/// This is the whole missing-signer attack: name the victim's account, but
+/// don't sign for it.
+pub fn without_signer(mut instruction: Instruction, key: &Pubkey) -> Instruction {
+    for meta in instruction.accounts.iter_mut() {
+        if meta.pubkey == *key {
+            meta.is_signer = false;
+        }
+    }
+    instruction
+}
+
+/// A new wallet holding `lamports`, funded by `payer`
+pub async fn funded_keypair(banks: &mut BanksClient, payer: &Keypair, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    let fund = system_instruction::transfer(&payer.pubkey(), &keypair.pubkey(), lamports);
+    send(banks, payer, &[fund], &[])
+        .await
+        .expect("payer can fund a wallet");
+    keypair
+}
+
+/// Send `instructions` paid for by `payer` and signed by `signers` too
+pub async fn send(
+    banks: &mut BanksClient,
+    payer: &Keypair,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let transaction = signed(banks, payer, instructions, signers).await?;
+    banks.process_transaction(transaction).await
+}
+
+/// `send`, keeping the program logs
+///
+/// For vulnerable paths whose only effect is what they report - a forged
+/// admin, a spoofed timestamp - the logs are the outcome.
+pub async fn send_for_logs(
+    banks: &mut BanksClient,
+    payer: &Keypair,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> (Result<(), TransactionError>, Vec<String>) {
+    let transaction = signed(banks, payer, instructions, signers)
+        .await
+        .expect("bank is reachable");
+    let outcome = banks
+        .process_transaction_with_metadata(transaction)
+        .await
+        .expect("bank is reachable");
+    let logs = outcome
+        .metadata
+        .map(|metadata| metadata.log_messages)
+        .unwrap_or_default();
+    (outcome.result, logs)
+}
+
+async fn signed(
+    banks: &mut BanksClient,
+    payer: &Keypair,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<Transaction, BanksClientError> {
+    let blockhash = banks.get_latest_blockhash().await?;
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    Ok(Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        blockhash,
+    ))
+}
+
+/// The custom error code a failed transaction ended with
+///
+/// Compare against `u32::from(ErrorCode::...)` for the lesson's own errors,
+/// Anchor's `ErrorCode` for constraint failures, or `SecurityError`.
+pub fn error_code(result: Result<(), BanksClientError>) -> u32 {
+    match instruction_error(result) {
+        InstructionError::Custom(code) => code,
+        other => panic!("expected a custom program error, got {other:?}"),
+    }
+}
+
+/// The instruction error a failed transaction ended with, for failures the
+/// runtime raises rather than the program
+pub fn instruction_error(result: Result<(), BanksClientError>) -> InstructionError {
+    match transaction_error(result) {
+        TransactionError::InstructionError(_, error) => error,
+        other => panic!("expected an instruction error, got {other:?}"),
+    }
+}
+
+/// The error a failed transaction ended with, for checks the runtime makes
+/// after every instruction has run - rent, balances
+pub fn transaction_error(result: Result<(), BanksClientError>) -> TransactionError {
+    match result {
+        Ok(()) => panic!("expected the transaction to fail"),
+        Err(BanksClientError::TransactionError(error)) => error,
+        Err(BanksClientError::SimulationError { err, .. }) => err,
+        Err(other) => panic!("transaction never reached the program: {other}"),
+    }
+}
+
+/// Fetch and deserialize an Anchor account
+pub async fn fetch<T: AccountDeserialize>(banks: &mut BanksClient, address: Pubkey) -> T {
+    let account = banks
+        .get_account(address)
+        .await
+        .expect("bank is reachable")
+        .unwrap_or_else(|| panic!("{address} does not exist"));
+    T::try_deserialize(&mut account.data.as_slice()).expect("account deserializes")
+}
+
+/// Fetch and unpack an SPL token account
+pub async fn fetch_token(banks: &mut BanksClient, address: Pubkey) -> spl_token::state::Account {
+    let account = banks
+        .get_account(address)
+        .await
+        .expect("bank is reachable")
+        .unwrap_or_else(|| panic!("{address} does not exist"));
+    spl_token::state::Account::unpack(&account.data).expect("token account unpacks")
+}
+
+pub async fn balance(banks: &mut BanksClient, address: Pubkey) -> u64 {
+    banks.get_balance(address).await.expect("bank is reachable")
+}
+
+/// Whether `address` still holds an account
+pub async fn exists(banks: &mut BanksClient, address: Pubkey) -> bool {
+    banks
+        .get_account(address)
+        .await
+        .expect("bank is reachable")
+        .is_some()
+}
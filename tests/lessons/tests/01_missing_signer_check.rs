@@ -0,0 +1,90 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::system_program;
+use lesson_tests::*;
+use missing_signer_check::{accounts, instruction as ix};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_system_interface::instruction as system_instruction;
+
+const PROGRAM_ID: Pubkey = missing_signer_check::ID;
+
+/// The authority's PDA vault, created unlimited and funded with 1 SOL
+async fn funded_vault(
+    banks: &mut solana_program_test::BanksClient,
+    payer: &Keypair,
+    authority: &Keypair,
+) -> Pubkey {
+    let (vault, _) =
+        Pubkey::find_program_address(&[b"vault", authority.pubkey().as_ref()], &PROGRAM_ID);
+    let initialize = instruction(
+        PROGRAM_ID,
+        accounts::InitializeVault {
+            initializer: authority.pubkey(),
+            vault,
+            system_program: system_program::ID,
+        },
+        ix::InitializeVaultSecure {
+            withdrawal_limit: 0,
+        },
+    );
+    let fund = system_instruction::transfer(&payer.pubkey(), &vault, LAMPORTS_PER_SOL);
+    send(banks, payer, &[initialize, fund], &[authority])
+        .await
+        .unwrap();
+    vault
+}
+
+#[tokio::test]
+async fn withdraw_insecure_pays_out_without_the_authority_signature() {
+    let (mut banks, attacker, _) = setup_program(PROGRAM_ID).await;
+    let authority = funded_keypair(&mut banks, &attacker, LAMPORTS_PER_SOL).await;
+    let vault = funded_vault(&mut banks, &attacker, &authority).await;
+    let before = balance(&mut banks, vault).await;
+
+    // 🚨 authority is named, but only the attacker signs
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::WithdrawInsecure {
+            vault,
+            authority: authority.pubkey(),
+        },
+        ix::WithdrawInsecure {
+            amount: LAMPORTS_PER_SOL / 2,
+        },
+    );
+    send(&mut banks, &attacker, &[exploit], &[]).await.unwrap();
+
+    // ❌ VULNERABLE: half the vault moved without the authority's signature
+    assert_eq!(
+        balance(&mut banks, vault).await,
+        before - LAMPORTS_PER_SOL / 2
+    );
+}
+
+#[tokio::test]
+async fn withdraw_secure_rejects_a_missing_signature() {
+    let (mut banks, attacker, _) = setup_program(PROGRAM_ID).await;
+    let authority = funded_keypair(&mut banks, &attacker, LAMPORTS_PER_SOL).await;
+    let vault = funded_vault(&mut banks, &attacker, &authority).await;
+    let before = balance(&mut banks, vault).await;
+
+    let exploit = without_signer(
+        instruction(
+            PROGRAM_ID,
+            accounts::WithdrawSecure {
+                vault,
+                authority: authority.pubkey(),
+            },
+            ix::WithdrawSecure {
+                amount: LAMPORTS_PER_SOL / 2,
+            },
+        ),
+        &authority.pubkey(),
+    );
+    let result = send(&mut banks, &attacker, &[exploit], &[]).await;
+
+    // ✅ SECURE: Signer<'info> fails before the handler runs
+    assert_eq!(error_code(result), u32::from(ErrorCode::AccountNotSigner));
+    assert_eq!(balance(&mut banks, vault).await, before);
+}
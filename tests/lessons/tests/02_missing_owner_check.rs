@@ -0,0 +1,91 @@
+use anchor_spl::token::spl_token;
+use anchor_spl::token::spl_token::solana_program::program_pack::Pack;
+use lesson_tests::*;
+use missing_owner_check::secure::{ErrorCode, PaymentVault};
+use missing_owner_check::{accounts, instruction as ix};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = missing_owner_check::ID;
+
+/// A token account claiming u64::MAX, owned by the attacker's program
+/// instead of SPL Token
+fn forged_token_account(mint: Pubkey, owner: Pubkey) -> solana_sdk::account::Account {
+    let mut forged = token_account(mint, owner, u64::MAX);
+    forged.owner = Pubkey::new_unique();
+    assert_eq!(forged.data.len(), spl_token::state::Account::LEN);
+    forged
+}
+
+/// The attacker's fake token account and a vault, seeded into a fresh bank
+async fn setup(attacker: &Keypair) -> (solana_program_test::BanksClient, Keypair, Pubkey, Pubkey) {
+    let fake_token_account = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let (banks, payer, _) = setup_programs(
+        &[PROGRAM_ID],
+        vec![
+            (
+                fake_token_account,
+                forged_token_account(mint, attacker.pubkey()),
+            ),
+            (
+                vault,
+                anchor_account(&PaymentVault {
+                    authority: attacker.pubkey(),
+                    total_collected: 0,
+                    expected_mint: mint,
+                }),
+            ),
+        ],
+    )
+    .await;
+    (banks, payer, fake_token_account, vault)
+}
+
+#[tokio::test]
+async fn process_payment_insecure_trusts_a_forged_token_account() {
+    let attacker = Keypair::new();
+    let (mut banks, payer, fake_token_account, vault) = setup(&attacker).await;
+
+    // 🚨 the "balance" comes from bytes the attacker's program wrote
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::PaymentInsecure {
+            user: attacker.pubkey(),
+            user_token_account: fake_token_account,
+            vault,
+        },
+        ix::ProcessPaymentInsecure {
+            amount: 1_000_000_000,
+        },
+    );
+
+    // ❌ VULNERABLE: the balance check passes on forged data
+    send(&mut banks, &payer, &[exploit], &[&attacker])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn process_payment_manual_rejects_an_account_spl_token_does_not_own() {
+    let attacker = Keypair::new();
+    let (mut banks, payer, fake_token_account, vault) = setup(&attacker).await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::PaymentManual {
+            user: attacker.pubkey(),
+            user_token_account: fake_token_account,
+            vault,
+        },
+        ix::ProcessPaymentManual {
+            amount: 1_000_000_000,
+        },
+    );
+    let result = send(&mut banks, &payer, &[exploit], &[&attacker]).await;
+
+    // ✅ SECURE: the owner check fails before a byte is read
+    assert_eq!(error_code(result), u32::from(ErrorCode::InvalidOwner));
+}
@@ -0,0 +1,95 @@
+use anchor_lang::system_program;
+use arithmetic_overflow::secure::Vault;
+use arithmetic_overflow::{accounts, instruction as ix};
+use lesson_tests::*;
+use solana_program_test::BanksClient;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = arithmetic_overflow::ID;
+
+/// Large enough that `deposited * 2x` wraps in u64, small enough that the
+/// reward itself still fits
+const DEPOSIT: u64 = u64::MAX / 2;
+const MULTIPLIER_2X: u64 = 20_000;
+
+/// An uncapped vault holding `DEPOSIT`, owned by `authority`
+async fn deposited_vault(banks: &mut BanksClient, payer: &Keypair, authority: &Keypair) -> Pubkey {
+    let vault = Keypair::new();
+    let initialize = instruction(
+        PROGRAM_ID,
+        accounts::InitializeVault {
+            vault: vault.pubkey(),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        ix::InitializeVault {
+            max_deposit: u64::MAX,
+            min_deposit: 0,
+        },
+    );
+    let deposit = instruction(
+        PROGRAM_ID,
+        accounts::VaultOps {
+            vault: vault.pubkey(),
+            authority: authority.pubkey(),
+        },
+        ix::DepositSecure { amount: DEPOSIT },
+    );
+    send(banks, payer, &[initialize, deposit], &[authority, &vault])
+        .await
+        .unwrap();
+    vault.pubkey()
+}
+
+#[tokio::test]
+async fn calculate_rewards_insecure_wraps_the_product() {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+    let authority = funded_keypair(&mut banks, &payer, LAMPORTS_PER_SOL).await;
+    let vault = deposited_vault(&mut banks, &payer, &authority).await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::InsecureVaultOps {
+            vault,
+            authority: authority.pubkey(),
+        },
+        ix::CalculateRewardsInsecure {
+            multiplier: MULTIPLIER_2X,
+        },
+    );
+    send(&mut banks, &payer, &[exploit], &[&authority])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: a 2x reward came out as whatever the wrapped product left
+    let rewards = fetch::<Vault>(&mut banks, vault).await.total_rewards;
+    assert_eq!(rewards, DEPOSIT.wrapping_mul(MULTIPLIER_2X) / 10_000);
+    assert_ne!(rewards, DEPOSIT * 2);
+}
+
+#[tokio::test]
+async fn calculate_rewards_secure_computes_the_exact_reward() {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+    let authority = funded_keypair(&mut banks, &payer, LAMPORTS_PER_SOL).await;
+    let vault = deposited_vault(&mut banks, &payer, &authority).await;
+
+    let calculate = instruction(
+        PROGRAM_ID,
+        accounts::VaultOps {
+            vault,
+            authority: authority.pubkey(),
+        },
+        ix::CalculateRewardsSecure {
+            multiplier: MULTIPLIER_2X,
+        },
+    );
+    send(&mut banks, &payer, &[calculate], &[&authority])
+        .await
+        .unwrap();
+
+    // ✅ SECURE: the product is taken in u128, so nothing wraps
+    let rewards = fetch::<Vault>(&mut banks, vault).await.total_rewards;
+    assert_eq!(rewards, DEPOSIT * 2);
+}
@@ -0,0 +1,122 @@
+use account_data_matching::{accounts, instruction as ix};
+use anchor_lang::error::ErrorCode;
+use anchor_lang::system_program;
+use lesson_tests::*;
+use solana_program_test::BanksClient;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = account_data_matching::ID;
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &PROGRAM_ID).0
+}
+
+/// The shared vault and program stats, plus a victim who deposited half a SOL
+///
+/// Returns the vault and the victim's user_stats.
+async fn victim_deposit(banks: &mut BanksClient, payer: &Keypair) -> (Pubkey, Pubkey) {
+    let victim = funded_keypair(banks, payer, 2 * LAMPORTS_PER_SOL).await;
+    let vault = pda(&[b"vault"]);
+    let program_stats = pda(&[b"program_stats"]);
+    let victim_stats = pda(&[b"user_stats", victim.pubkey().as_ref()]);
+
+    let setup = [
+        instruction(
+            PROGRAM_ID,
+            accounts::InitializeVault {
+                payer: victim.pubkey(),
+                vault,
+                system_program: system_program::ID,
+            },
+            ix::InitializeVault {},
+        ),
+        instruction(
+            PROGRAM_ID,
+            accounts::InitializeProgramStats {
+                payer: victim.pubkey(),
+                program_stats,
+                system_program: system_program::ID,
+            },
+            ix::InitializeProgramStats {},
+        ),
+        instruction(
+            PROGRAM_ID,
+            accounts::InitializeUserStats {
+                user: victim.pubkey(),
+                user_stats: victim_stats,
+                program_stats,
+                system_program: system_program::ID,
+            },
+            ix::InitializeUserStats {},
+        ),
+        instruction(
+            PROGRAM_ID,
+            accounts::Deposit {
+                user: victim.pubkey(),
+                user_stats: victim_stats,
+                vault,
+                program_stats,
+                system_program: system_program::ID,
+            },
+            ix::Deposit {
+                amount: LAMPORTS_PER_SOL / 2,
+            },
+        ),
+    ];
+    send(banks, payer, &setup, &[&victim]).await.unwrap();
+    (vault, victim_stats)
+}
+
+#[tokio::test]
+async fn withdraw_insecure_spends_another_users_stats() {
+    let (mut banks, attacker, _) = setup_program(PROGRAM_ID).await;
+    let (vault, victim_stats) = victim_deposit(&mut banks, &attacker).await;
+    let before = balance(&mut banks, vault).await;
+
+    // 🚨 the attacker signs, but spends the victim's recorded balance
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::WithdrawInsecure {
+            user: attacker.pubkey(),
+            user_stats: victim_stats,
+            vault,
+        },
+        ix::WithdrawInsecure {
+            amount: LAMPORTS_PER_SOL / 2,
+        },
+    );
+    send(&mut banks, &attacker, &[exploit], &[]).await.unwrap();
+
+    // ❌ VULNERABLE: the victim's deposit left the vault
+    assert_eq!(
+        balance(&mut banks, vault).await,
+        before - LAMPORTS_PER_SOL / 2
+    );
+}
+
+#[tokio::test]
+async fn withdraw_secure_rejects_stats_not_derived_from_the_signer() {
+    let (mut banks, attacker, _) = setup_program(PROGRAM_ID).await;
+    let (vault, victim_stats) = victim_deposit(&mut banks, &attacker).await;
+    let before = balance(&mut banks, vault).await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::WithdrawSecure {
+            user: attacker.pubkey(),
+            user_stats: victim_stats,
+            vault,
+            program_stats: pda(&[b"program_stats"]),
+        },
+        ix::WithdrawSecure {
+            amount: LAMPORTS_PER_SOL / 2,
+        },
+    );
+    let result = send(&mut banks, &attacker, &[exploit], &[]).await;
+
+    // ✅ SECURE: user_stats must sit at [b"user_stats", signer]
+    assert_eq!(error_code(result), u32::from(ErrorCode::ConstraintSeeds));
+    assert_eq!(balance(&mut banks, vault).await, before);
+}
@@ -0,0 +1,141 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::system_program;
+use arbitrary_cpi::{accounts, instruction as ix};
+use lesson_tests::*;
+use solana_program_test::BanksClient;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::sysvar;
+
+const PROGRAM_ID: Pubkey = arbitrary_cpi::ID;
+const ROGUE_CALLER_ID: Pubkey = rogue_caller::ID;
+
+/// SPL Memo v1 accepts any accounts and any UTF-8 data. It stands in for a
+/// malicious program that "succeeds" without moving a single token.
+const FAKE_TOKEN_PROGRAM: Pubkey =
+    solana_sdk::pubkey!("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo");
+
+/// Packs to valid UTF-8, so Memo accepts the forwarded transfer data
+const AMOUNT: u64 = 100;
+
+struct Tokens {
+    authority: Keypair,
+    from: Pubkey,
+    to: Pubkey,
+}
+
+/// `authority` holds 1_000 tokens in `from`; `to` is someone else's, empty
+async fn setup() -> (BanksClient, Keypair, Tokens) {
+    let authority = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let from = Pubkey::new_unique();
+    let to = Pubkey::new_unique();
+
+    let (banks, payer, _) = setup_programs(
+        &[PROGRAM_ID, ROGUE_CALLER_ID],
+        vec![
+            (authority.pubkey(), system_account(LAMPORTS_PER_SOL)),
+            (mint, mint_account(authority.pubkey(), None)),
+            (from, token_account(mint, authority.pubkey(), 1_000)),
+            (to, token_account(mint, Pubkey::new_unique(), 0)),
+        ],
+    )
+    .await;
+    (
+        banks,
+        payer,
+        Tokens {
+            authority,
+            from,
+            to,
+        },
+    )
+}
+
+#[tokio::test]
+async fn transfer_insecure_calls_whatever_program_it_is_handed() {
+    let (mut banks, payer, tokens) = setup().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::TransferInsecure {
+            token_program: FAKE_TOKEN_PROGRAM,
+            from: tokens.from,
+            to: tokens.to,
+            authority: tokens.authority.pubkey(),
+        },
+        ix::TransferInsecure { amount: AMOUNT },
+    );
+    send(&mut banks, &payer, &[exploit], &[&tokens.authority])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: "Transfer completed", yet no tokens moved
+    assert_eq!(fetch_token(&mut banks, tokens.from).await.amount, 1_000);
+    assert_eq!(fetch_token(&mut banks, tokens.to).await.amount, 0);
+}
+
+#[tokio::test]
+async fn transfer_secure_rejects_any_program_but_spl_token() {
+    let (mut banks, payer, tokens) = setup().await;
+    let (config, _) =
+        Pubkey::find_program_address(&[b"authority_config", tokens.from.as_ref()], &PROGRAM_ID);
+    let configure = instruction(
+        PROGRAM_ID,
+        accounts::InitializeAuthorityConfig {
+            authority: tokens.authority.pubkey(),
+            from: tokens.from,
+            config,
+            system_program: system_program::ID,
+        },
+        ix::InitializeAuthorityConfig {},
+    );
+    send(&mut banks, &payer, &[configure], &[&tokens.authority])
+        .await
+        .unwrap();
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::TransferSecure {
+            token_program: FAKE_TOKEN_PROGRAM,
+            from: tokens.from,
+            to: tokens.to,
+            config,
+            authority: tokens.authority.pubkey(),
+        },
+        ix::TransferSecure { amount: AMOUNT },
+    );
+    let result = send(&mut banks, &payer, &[exploit], &[&tokens.authority]).await;
+
+    // ✅ SECURE: Program<'info, Token> only accepts spl_token::ID
+    assert_eq!(error_code(result), u32::from(ErrorCode::InvalidProgramId));
+}
+
+#[tokio::test]
+async fn transfer_with_introspection_rejects_a_relayed_signature() {
+    let (mut banks, payer, tokens) = setup().await;
+
+    // 🚨 the user signed for the rogue program, which forwards the
+    // signature into 05 through a CPI
+    let exploit = instruction(
+        ROGUE_CALLER_ID,
+        rogue_caller::accounts::RelayTransfer {
+            arbitrary_cpi_program: PROGRAM_ID,
+            token_program: anchor_spl::token::ID,
+            from: tokens.from,
+            to: tokens.to,
+            authority: tokens.authority.pubkey(),
+            instructions: sysvar::instructions::ID,
+        },
+        rogue_caller::instruction::RelayTransfer { amount: AMOUNT },
+    );
+    let result = send(&mut banks, &payer, &[exploit], &[&tokens.authority]).await;
+
+    // ✅ SECURE: the top-level instruction belongs to the rogue program
+    assert_eq!(
+        error_code(result),
+        u32::from(arbitrary_cpi::secure::ErrorCode::CpiNotAllowed)
+    );
+    assert_eq!(fetch_token(&mut banks, tokens.from).await.amount, 1_000);
+}
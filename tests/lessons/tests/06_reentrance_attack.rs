@@ -0,0 +1,126 @@
+use anchor_lang::system_program;
+use lesson_tests::*;
+use reentrance_attack::secure::UserAccount;
+use reentrance_attack::{accounts, instruction as ix};
+use solana_program_test::BanksClient;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = reentrance_attack::ID;
+const CALLBACK_ID: Pubkey = reentrance_callback::ID;
+
+struct Depositor {
+    user: Keypair,
+    user_account: Pubkey,
+    vault: Pubkey,
+}
+
+/// The shared vault, and a user who deposited 1 SOL into it
+async fn deposited(banks: &mut BanksClient, payer: &Keypair) -> Depositor {
+    let user = funded_keypair(banks, payer, 2 * LAMPORTS_PER_SOL).await;
+    let (vault, _) = Pubkey::find_program_address(&[b"vault"], &PROGRAM_ID);
+    let (user_account, _) =
+        Pubkey::find_program_address(&[b"user_account", user.pubkey().as_ref()], &PROGRAM_ID);
+
+    let setup = [
+        instruction(
+            PROGRAM_ID,
+            accounts::InitializeVault {
+                payer: payer.pubkey(),
+                vault,
+                system_program: system_program::ID,
+            },
+            ix::InitializeVault {},
+        ),
+        instruction(
+            PROGRAM_ID,
+            accounts::InitializeUserAccount {
+                user: user.pubkey(),
+                user_account,
+                system_program: system_program::ID,
+            },
+            ix::InitializeUserAccount {},
+        ),
+        instruction(
+            PROGRAM_ID,
+            accounts::Deposit {
+                user: user.pubkey(),
+                user_account,
+                vault,
+                system_program: system_program::ID,
+            },
+            ix::Deposit {
+                amount: LAMPORTS_PER_SOL,
+            },
+        ),
+    ];
+    send(banks, payer, &setup, &[&user]).await.unwrap();
+    Depositor {
+        user,
+        user_account,
+        vault,
+    }
+}
+
+#[tokio::test]
+async fn withdraw_with_callback_vulnerable_lets_the_callback_see_a_stale_balance() {
+    let (mut banks, payer, _) = setup_programs(&[PROGRAM_ID, CALLBACK_ID], vec![]).await;
+    let depositor = deposited(&mut banks, &payer).await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::WithdrawWithCallbackVulnerable {
+            user: depositor.user.pubkey(),
+            user_account: depositor.user_account,
+            vault: depositor.vault,
+            callback_program: CALLBACK_ID,
+            this_program: PROGRAM_ID,
+        },
+        ix::WithdrawWithCallbackVulnerable {
+            amount: LAMPORTS_PER_SOL,
+        },
+    );
+    let result = send(&mut banks, &payer, &[exploit], &[&depositor.user]).await;
+
+    // ❌ VULNERABLE: the callback only calls back in when the balance on
+    // chain still covers the withdrawal it is being paid for. Solana allows
+    // only direct self-recursion, so the runtime stops the A -> B -> A drain.
+    assert_eq!(
+        instruction_error(result),
+        InstructionError::ReentrancyNotAllowed
+    );
+}
+
+#[tokio::test]
+async fn withdraw_with_callback_secure_finalizes_the_balance_first() {
+    let (mut banks, payer, _) = setup_programs(&[PROGRAM_ID, CALLBACK_ID], vec![]).await;
+    let depositor = deposited(&mut banks, &payer).await;
+    let before = balance(&mut banks, depositor.vault).await;
+
+    let withdraw = instruction(
+        PROGRAM_ID,
+        accounts::WithdrawWithCallbackSecure {
+            user: depositor.user.pubkey(),
+            user_account: depositor.user_account,
+            vault: depositor.vault,
+            callback_program: CALLBACK_ID,
+            this_program: PROGRAM_ID,
+        },
+        ix::WithdrawWithCallbackSecure {
+            amount: LAMPORTS_PER_SOL,
+        },
+    );
+    send(&mut banks, &payer, &[withdraw], &[&depositor.user])
+        .await
+        .unwrap();
+
+    // ✅ SECURE: the callback saw balance 0 and had nothing to steal
+    let account = fetch::<UserAccount>(&mut banks, depositor.user_account).await;
+    assert_eq!(account.balance, 0);
+    assert_eq!(
+        balance(&mut banks, depositor.vault).await,
+        before - LAMPORTS_PER_SOL
+    );
+}
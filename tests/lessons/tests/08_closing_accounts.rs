@@ -0,0 +1,120 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::system_program;
+use closing_accounts::secure::UserAccount;
+use closing_accounts::{accounts, instruction as ix};
+use lesson_tests::*;
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_system_interface::instruction as system_instruction;
+
+const PROGRAM_ID: Pubkey = closing_accounts::ID;
+
+/// A UserAccount holding a recorded balance of 1_000
+async fn initialized(banks: &mut BanksClient, payer: &Keypair, authority: &Keypair) -> Pubkey {
+    let account = Keypair::new();
+    let initialize = instruction(
+        PROGRAM_ID,
+        accounts::Initialize {
+            account: account.pubkey(),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        ix::Initialize { balance: 1_000 },
+    );
+    send(banks, payer, &[initialize], &[authority, &account])
+        .await
+        .unwrap();
+    account.pubkey()
+}
+
+/// Close and re-fund in ONE transaction, before garbage collection runs
+async fn close_and_revive(
+    banks: &mut BanksClient,
+    payer: &Keypair,
+    authority: &Keypair,
+    account: Pubkey,
+    close: Instruction,
+) {
+    let size = banks
+        .get_account(account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data
+        .len();
+    let rent = banks.get_rent().await.unwrap().minimum_balance(size);
+    let revive = system_instruction::transfer(&payer.pubkey(), &account, rent);
+    send(banks, payer, &[close, revive], &[authority])
+        .await
+        .unwrap();
+}
+
+async fn use_account(
+    banks: &mut BanksClient,
+    payer: &Keypair,
+    authority: &Keypair,
+    account: Pubkey,
+) -> Result<(), BanksClientError> {
+    let use_account = instruction(
+        PROGRAM_ID,
+        accounts::UseAccount {
+            account,
+            authority: authority.pubkey(),
+        },
+        ix::UseAccount {},
+    );
+    send(banks, payer, &[use_account], &[authority]).await
+}
+
+#[tokio::test]
+async fn close_insecure_leaves_an_account_that_can_be_revived() {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+    let authority = funded_keypair(&mut banks, &payer, LAMPORTS_PER_SOL).await;
+    let account = initialized(&mut banks, &payer, &authority).await;
+
+    let close = instruction(
+        PROGRAM_ID,
+        accounts::CloseInsecure {
+            account,
+            destination: authority.pubkey(),
+            authority: authority.pubkey(),
+        },
+        ix::CloseInsecure {},
+    );
+    close_and_revive(&mut banks, &payer, &authority, account, close).await;
+
+    // ❌ VULNERABLE: the "closed" account is still a valid UserAccount
+    let revived = fetch::<UserAccount>(&mut banks, account).await;
+    assert_eq!(revived.balance, 1_000);
+    use_account(&mut banks, &payer, &authority, account)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn close_secure_poisons_the_account_against_revival() {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+    let authority = funded_keypair(&mut banks, &payer, LAMPORTS_PER_SOL).await;
+    let account = initialized(&mut banks, &payer, &authority).await;
+
+    let close = instruction(
+        PROGRAM_ID,
+        accounts::CloseSecure {
+            account,
+            destination: authority.pubkey(),
+            authority: authority.pubkey(),
+        },
+        ix::CloseSecure {},
+    );
+    close_and_revive(&mut banks, &payer, &authority, account, close).await;
+
+    // ✅ SECURE: revived, but as CLOSED_ACCOUNT_DISCRIMINATOR
+    let result = use_account(&mut banks, &payer, &authority, account).await;
+    assert_eq!(
+        error_code(result),
+        u32::from(ErrorCode::AccountDiscriminatorMismatch)
+    );
+}
@@ -0,0 +1,69 @@
+use anchor_lang::system_program;
+use duplicate_mutable_accounts::secure::{ErrorCode, UserAccount};
+use duplicate_mutable_accounts::{accounts, instruction as ix};
+use lesson_tests::*;
+use solana_program_test::BanksClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = duplicate_mutable_accounts::ID;
+
+/// The attacker's own account, holding a recorded balance of 100
+async fn own_account(banks: &mut BanksClient, attacker: &Keypair) -> Pubkey {
+    let account = Keypair::new();
+    let initialize = instruction(
+        PROGRAM_ID,
+        accounts::Initialize {
+            account: account.pubkey(),
+            authority: attacker.pubkey(),
+            system_program: system_program::ID,
+        },
+        ix::Initialize { balance: 100 },
+    );
+    send(banks, attacker, &[initialize], &[&account])
+        .await
+        .unwrap();
+    account.pubkey()
+}
+
+#[tokio::test]
+async fn transfer_insecure_mints_on_a_transfer_to_self() {
+    let (mut banks, attacker, _) = setup_program(PROGRAM_ID).await;
+    let account = own_account(&mut banks, &attacker).await;
+
+    // 🚨 the same account in both mutable slots
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::TransferInsecure {
+            account_a: account,
+            account_b: account,
+            authority: attacker.pubkey(),
+        },
+        ix::TransferInsecure { amount: 50 },
+    );
+    send(&mut banks, &attacker, &[exploit], &[]).await.unwrap();
+
+    // ❌ VULNERABLE: account_b's write lands last - 50 out of thin air
+    assert_eq!(fetch::<UserAccount>(&mut banks, account).await.balance, 150);
+}
+
+#[tokio::test]
+async fn transfer_secure_rejects_aliased_accounts() {
+    let (mut banks, attacker, _) = setup_program(PROGRAM_ID).await;
+    let account = own_account(&mut banks, &attacker).await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::TransferSecure {
+            account_a: account,
+            account_b: account,
+            authority: attacker.pubkey(),
+        },
+        ix::TransferSecure { amount: 50 },
+    );
+    let result = send(&mut banks, &attacker, &[exploit], &[]).await;
+
+    // ✅ SECURE: the key comparison runs before either slot is written
+    assert_eq!(error_code(result), u32::from(ErrorCode::DuplicateAccount));
+    assert_eq!(fetch::<UserAccount>(&mut banks, account).await.balance, 100);
+}
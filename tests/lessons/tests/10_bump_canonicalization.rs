@@ -0,0 +1,86 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::system_program;
+use bump_canonicalization::secure::UserData;
+use bump_canonicalization::{accounts, instruction as ix};
+use lesson_tests::*;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = bump_canonicalization::ID;
+
+/// The canonical (address, bump) for `authority`, and the next valid one
+/// below it
+fn canonical_and_not(authority: &Keypair) -> ((Pubkey, u8), (Pubkey, u8)) {
+    let authority = authority.pubkey();
+    let canonical = Pubkey::find_program_address(&[b"user_data", authority.as_ref()], &PROGRAM_ID);
+    let non_canonical = (0..canonical.1)
+        .rev()
+        .find_map(|bump| {
+            Pubkey::create_program_address(
+                &[b"user_data", authority.as_ref(), &[bump]],
+                &PROGRAM_ID,
+            )
+            .ok()
+            .map(|address| (address, bump))
+        })
+        .expect("some lower bump lands off the curve");
+    (canonical, non_canonical)
+}
+
+fn initialize_insecure(authority: &Keypair, (user_data, bump): (Pubkey, u8)) -> Instruction {
+    instruction(
+        PROGRAM_ID,
+        accounts::InitializeInsecure {
+            authority: authority.pubkey(),
+            user_data,
+            system_program: system_program::ID,
+        },
+        ix::InitializeInsecure { bump },
+    )
+}
+
+#[tokio::test]
+async fn initialize_insecure_creates_two_accounts_for_the_same_seeds() {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+    let authority = funded_keypair(&mut banks, &payer, LAMPORTS_PER_SOL).await;
+    let (canonical, non_canonical) = canonical_and_not(&authority);
+
+    let initialize = [
+        initialize_insecure(&authority, canonical),
+        initialize_insecure(&authority, non_canonical),
+    ];
+    send(&mut banks, &payer, &initialize, &[&authority])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: one user, two "unique" PDAs
+    let first = fetch::<UserData>(&mut banks, canonical.0).await;
+    let second = fetch::<UserData>(&mut banks, non_canonical.0).await;
+    assert_eq!(first.authority, authority.pubkey());
+    assert_eq!(second.authority, authority.pubkey());
+    assert_eq!(second.bump, non_canonical.1);
+}
+
+#[tokio::test]
+async fn initialize_secure_rejects_a_non_canonical_address() {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+    let authority = funded_keypair(&mut banks, &payer, LAMPORTS_PER_SOL).await;
+    let (_, non_canonical) = canonical_and_not(&authority);
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::InitializeSecure {
+            authority: authority.pubkey(),
+            user_data: non_canonical.0,
+            system_program: system_program::ID,
+        },
+        ix::InitializeSecure {},
+    );
+    let result = send(&mut banks, &payer, &[exploit], &[&authority]).await;
+
+    // ✅ SECURE: `bump` on init means find_program_address - canonical only
+    assert_eq!(error_code(result), u32::from(ErrorCode::ConstraintSeeds));
+    assert!(!exists(&mut banks, non_canonical.0).await);
+}
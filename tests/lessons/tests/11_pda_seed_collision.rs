@@ -0,0 +1,122 @@
+use anchor_lang::system_program;
+use lesson_tests::*;
+use pda_seed_collision::secure::Profile;
+use pda_seed_collision::{accounts, instruction as ix};
+use solana_program_test::BanksClient;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = pda_seed_collision::ID;
+
+fn insecure_pda(namespace: &str, name: &str) -> Pubkey {
+    Pubkey::find_program_address(&[namespace.as_bytes(), name.as_bytes()], &PROGRAM_ID).0
+}
+
+fn secure_pda(namespace: &str, name: &str) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            &[namespace.len() as u8],
+            namespace.as_bytes(),
+            &[name.len() as u8],
+            name.as_bytes(),
+        ],
+        &PROGRAM_ID,
+    )
+    .0
+}
+
+fn register_insecure(owner: &Keypair, namespace: &str, name: &str) -> Instruction {
+    instruction(
+        PROGRAM_ID,
+        accounts::RegisterInsecure {
+            owner: owner.pubkey(),
+            profile: insecure_pda(namespace, name),
+            system_program: system_program::ID,
+        },
+        ix::RegisterInsecure {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        },
+    )
+}
+
+fn register_secure(owner: &Keypair, namespace: &str, name: &str) -> Instruction {
+    instruction(
+        PROGRAM_ID,
+        accounts::RegisterSecure {
+            owner: owner.pubkey(),
+            profile: secure_pda(namespace, name),
+            system_program: system_program::ID,
+        },
+        ix::RegisterSecure {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        },
+    )
+}
+
+async fn attacker_and_victim(banks: &mut BanksClient, payer: &Keypair) -> (Keypair, Keypair) {
+    (
+        funded_keypair(banks, payer, LAMPORTS_PER_SOL).await,
+        funded_keypair(banks, payer, LAMPORTS_PER_SOL).await,
+    )
+}
+
+#[tokio::test]
+async fn register_insecure_lets_one_name_squat_another() {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+    let (attacker, victim) = attacker_and_victim(&mut banks, &payer).await;
+    assert_eq!(insecure_pda("ab", "c"), insecure_pda("a", "bc"));
+
+    // 🚨 the attacker registers first and takes the victim's address
+    send(
+        &mut banks,
+        &payer,
+        &[register_insecure(&attacker, "ab", "c")],
+        &[&attacker],
+    )
+    .await
+    .unwrap();
+    let result = send(
+        &mut banks,
+        &payer,
+        &[register_insecure(&victim, "a", "bc")],
+        &[&victim],
+    )
+    .await;
+
+    // ❌ VULNERABLE: ("a", "bc") is already taken by ("ab", "c")
+    assert!(result.is_err());
+    let squatted = fetch::<Profile>(&mut banks, insecure_pda("a", "bc")).await;
+    assert_eq!(squatted.owner, attacker.pubkey());
+}
+
+#[tokio::test]
+async fn register_secure_keeps_the_two_names_apart() {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+    let (attacker, victim) = attacker_and_victim(&mut banks, &payer).await;
+
+    send(
+        &mut banks,
+        &payer,
+        &[register_secure(&attacker, "ab", "c")],
+        &[&attacker],
+    )
+    .await
+    .unwrap();
+    send(
+        &mut banks,
+        &payer,
+        &[register_secure(&victim, "a", "bc")],
+        &[&victim],
+    )
+    .await
+    .unwrap();
+
+    // ✅ SECURE: length prefixes make the two seed lists different bytes
+    let profile = fetch::<Profile>(&mut banks, secure_pda("a", "bc")).await;
+    assert_eq!(profile.owner, victim.pubkey());
+    assert_eq!(profile.name, "bc");
+}
@@ -0,0 +1,91 @@
+use anchor_lang::system_program;
+use lesson_tests::*;
+use solana_program_test::BanksClient;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use sysvar_spoofing::secure::ErrorCode;
+use sysvar_spoofing::{accounts, instruction as ix};
+
+const PROGRAM_ID: Pubkey = sysvar_spoofing::ID;
+
+/// Clock-shaped bytes with `unix_timestamp` (offset 32) set to i64::MAX
+fn fake_clock() -> Vec<u8> {
+    let mut data = vec![0; 40];
+    data[32..40].copy_from_slice(&i64::MAX.to_le_bytes());
+    data
+}
+
+/// A bank with the attacker's fake clock, and half a SOL locked until the
+/// end of time
+async fn setup() -> (BanksClient, Keypair, Keypair, Pubkey, Pubkey) {
+    let fake_clock_address = Pubkey::new_unique();
+    let (mut banks, payer, _) = setup_programs(
+        &[PROGRAM_ID],
+        vec![(
+            fake_clock_address,
+            owned_account(Pubkey::new_unique(), fake_clock()),
+        )],
+    )
+    .await;
+
+    let owner = funded_keypair(&mut banks, &payer, LAMPORTS_PER_SOL).await;
+    let lock = Keypair::new();
+    let create = instruction(
+        PROGRAM_ID,
+        accounts::CreateLock {
+            owner: owner.pubkey(),
+            lock: lock.pubkey(),
+            system_program: system_program::ID,
+        },
+        ix::CreateLock {
+            amount: LAMPORTS_PER_SOL / 2,
+            unlock_at: i64::MAX,
+        },
+    );
+    send(&mut banks, &payer, &[create], &[&owner, &lock])
+        .await
+        .unwrap();
+    (banks, payer, owner, lock.pubkey(), fake_clock_address)
+}
+
+#[tokio::test]
+async fn unlock_insecure_trusts_a_forged_clock() {
+    let (mut banks, payer, owner, lock, fake_clock) = setup().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::UnlockInsecure {
+            owner: owner.pubkey(),
+            lock,
+            clock: fake_clock,
+        },
+        ix::UnlockInsecure {},
+    );
+    send(&mut banks, &payer, &[exploit], &[&owner])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: a lock meant to last forever was opened today
+    assert!(!exists(&mut banks, lock).await);
+}
+
+#[tokio::test]
+async fn unlock_secure_only_accepts_the_real_clock() {
+    let (mut banks, payer, owner, lock, fake_clock) = setup().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::UnlockSecure {
+            owner: owner.pubkey(),
+            lock,
+            clock: fake_clock,
+        },
+        ix::UnlockSecure {},
+    );
+    let result = send(&mut banks, &payer, &[exploit], &[&owner]).await;
+
+    // ✅ SECURE: the clock account must be the sysvar's own address
+    assert_eq!(error_code(result), u32::from(ErrorCode::InvalidSysvar));
+    assert!(exists(&mut banks, lock).await);
+}
@@ -0,0 +1,94 @@
+use lesson_tests::*;
+use reload_after_cpi::secure::ErrorCode;
+use reload_after_cpi::{accounts, instruction as ix};
+use solana_program_test::BanksClient;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = reload_after_cpi::ID;
+
+/// Leaves 40 in a source that must keep 50
+const AMOUNT: u64 = 60;
+const MIN_RESERVE: u64 = 50;
+
+struct Tokens {
+    authority: Keypair,
+    source: Pubkey,
+    destination: Pubkey,
+}
+
+/// `authority` holds 100 tokens in `source`; `destination` is empty
+async fn setup() -> (BanksClient, Keypair, Tokens) {
+    let authority = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let (banks, payer, _) = setup_programs(
+        &[PROGRAM_ID],
+        vec![
+            (authority.pubkey(), system_account(LAMPORTS_PER_SOL)),
+            (mint, mint_account(authority.pubkey(), None)),
+            (source, token_account(mint, authority.pubkey(), 100)),
+            (destination, token_account(mint, Pubkey::new_unique(), 0)),
+        ],
+    )
+    .await;
+    let tokens = Tokens {
+        authority,
+        source,
+        destination,
+    };
+    (banks, payer, tokens)
+}
+
+#[tokio::test]
+async fn pay_out_insecure_checks_the_reserve_against_a_stale_balance() {
+    let (mut banks, payer, tokens) = setup().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::PayOutInsecure {
+            source: tokens.source,
+            destination: tokens.destination,
+            authority: tokens.authority.pubkey(),
+            token_program: anchor_spl::token::ID,
+        },
+        ix::PayOutInsecure {
+            amount: AMOUNT,
+            min_reserve: MIN_RESERVE,
+        },
+    );
+    send(&mut banks, &payer, &[exploit], &[&tokens.authority])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: the check saw 100, the account now holds 40
+    let source = fetch_token(&mut banks, tokens.source).await;
+    assert!(source.amount < MIN_RESERVE);
+}
+
+#[tokio::test]
+async fn pay_out_secure_reloads_before_checking_the_reserve() {
+    let (mut banks, payer, tokens) = setup().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::PayOutSecure {
+            source: tokens.source,
+            destination: tokens.destination,
+            authority: tokens.authority.pubkey(),
+            token_program: anchor_spl::token::ID,
+        },
+        ix::PayOutSecure {
+            amount: AMOUNT,
+            min_reserve: MIN_RESERVE,
+        },
+    );
+    let result = send(&mut banks, &payer, &[exploit], &[&tokens.authority]).await;
+
+    // ✅ SECURE: the reserve check fails and the transfer reverts with it
+    assert_eq!(error_code(result), u32::from(ErrorCode::BelowReserve));
+    assert_eq!(fetch_token(&mut banks, tokens.source).await.amount, 100);
+}
@@ -0,0 +1,87 @@
+use anchor_lang::system_program;
+use lesson_tests::*;
+use rent_exemption::secure::ErrorCode;
+use rent_exemption::{accounts, instruction as ix};
+use solana_program_test::BanksClient;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = rent_exemption::ID;
+
+/// An owner whose vault holds rent plus a 1 SOL deposit
+async fn funded_vault() -> (BanksClient, Keypair, Keypair, Pubkey) {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+    let owner = funded_keypair(&mut banks, &payer, 2 * LAMPORTS_PER_SOL).await;
+    let (vault, _) =
+        Pubkey::find_program_address(&[b"vault", owner.pubkey().as_ref()], &PROGRAM_ID);
+
+    let setup = [
+        instruction(
+            PROGRAM_ID,
+            accounts::InitializeVault {
+                owner: owner.pubkey(),
+                vault,
+                system_program: system_program::ID,
+            },
+            ix::InitializeVault {},
+        ),
+        instruction(
+            PROGRAM_ID,
+            accounts::Deposit {
+                owner: owner.pubkey(),
+                vault,
+                system_program: system_program::ID,
+            },
+            ix::Deposit {
+                amount: LAMPORTS_PER_SOL,
+            },
+        ),
+    ];
+    send(&mut banks, &payer, &setup, &[&owner]).await.unwrap();
+    (banks, payer, owner, vault)
+}
+
+#[tokio::test]
+async fn withdraw_insecure_drains_the_vault_out_of_existence() {
+    let (mut banks, payer, owner, vault) = funded_vault().await;
+    let everything = balance(&mut banks, vault).await;
+
+    let withdraw = instruction(
+        PROGRAM_ID,
+        accounts::WithdrawInsecure {
+            owner: owner.pubkey(),
+            vault,
+        },
+        ix::WithdrawInsecure { amount: everything },
+    );
+    send(&mut banks, &payer, &[withdraw], &[&owner])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: zero lamports - the runtime purged the vault's state
+    assert!(!exists(&mut banks, vault).await);
+}
+
+#[tokio::test]
+async fn withdraw_secure_keeps_the_rent_exempt_minimum() {
+    let (mut banks, payer, owner, vault) = funded_vault().await;
+    let everything = balance(&mut banks, vault).await;
+
+    let withdraw = instruction(
+        PROGRAM_ID,
+        accounts::WithdrawSecure {
+            owner: owner.pubkey(),
+            vault,
+        },
+        ix::WithdrawSecure { amount: everything },
+    );
+    let result = send(&mut banks, &payer, &[withdraw], &[&owner]).await;
+
+    // ✅ SECURE: only the deposit above the rent minimum is withdrawable
+    assert_eq!(
+        error_code(result),
+        u32::from(ErrorCode::WouldBreakRentExemption)
+    );
+    assert_eq!(balance(&mut banks, vault).await, everything);
+}
@@ -0,0 +1,70 @@
+use anchor_lang::system_program;
+use integer_truncation::secure::{ErrorCode, Ledger, DAILY_LIMIT};
+use integer_truncation::{accounts, instruction as ix};
+use lesson_tests::*;
+use solana_program_test::BanksClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = integer_truncation::ID;
+
+/// 2^32 + 100 - counted as 100 once cast to u32
+const HUGE: u64 = (1 << 32) + 100;
+
+async fn ledger(banks: &mut BanksClient, authority: &Keypair) -> Pubkey {
+    let (ledger, _) =
+        Pubkey::find_program_address(&[b"ledger", authority.pubkey().as_ref()], &PROGRAM_ID);
+    let initialize = instruction(
+        PROGRAM_ID,
+        accounts::InitializeLedger {
+            authority: authority.pubkey(),
+            ledger,
+            system_program: system_program::ID,
+        },
+        ix::InitializeLedger {},
+    );
+    send(banks, authority, &[initialize], &[]).await.unwrap();
+    ledger
+}
+
+#[tokio::test]
+async fn request_withdrawal_insecure_counts_the_truncated_amount() {
+    let (mut banks, authority, _) = setup_program(PROGRAM_ID).await;
+    let ledger = ledger(&mut banks, &authority).await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::RequestWithdrawalInsecure {
+            authority: authority.pubkey(),
+            ledger,
+        },
+        ix::RequestWithdrawalInsecure { amount: HUGE },
+    );
+    send(&mut banks, &authority, &[exploit], &[]).await.unwrap();
+
+    // ❌ VULNERABLE: 100 counted against the limit, 2^32 + 100 queued
+    let ledger = fetch::<Ledger>(&mut banks, ledger).await;
+    assert_eq!(ledger.requested_today, 100);
+    assert_eq!(ledger.pending, HUGE);
+    assert!(ledger.pending > u64::from(DAILY_LIMIT));
+}
+
+#[tokio::test]
+async fn request_withdrawal_secure_rejects_amounts_that_do_not_fit() {
+    let (mut banks, authority, _) = setup_program(PROGRAM_ID).await;
+    let ledger = ledger(&mut banks, &authority).await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::RequestWithdrawalSecure {
+            authority: authority.pubkey(),
+            ledger,
+        },
+        ix::RequestWithdrawalSecure { amount: HUGE },
+    );
+    let result = send(&mut banks, &authority, &[exploit], &[]).await;
+
+    // ✅ SECURE: u32::try_from fails instead of dropping the high bits
+    assert_eq!(error_code(result), u32::from(ErrorCode::Truncation));
+    assert_eq!(fetch::<Ledger>(&mut banks, ledger).await.pending, 0);
+}
@@ -0,0 +1,100 @@
+use anchor_lang::system_program;
+use lesson_tests::*;
+use precision_loss::secure::Position;
+use precision_loss::{accounts, instruction as ix};
+use solana_program_test::BanksClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = precision_loss::ID;
+
+const TOTAL_STAKED: u64 = 1_000_000_000;
+const REWARD_POOL: u64 = 1_000_000_000;
+/// A millionth of the pool - owed a millionth of the rewards
+const STAKE: u64 = 1_000;
+
+struct Staker {
+    pool: Pubkey,
+    position: Pubkey,
+}
+
+/// A pool and one small position in it, both owned by `owner`
+async fn staked(banks: &mut BanksClient, owner: &Keypair) -> Staker {
+    let pool = Keypair::new();
+    let (position, _) = Pubkey::find_program_address(
+        &[b"position", pool.pubkey().as_ref(), owner.pubkey().as_ref()],
+        &PROGRAM_ID,
+    );
+
+    let setup = [
+        instruction(
+            PROGRAM_ID,
+            accounts::InitializePool {
+                pool: pool.pubkey(),
+                authority: owner.pubkey(),
+                system_program: system_program::ID,
+            },
+            ix::InitializePool {
+                total_staked: TOTAL_STAKED,
+                reward_pool: REWARD_POOL,
+            },
+        ),
+        instruction(
+            PROGRAM_ID,
+            accounts::OpenPosition {
+                owner: owner.pubkey(),
+                pool: pool.pubkey(),
+                position,
+                system_program: system_program::ID,
+            },
+            ix::OpenPosition { stake: STAKE },
+        ),
+    ];
+    send(banks, owner, &setup, &[&pool]).await.unwrap();
+    Staker {
+        pool: pool.pubkey(),
+        position,
+    }
+}
+
+#[tokio::test]
+async fn accrue_insecure_rounds_a_small_stake_down_to_nothing() {
+    let (mut banks, owner, _) = setup_program(PROGRAM_ID).await;
+    let staker = staked(&mut banks, &owner).await;
+
+    let accrue = instruction(
+        PROGRAM_ID,
+        accounts::AccrueInsecure {
+            owner: owner.pubkey(),
+            pool: staker.pool,
+            position: staker.position,
+        },
+        ix::AccrueInsecure {},
+    );
+    send(&mut banks, &owner, &[accrue], &[]).await.unwrap();
+
+    // ❌ VULNERABLE: 1_000 / 1_000_000_000 == 0 before the multiply
+    let position = fetch::<Position>(&mut banks, staker.position).await;
+    assert_eq!(position.reward, 0);
+}
+
+#[tokio::test]
+async fn accrue_secure_pays_the_proportional_reward() {
+    let (mut banks, owner, _) = setup_program(PROGRAM_ID).await;
+    let staker = staked(&mut banks, &owner).await;
+
+    let accrue = instruction(
+        PROGRAM_ID,
+        accounts::AccrueSecure {
+            owner: owner.pubkey(),
+            pool: staker.pool,
+            position: staker.position,
+        },
+        ix::AccrueSecure {},
+    );
+    send(&mut banks, &owner, &[accrue], &[]).await.unwrap();
+
+    // ✅ SECURE: multiply first, divide last
+    let position = fetch::<Position>(&mut banks, staker.position).await;
+    assert_eq!(position.reward, STAKE * REWARD_POOL / TOTAL_STAKED);
+}
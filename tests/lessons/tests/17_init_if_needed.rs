@@ -0,0 +1,110 @@
+use anchor_lang::system_program;
+use init_if_needed::secure::Vault;
+use init_if_needed::{accounts, instruction as ix};
+use lesson_tests::*;
+use security_common::SecurityError;
+use solana_program_test::BanksClient;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = init_if_needed::ID;
+
+struct Players {
+    attacker: Keypair,
+    victim: Keypair,
+    vault: Pubkey,
+}
+
+/// The attacker creates the victim's vault first, through the insecure
+/// path, with a 1 lamport deposit - and becomes its authority
+async fn front_run() -> (BanksClient, Keypair, Players) {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+    let attacker = funded_keypair(&mut banks, &payer, LAMPORTS_PER_SOL).await;
+    let victim = funded_keypair(&mut banks, &payer, 2 * LAMPORTS_PER_SOL).await;
+    let (vault, _) =
+        Pubkey::find_program_address(&[b"vault", victim.pubkey().as_ref()], &PROGRAM_ID);
+
+    let squat = instruction(
+        PROGRAM_ID,
+        accounts::DepositInsecure {
+            payer: attacker.pubkey(),
+            user: victim.pubkey(),
+            vault,
+            system_program: system_program::ID,
+        },
+        ix::DepositInsecure { amount: 1 },
+    );
+    send(&mut banks, &payer, &[squat], &[&attacker])
+        .await
+        .unwrap();
+
+    let players = Players {
+        attacker,
+        victim,
+        vault,
+    };
+    (banks, payer, players)
+}
+
+#[tokio::test]
+async fn deposit_insecure_credits_a_vault_the_attacker_controls() {
+    let (mut banks, payer, players) = front_run().await;
+
+    let deposit = instruction(
+        PROGRAM_ID,
+        accounts::DepositInsecure {
+            payer: players.victim.pubkey(),
+            user: players.victim.pubkey(),
+            vault: players.vault,
+            system_program: system_program::ID,
+        },
+        ix::DepositInsecure {
+            amount: LAMPORTS_PER_SOL,
+        },
+    );
+    send(&mut banks, &payer, &[deposit], &[&players.victim])
+        .await
+        .unwrap();
+
+    let vault = fetch::<Vault>(&mut banks, players.vault).await;
+    let steal = instruction(
+        PROGRAM_ID,
+        accounts::Withdraw {
+            authority: players.attacker.pubkey(),
+            vault: players.vault,
+        },
+        ix::Withdraw {
+            amount: vault.balance,
+        },
+    );
+    send(&mut banks, &payer, &[steal], &[&players.attacker])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: the victim's deposit went to the attacker's vault
+    assert_eq!(vault.authority, players.attacker.pubkey());
+    assert_eq!(fetch::<Vault>(&mut banks, players.vault).await.balance, 0);
+}
+
+#[tokio::test]
+async fn deposit_secure_rejects_a_vault_initialized_by_someone_else() {
+    let (mut banks, payer, players) = front_run().await;
+
+    let deposit = instruction(
+        PROGRAM_ID,
+        accounts::DepositSecure {
+            user: players.victim.pubkey(),
+            vault: players.vault,
+            system_program: system_program::ID,
+        },
+        ix::DepositSecure {
+            amount: LAMPORTS_PER_SOL,
+        },
+    );
+    let result = send(&mut banks, &payer, &[deposit], &[&players.victim]).await;
+
+    // ✅ SECURE: the authority check runs on the already-initialized branch too
+    assert_eq!(error_code(result), u32::from(SecurityError::Unauthorized));
+    assert_eq!(fetch::<Vault>(&mut banks, players.vault).await.balance, 1);
+}
@@ -0,0 +1,112 @@
+use anchor_lang::system_program;
+use lesson_tests::*;
+use remaining_accounts::secure::{ErrorCode, Reward};
+use remaining_accounts::{accounts, instruction as ix};
+use solana_program_test::BanksClient;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = remaining_accounts::ID;
+
+struct Forgery {
+    reward: Pubkey,
+    recipient: Pubkey,
+    treasury: Pubkey,
+}
+
+/// A treasury holding 2 SOL, and a Reward-shaped account the attacker
+/// wrote themselves - owned by some other program, never created here
+async fn setup() -> (BanksClient, Keypair, Forgery) {
+    let reward = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let forged = Reward {
+        owner: recipient,
+        epoch: 0,
+        amount: LAMPORTS_PER_SOL,
+        bump: 0,
+    };
+    let (mut banks, admin, _) = setup_programs(
+        &[PROGRAM_ID],
+        vec![(
+            reward,
+            owned_account(Pubkey::new_unique(), anchor_data(&forged)),
+        )],
+    )
+    .await;
+
+    let (treasury, _) = Pubkey::find_program_address(&[b"treasury"], &PROGRAM_ID);
+    let initialize = instruction(
+        PROGRAM_ID,
+        accounts::InitializeTreasury {
+            admin: admin.pubkey(),
+            treasury,
+            system_program: system_program::ID,
+        },
+        ix::InitializeTreasury {
+            amount: 2 * LAMPORTS_PER_SOL,
+        },
+    );
+    send(&mut banks, &admin, &[initialize], &[]).await.unwrap();
+
+    let forgery = Forgery {
+        reward,
+        recipient,
+        treasury,
+    };
+    (banks, admin, forgery)
+}
+
+/// Appends the (reward, recipient) pair as remaining_accounts
+fn with_pair(mut instruction: Instruction, forgery: &Forgery) -> Instruction {
+    instruction.accounts.extend([
+        AccountMeta::new_readonly(forgery.reward, false),
+        AccountMeta::new(forgery.recipient, false),
+    ]);
+    instruction
+}
+
+#[tokio::test]
+async fn distribute_insecure_pays_out_a_forged_reward() {
+    let (mut banks, payer, forgery) = setup().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::DistributeInsecure {
+            treasury: forgery.treasury,
+        },
+        ix::DistributeInsecure {},
+    );
+    send(&mut banks, &payer, &[with_pair(exploit, &forgery)], &[])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: a reward the program never created was paid in full
+    assert_eq!(
+        balance(&mut banks, forgery.recipient).await,
+        LAMPORTS_PER_SOL
+    );
+}
+
+#[tokio::test]
+async fn distribute_secure_rejects_a_reward_it_does_not_own() {
+    let (mut banks, payer, forgery) = setup().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::DistributeSecure {
+            admin: payer.pubkey(),
+            treasury: forgery.treasury,
+        },
+        ix::DistributeSecure {},
+    );
+    let result = send(&mut banks, &payer, &[with_pair(exploit, &forgery)], &[]).await;
+
+    // ✅ SECURE: owner, discriminator and PDA are all checked by hand
+    assert_eq!(
+        error_code(result),
+        u32::from(ErrorCode::InvalidRewardAccount)
+    );
+    assert!(!exists(&mut banks, forgery.recipient).await);
+}
@@ -0,0 +1,140 @@
+use anchor_lang::system_program;
+use lesson_tests::*;
+use oracle_manipulation::secure::{max_borrow, ErrorCode, Loan};
+use oracle_manipulation::{accounts, instruction as ix};
+use solana_program_test::BanksClient;
+use solana_sdk::clock::Clock;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = oracle_manipulation::ID;
+
+const COLLATERAL: u64 = 1_000;
+/// 1_000.0 with six decimals - a thousand times the real price of 1.0
+const SPIKED_PRICE: u64 = 1_000_000_000;
+/// The publisher's own band: +/- 50%
+const SPIKED_CONF: u64 = SPIKED_PRICE / 2;
+
+struct SpikedLoan {
+    owner: Keypair,
+    market: Pubkey,
+    loan: Pubkey,
+    price_feed: Pubkey,
+}
+
+/// A loan against 1_000 collateral in a market whose fresh feed shows a
+/// price spike its own publisher is unsure of
+async fn spiked_market() -> (BanksClient, SpikedLoan) {
+    let (mut banks, owner, _) = setup_program(PROGRAM_ID).await;
+    let price_feed = Keypair::new();
+    let (market, _) =
+        Pubkey::find_program_address(&[b"market", owner.pubkey().as_ref()], &PROGRAM_ID);
+    let (loan, _) = Pubkey::find_program_address(
+        &[b"loan", market.as_ref(), owner.pubkey().as_ref()],
+        &PROGRAM_ID,
+    );
+    let slot = banks.get_sysvar::<Clock>().await.unwrap().slot;
+
+    let setup = [
+        instruction(
+            PROGRAM_ID,
+            accounts::InitializeFeed {
+                price_feed: price_feed.pubkey(),
+                authority: owner.pubkey(),
+                system_program: system_program::ID,
+            },
+            ix::InitializeFeed {},
+        ),
+        instruction(
+            PROGRAM_ID,
+            accounts::UpdatePrice {
+                authority: owner.pubkey(),
+                price_feed: price_feed.pubkey(),
+            },
+            ix::UpdatePrice {
+                price: SPIKED_PRICE,
+                conf: SPIKED_CONF,
+                publish_slot: slot,
+            },
+        ),
+        instruction(
+            PROGRAM_ID,
+            accounts::InitializeMarket {
+                admin: owner.pubkey(),
+                market,
+                price_feed: price_feed.pubkey(),
+                system_program: system_program::ID,
+            },
+            ix::InitializeMarket {},
+        ),
+        instruction(
+            PROGRAM_ID,
+            accounts::OpenLoan {
+                owner: owner.pubkey(),
+                market,
+                loan,
+                system_program: system_program::ID,
+            },
+            ix::OpenLoan {
+                collateral: COLLATERAL,
+            },
+        ),
+    ];
+    send(&mut banks, &owner, &setup, &[&price_feed])
+        .await
+        .unwrap();
+
+    let market = SpikedLoan {
+        owner,
+        market,
+        loan,
+        price_feed: price_feed.pubkey(),
+    };
+    (banks, market)
+}
+
+#[tokio::test]
+async fn borrow_insecure_lends_against_an_unreliable_price() {
+    let (mut banks, market) = spiked_market().await;
+    let amount = max_borrow(COLLATERAL, SPIKED_PRICE).unwrap();
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::BorrowInsecure {
+            owner: market.owner.pubkey(),
+            loan: market.loan,
+            price_feed: market.price_feed,
+        },
+        ix::BorrowInsecure { amount },
+    );
+    send(&mut banks, &market.owner, &[exploit], &[])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: a thousand times what the collateral is worth
+    let loan = fetch::<Loan>(&mut banks, market.loan).await;
+    assert_eq!(loan.borrowed, amount);
+    assert!(loan.borrowed > COLLATERAL);
+}
+
+#[tokio::test]
+async fn borrow_secure_rejects_a_wide_confidence_band() {
+    let (mut banks, market) = spiked_market().await;
+    let amount = max_borrow(COLLATERAL, SPIKED_PRICE).unwrap();
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::BorrowSecure {
+            owner: market.owner.pubkey(),
+            loan: market.loan,
+            market: market.market,
+            price_feed: market.price_feed,
+        },
+        ix::BorrowSecure { amount },
+    );
+    let result = send(&mut banks, &market.owner, &[exploit], &[]).await;
+
+    // ✅ SECURE: conf / price must stay under MAX_CONF_BPS
+    assert_eq!(error_code(result), u32::from(ErrorCode::WideConfidence));
+    assert_eq!(fetch::<Loan>(&mut banks, market.loan).await.borrowed, 0);
+}
@@ -0,0 +1,83 @@
+use anchor_lang::system_program;
+use flash_loan::secure::ErrorCode;
+use flash_loan::{accounts, instruction as ix};
+use lesson_tests::*;
+use solana_program_test::BanksClient;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::sysvar;
+
+const PROGRAM_ID: Pubkey = flash_loan::ID;
+
+/// A pool holding 10 SOL of liquidity, and a borrower with pocket money
+async fn pool() -> (BanksClient, Keypair, Keypair, Pubkey) {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+    let borrower = funded_keypair(&mut banks, &payer, LAMPORTS_PER_SOL).await;
+    let (pool, _) = Pubkey::find_program_address(&[b"pool"], &PROGRAM_ID);
+
+    let initialize = instruction(
+        PROGRAM_ID,
+        accounts::InitializePool {
+            payer: payer.pubkey(),
+            pool,
+            system_program: system_program::ID,
+        },
+        ix::InitializePool {
+            amount: 10 * LAMPORTS_PER_SOL,
+        },
+    );
+    send(&mut banks, &payer, &[initialize], &[]).await.unwrap();
+    (banks, payer, borrower, pool)
+}
+
+#[tokio::test]
+async fn borrow_insecure_lends_on_a_promise_to_repay() {
+    let (mut banks, payer, borrower, pool) = pool().await;
+    let before = balance(&mut banks, borrower.pubkey()).await;
+
+    // 🚨 will_repay = true, and no repay instruction anywhere
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::BorrowInsecure {
+            borrower: borrower.pubkey(),
+            pool,
+        },
+        ix::BorrowInsecure {
+            amount: 5 * LAMPORTS_PER_SOL,
+            will_repay: true,
+        },
+    );
+    send(&mut banks, &payer, &[exploit], &[&borrower])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: the "flash" loan is simply kept
+    assert_eq!(
+        balance(&mut banks, borrower.pubkey()).await,
+        before + 5 * LAMPORTS_PER_SOL
+    );
+}
+
+#[tokio::test]
+async fn borrow_secure_requires_a_repay_in_the_same_transaction() {
+    let (mut banks, payer, borrower, pool) = pool().await;
+    let before = balance(&mut banks, borrower.pubkey()).await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::BorrowSecure {
+            borrower: borrower.pubkey(),
+            pool,
+            instructions: sysvar::instructions::ID,
+        },
+        ix::BorrowSecure {
+            amount: 5 * LAMPORTS_PER_SOL,
+        },
+    );
+    let result = send(&mut banks, &payer, &[exploit], &[&borrower]).await;
+
+    // ✅ SECURE: the instructions sysvar shows no matching repay
+    assert_eq!(error_code(result), u32::from(ErrorCode::MissingRepay));
+    assert_eq!(balance(&mut banks, borrower.pubkey()).await, before);
+}
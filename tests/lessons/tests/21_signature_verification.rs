@@ -0,0 +1,123 @@
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::system_program;
+use lesson_tests::*;
+use signature_verification::secure::{Config, ErrorCode, Voucher};
+use signature_verification::{accounts, instruction as ix};
+use solana_program_test::BanksClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::sysvar;
+
+const PROGRAM_ID: Pubkey = signature_verification::ID;
+
+const AMOUNT: u64 = 1_000_000;
+
+/// The program's config, trusting a voucher signer the attacker doesn't
+/// have the key for
+///
+/// Only the upgrade authority may create the config, so it is injected
+/// as already initialized.
+async fn config() -> (BanksClient, Keypair, Pubkey, Pubkey) {
+    let (config, bump) = Pubkey::find_program_address(&[b"config"], &PROGRAM_ID);
+    let trusted = Config {
+        signer: Keypair::new().pubkey(),
+        bump,
+    };
+    let (banks, attacker, _) =
+        setup_programs(&[PROGRAM_ID], vec![(config, anchor_account(&trusted))]).await;
+
+    let (voucher, _) = Pubkey::find_program_address(
+        &[b"voucher", config.as_ref(), attacker.pubkey().as_ref()],
+        &PROGRAM_ID,
+    );
+    (banks, attacker, config, voucher)
+}
+
+#[tokio::test]
+async fn redeem_insecure_accepts_any_nonzero_signature() {
+    let (mut banks, attacker, config, voucher) = config().await;
+
+    // 🚨 64 bytes of nothing in particular
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::RedeemInsecure {
+            claimant: attacker.pubkey(),
+            config,
+            voucher,
+            system_program: system_program::ID,
+        },
+        ix::RedeemInsecure {
+            amount: AMOUNT,
+            signature: [7; 64],
+        },
+    );
+    send(&mut banks, &attacker, &[exploit], &[]).await.unwrap();
+
+    // ❌ VULNERABLE: a voucher nobody signed
+    assert_eq!(fetch::<Voucher>(&mut banks, voucher).await.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn redeem_secure_requires_an_ed25519_verification() {
+    let (mut banks, attacker, config, voucher) = config().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::RedeemSecure {
+            claimant: attacker.pubkey(),
+            config,
+            voucher,
+            instructions: sysvar::instructions::ID,
+            system_program: system_program::ID,
+        },
+        ix::RedeemSecure { amount: AMOUNT },
+    );
+    let result = send(&mut banks, &attacker, &[exploit], &[]).await;
+
+    // ✅ SECURE: no Ed25519 program instruction precedes the redeem
+    assert_eq!(
+        error_code(result),
+        u32::from(ErrorCode::MissingSignatureVerification)
+    );
+    assert!(!exists(&mut banks, voucher).await);
+}
+
+#[tokio::test]
+async fn redeem_secure_rejects_a_config_the_caller_made() {
+    // 🚨 A Config naming the attacker as signer, somewhere other than the PDA
+    let forged = Pubkey::new_unique();
+    let attacker_key = Keypair::new();
+    let forged_config = Config {
+        signer: attacker_key.pubkey(),
+        bump: 255,
+    };
+    let (mut banks, attacker, _) = setup_programs(
+        &[PROGRAM_ID],
+        vec![(forged, anchor_account(&forged_config))],
+    )
+    .await;
+    let (voucher, _) = Pubkey::find_program_address(
+        &[b"voucher", forged.as_ref(), attacker.pubkey().as_ref()],
+        &PROGRAM_ID,
+    );
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::RedeemSecure {
+            claimant: attacker.pubkey(),
+            config: forged,
+            voucher,
+            instructions: sysvar::instructions::ID,
+            system_program: system_program::ID,
+        },
+        ix::RedeemSecure { amount: AMOUNT },
+    );
+    let result = send(&mut banks, &attacker, &[exploit], &[]).await;
+
+    // ✅ SECURE: only the config at the program's PDA is trusted
+    assert_eq!(
+        error_code(result),
+        u32::from(AnchorErrorCode::ConstraintSeeds)
+    );
+    assert!(!exists(&mut banks, voucher).await);
+}
@@ -0,0 +1,120 @@
+use anchor_lang::system_program;
+use anchor_spl::token::spl_token::state::{Account as TokenAccount, AccountState};
+use freeze_authority::secure::{ErrorCode, Proposal};
+use freeze_authority::{accounts, instruction as ix};
+use lesson_tests::*;
+use solana_program_test::BanksClient;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = freeze_authority::ID;
+
+const WEIGHT: u64 = 1_000_000;
+
+struct Ballot {
+    voter: Keypair,
+    voter_token: Pubkey,
+    proposal: Pubkey,
+    vote_record: Pubkey,
+}
+
+/// A proposal, and a voter whose 1_000_000 tokens the issuer has frozen
+async fn frozen_voter() -> (BanksClient, Keypair, Ballot) {
+    let voter = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let voter_token = Pubkey::new_unique();
+    let frozen = TokenAccount {
+        mint,
+        owner: voter.pubkey(),
+        amount: WEIGHT,
+        state: AccountState::Frozen,
+        ..Default::default()
+    };
+    let (mut banks, payer, _) = setup_programs(
+        &[PROGRAM_ID],
+        vec![
+            (voter.pubkey(), system_account(LAMPORTS_PER_SOL)),
+            (
+                mint,
+                mint_account(Pubkey::new_unique(), Some(Pubkey::new_unique())),
+            ),
+            (voter_token, token_account_with(frozen)),
+        ],
+    )
+    .await;
+
+    let proposal = Keypair::new();
+    let create = instruction(
+        PROGRAM_ID,
+        accounts::CreateProposal {
+            proposal: proposal.pubkey(),
+            mint,
+            payer: payer.pubkey(),
+            system_program: system_program::ID,
+        },
+        ix::CreateProposal {},
+    );
+    send(&mut banks, &payer, &[create], &[&proposal])
+        .await
+        .unwrap();
+
+    let (vote_record, _) = Pubkey::find_program_address(
+        &[b"vote", proposal.pubkey().as_ref(), voter.pubkey().as_ref()],
+        &PROGRAM_ID,
+    );
+    let ballot = Ballot {
+        voter,
+        voter_token,
+        proposal: proposal.pubkey(),
+        vote_record,
+    };
+    (banks, payer, ballot)
+}
+
+#[tokio::test]
+async fn cast_vote_insecure_counts_a_frozen_balance() {
+    let (mut banks, payer, ballot) = frozen_voter().await;
+
+    let vote = instruction(
+        PROGRAM_ID,
+        accounts::CastVoteInsecure {
+            voter: ballot.voter.pubkey(),
+            proposal: ballot.proposal,
+            voter_token: ballot.voter_token,
+            vote_record: ballot.vote_record,
+            system_program: system_program::ID,
+        },
+        ix::CastVoteInsecure {},
+    );
+    send(&mut banks, &payer, &[vote], &[&ballot.voter])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: seized tokens still swing the vote
+    let proposal = fetch::<Proposal>(&mut banks, ballot.proposal).await;
+    assert_eq!(proposal.yes_votes, WEIGHT);
+}
+
+#[tokio::test]
+async fn cast_vote_secure_rejects_a_frozen_account() {
+    let (mut banks, payer, ballot) = frozen_voter().await;
+
+    let vote = instruction(
+        PROGRAM_ID,
+        accounts::CastVoteSecure {
+            voter: ballot.voter.pubkey(),
+            proposal: ballot.proposal,
+            voter_token: ballot.voter_token,
+            vote_record: ballot.vote_record,
+            system_program: system_program::ID,
+        },
+        ix::CastVoteSecure {},
+    );
+    let result = send(&mut banks, &payer, &[vote], &[&ballot.voter]).await;
+
+    // ✅ SECURE: frozen balances carry no weight
+    assert_eq!(error_code(result), u32::from(ErrorCode::AccountFrozen));
+    let proposal = fetch::<Proposal>(&mut banks, ballot.proposal).await;
+    assert_eq!(proposal.yes_votes, 0);
+}
@@ -0,0 +1,116 @@
+use anchor_spl::token::spl_token::state::{Account as TokenAccount, AccountState};
+use delegate_misuse::secure::ErrorCode;
+use delegate_misuse::{accounts, instruction as ix};
+use lesson_tests::*;
+use solana_program_test::BanksClient;
+use solana_sdk::program_option::COption;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = delegate_misuse::ID;
+
+const VAULT_BALANCE: u64 = 1_000;
+const ALLOWANCE: u64 = 100;
+
+struct Vault {
+    spender: Keypair,
+    user: Pubkey,
+    vault_authority: Pubkey,
+    vault_token: Pubkey,
+    destination: Pubkey,
+}
+
+/// A user's vault holding 1_000 tokens, with a spender approved for 100
+async fn approved_vault() -> (BanksClient, Keypair, Vault) {
+    let spender = Keypair::new();
+    let user = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let (vault_authority, _) =
+        Pubkey::find_program_address(&[b"vault_authority", user.as_ref()], &PROGRAM_ID);
+    let (vault_token, _) =
+        Pubkey::find_program_address(&[b"vault_token", user.as_ref()], &PROGRAM_ID);
+    let approved = TokenAccount {
+        mint,
+        owner: vault_authority,
+        amount: VAULT_BALANCE,
+        delegate: COption::Some(spender.pubkey()),
+        state: AccountState::Initialized,
+        delegated_amount: ALLOWANCE,
+        ..Default::default()
+    };
+
+    let (banks, payer, _) = setup_programs(
+        &[PROGRAM_ID],
+        vec![
+            (mint, mint_account(Pubkey::new_unique(), None)),
+            (vault_token, token_account_with(approved)),
+            (destination, token_account(mint, spender.pubkey(), 0)),
+        ],
+    )
+    .await;
+    let vault = Vault {
+        spender,
+        user,
+        vault_authority,
+        vault_token,
+        destination,
+    };
+    (banks, payer, vault)
+}
+
+#[tokio::test]
+async fn spend_insecure_lets_the_delegate_exceed_its_allowance() {
+    let (mut banks, payer, vault) = approved_vault().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::SpendInsecure {
+            spender: vault.spender.pubkey(),
+            user: vault.user,
+            vault_authority: vault.vault_authority,
+            vault_token: vault.vault_token,
+            destination: vault.destination,
+            token_program: anchor_spl::token::ID,
+        },
+        ix::SpendInsecure {
+            amount: VAULT_BALANCE,
+        },
+    );
+    send(&mut banks, &payer, &[exploit], &[&vault.spender])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: approved for 100, walked off with 1_000
+    let destination = fetch_token(&mut banks, vault.destination).await;
+    assert_eq!(destination.amount, VAULT_BALANCE);
+}
+
+#[tokio::test]
+async fn spend_secure_holds_the_delegate_to_its_allowance() {
+    let (mut banks, payer, vault) = approved_vault().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::SpendSecure {
+            spender: vault.spender.pubkey(),
+            user: vault.user,
+            vault_authority: vault.vault_authority,
+            vault_token: vault.vault_token,
+            destination: vault.destination,
+            token_program: anchor_spl::token::ID,
+        },
+        ix::SpendSecure {
+            amount: VAULT_BALANCE,
+        },
+    );
+    let result = send(&mut banks, &payer, &[exploit], &[&vault.spender]).await;
+
+    // ✅ SECURE: delegated_amount is checked before the owner-signed transfer
+    assert_eq!(
+        error_code(result),
+        u32::from(ErrorCode::ExceedsDelegatedAmount)
+    );
+    let vault_token = fetch_token(&mut banks, vault.vault_token).await;
+    assert_eq!(vault_token.amount, VAULT_BALANCE);
+}
@@ -0,0 +1,107 @@
+use anchor_lang::error::ErrorCode;
+use close_authority::secure::Escrow;
+use close_authority::{accounts, instruction as ix};
+use lesson_tests::*;
+use solana_program_test::BanksClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = close_authority::ID;
+
+const ESCROWED: u64 = 1_000;
+
+struct Escrowed {
+    attacker: Keypair,
+    escrow: Pubkey,
+    escrow_token: Pubkey,
+    attacker_token: Pubkey,
+}
+
+/// An unlocked escrow holding the maker's 1_000 tokens, and an attacker
+/// with an empty token account of the same mint
+async fn unlocked_escrow() -> (BanksClient, Keypair, Escrowed) {
+    let attacker = Keypair::new();
+    let maker = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let attacker_token = Pubkey::new_unique();
+    let (escrow, bump) = Pubkey::find_program_address(&[b"escrow", maker.as_ref()], &PROGRAM_ID);
+    let (escrow_token, _) =
+        Pubkey::find_program_address(&[b"escrow_token", maker.as_ref()], &PROGRAM_ID);
+    let state = Escrow {
+        maker,
+        mint,
+        unlock_at: 0,
+        bump,
+    };
+
+    let (banks, payer, _) = setup_programs(
+        &[PROGRAM_ID],
+        vec![
+            (mint, mint_account(Pubkey::new_unique(), None)),
+            (escrow, anchor_account(&state)),
+            (escrow_token, token_account(mint, escrow, ESCROWED)),
+            (attacker_token, token_account(mint, attacker.pubkey(), 0)),
+        ],
+    )
+    .await;
+    let escrowed = Escrowed {
+        attacker,
+        escrow,
+        escrow_token,
+        attacker_token,
+    };
+    (banks, payer, escrowed)
+}
+
+#[tokio::test]
+async fn close_escrow_insecure_sends_the_funds_wherever_the_closer_says() {
+    let (mut banks, payer, escrowed) = unlocked_escrow().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::CloseEscrowInsecure {
+            closer: escrowed.attacker.pubkey(),
+            escrow: escrowed.escrow,
+            escrow_token: escrowed.escrow_token,
+            refund_token: escrowed.attacker_token,
+            rent_destination: escrowed.attacker.pubkey(),
+            token_program: anchor_spl::token::ID,
+        },
+        ix::CloseEscrowInsecure {},
+    );
+    send(&mut banks, &payer, &[exploit], &[&escrowed.attacker])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: the maker's tokens and both accounts' rent are gone
+    let stolen = fetch_token(&mut banks, escrowed.attacker_token).await;
+    assert_eq!(stolen.amount, ESCROWED);
+    assert!(balance(&mut banks, escrowed.attacker.pubkey()).await > 0);
+}
+
+#[tokio::test]
+async fn close_escrow_secure_refunds_only_the_maker() {
+    let (mut banks, payer, escrowed) = unlocked_escrow().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::CloseEscrowSecure {
+            closer: escrowed.attacker.pubkey(),
+            escrow: escrowed.escrow,
+            escrow_token: escrowed.escrow_token,
+            refund_token: escrowed.attacker_token,
+            rent_destination: escrowed.attacker.pubkey(),
+            token_program: anchor_spl::token::ID,
+        },
+        ix::CloseEscrowSecure {},
+    );
+    let result = send(&mut banks, &payer, &[exploit], &[&escrowed.attacker]).await;
+
+    // ✅ SECURE: refund_token must belong to escrow.maker
+    assert_eq!(
+        error_code(result),
+        u32::from(ErrorCode::ConstraintTokenOwner)
+    );
+    let escrow_token = fetch_token(&mut banks, escrowed.escrow_token).await;
+    assert_eq!(escrow_token.amount, ESCROWED);
+}
@@ -0,0 +1,135 @@
+use anchor_lang::system_program;
+use lesson_tests::*;
+use slippage::secure::{ErrorCode, Trader};
+use slippage::{accounts, instruction as ix};
+use solana_program_test::BanksClient;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = slippage::ID;
+
+const RESERVE: u64 = 1_000;
+const VICTIM_IN: u64 = 100;
+/// What the victim was quoted against the untouched pool
+const QUOTED_OUT: u64 = RESERVE * VICTIM_IN / (RESERVE + VICTIM_IN);
+
+struct Sandwich {
+    victim: Keypair,
+    pool: Pubkey,
+    victim_trader: Pubkey,
+}
+
+fn trader_address(pool: &Pubkey, owner: &Keypair) -> Pubkey {
+    let owner = owner.pubkey();
+    Pubkey::find_program_address(&[b"trader", pool.as_ref(), owner.as_ref()], &PROGRAM_ID).0
+}
+
+fn open_trader(pool: Pubkey, owner: &Keypair) -> Instruction {
+    instruction(
+        PROGRAM_ID,
+        accounts::OpenTrader {
+            owner: owner.pubkey(),
+            pool,
+            trader: trader_address(&pool, owner),
+            system_program: system_program::ID,
+        },
+        ix::OpenTrader { balance_a: RESERVE },
+    )
+}
+
+/// A 1_000/1_000 pool the attacker has already pushed with a 900 A swap,
+/// just ahead of the victim's transaction
+async fn front_run() -> (BanksClient, Keypair, Sandwich) {
+    let (mut banks, attacker, _) = setup_program(PROGRAM_ID).await;
+    let victim = funded_keypair(&mut banks, &attacker, LAMPORTS_PER_SOL).await;
+    let pool = Keypair::new();
+
+    let setup = [
+        instruction(
+            PROGRAM_ID,
+            accounts::InitializePool {
+                pool: pool.pubkey(),
+                payer: attacker.pubkey(),
+                system_program: system_program::ID,
+            },
+            ix::InitializePool {
+                reserve_a: RESERVE,
+                reserve_b: RESERVE,
+            },
+        ),
+        open_trader(pool.pubkey(), &attacker),
+        open_trader(pool.pubkey(), &victim),
+    ];
+    send(&mut banks, &attacker, &setup, &[&pool, &victim])
+        .await
+        .unwrap();
+
+    let push = instruction(
+        PROGRAM_ID,
+        accounts::SwapInsecure {
+            owner: attacker.pubkey(),
+            pool: pool.pubkey(),
+            trader: trader_address(&pool.pubkey(), &attacker),
+        },
+        ix::SwapInsecure { amount_in: 900 },
+    );
+    send(&mut banks, &attacker, &[push], &[]).await.unwrap();
+
+    let sandwich = Sandwich {
+        victim_trader: trader_address(&pool.pubkey(), &victim),
+        victim,
+        pool: pool.pubkey(),
+    };
+    (banks, attacker, sandwich)
+}
+
+#[tokio::test]
+async fn swap_insecure_fills_at_the_manipulated_price() {
+    let (mut banks, payer, sandwich) = front_run().await;
+
+    let swap = instruction(
+        PROGRAM_ID,
+        accounts::SwapInsecure {
+            owner: sandwich.victim.pubkey(),
+            pool: sandwich.pool,
+            trader: sandwich.victim_trader,
+        },
+        ix::SwapInsecure {
+            amount_in: VICTIM_IN,
+        },
+    );
+    send(&mut banks, &payer, &[swap], &[&sandwich.victim])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: quoted 90 B, received a fraction of that
+    let trader = fetch::<Trader>(&mut banks, sandwich.victim_trader).await;
+    assert!(trader.balance_b < QUOTED_OUT / 2);
+}
+
+#[tokio::test]
+async fn swap_secure_reverts_below_the_minimum_out() {
+    let (mut banks, payer, sandwich) = front_run().await;
+
+    let swap = instruction(
+        PROGRAM_ID,
+        accounts::SwapSecure {
+            owner: sandwich.victim.pubkey(),
+            pool: sandwich.pool,
+            trader: sandwich.victim_trader,
+        },
+        ix::SwapSecure {
+            amount_in: VICTIM_IN,
+            min_amount_out: QUOTED_OUT,
+        },
+    );
+    let result = send(&mut banks, &payer, &[swap], &[&sandwich.victim]).await;
+
+    // ✅ SECURE: the victim's limit turns the sandwich into a failed swap
+    assert_eq!(error_code(result), u32::from(ErrorCode::SlippageExceeded));
+    let trader = fetch::<Trader>(&mut banks, sandwich.victim_trader).await;
+    assert_eq!(trader.balance_a, RESERVE);
+    assert_eq!(trader.balance_b, 0);
+}
@@ -0,0 +1,112 @@
+use anchor_lang::system_program;
+use lesson_tests::*;
+use nested_has_one::secure::{ErrorCode, Project};
+use nested_has_one::{accounts, instruction as ix};
+use solana_program_test::BanksClient;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = nested_has_one::ID;
+
+const BUDGET: u64 = 1_000_000;
+
+struct Orgs {
+    attacker: Keypair,
+    attacker_org: Pubkey,
+    victim_project: Pubkey,
+}
+
+fn initialize_organization(organization: &Keypair, authority: &Keypair) -> Instruction {
+    instruction(
+        PROGRAM_ID,
+        accounts::InitializeOrganization {
+            organization: organization.pubkey(),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        ix::InitializeOrganization {},
+    )
+}
+
+/// Two organizations: the victim's, with a funded project, and the
+/// attacker's own, empty one
+async fn two_orgs() -> (BanksClient, Keypair, Orgs) {
+    let (mut banks, victim, _) = setup_program(PROGRAM_ID).await;
+    let attacker = funded_keypair(&mut banks, &victim, LAMPORTS_PER_SOL).await;
+    let victim_org = Keypair::new();
+    let attacker_org = Keypair::new();
+    let victim_project = Keypair::new();
+
+    let setup = [
+        initialize_organization(&victim_org, &victim),
+        initialize_organization(&attacker_org, &attacker),
+        instruction(
+            PROGRAM_ID,
+            accounts::CreateProject {
+                authority: victim.pubkey(),
+                organization: victim_org.pubkey(),
+                project: victim_project.pubkey(),
+                system_program: system_program::ID,
+            },
+            ix::CreateProject { budget: BUDGET },
+        ),
+    ];
+    let signers = [&attacker, &victim_org, &attacker_org, &victim_project];
+    send(&mut banks, &victim, &setup, &signers).await.unwrap();
+
+    let orgs = Orgs {
+        attacker,
+        attacker_org: attacker_org.pubkey(),
+        victim_project: victim_project.pubkey(),
+    };
+    (banks, victim, orgs)
+}
+
+#[tokio::test]
+async fn update_project_insecure_accepts_a_project_from_another_org() {
+    let (mut banks, payer, orgs) = two_orgs().await;
+
+    // 🚨 the attacker's own org, next to the victim's project
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::UpdateProjectInsecure {
+            authority: orgs.attacker.pubkey(),
+            organization: orgs.attacker_org,
+            project: orgs.victim_project,
+        },
+        ix::UpdateProjectInsecure { budget: 0 },
+    );
+    send(&mut banks, &payer, &[exploit], &[&orgs.attacker])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: the victim's project budget was zeroed
+    let project = fetch::<Project>(&mut banks, orgs.victim_project).await;
+    assert_eq!(project.budget, 0);
+}
+
+#[tokio::test]
+async fn update_project_secure_checks_the_project_belongs_to_the_org() {
+    let (mut banks, payer, orgs) = two_orgs().await;
+
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::UpdateProjectSecure {
+            authority: orgs.attacker.pubkey(),
+            organization: orgs.attacker_org,
+            project: orgs.victim_project,
+        },
+        ix::UpdateProjectSecure { budget: 0 },
+    );
+    let result = send(&mut banks, &payer, &[exploit], &[&orgs.attacker]).await;
+
+    // ✅ SECURE: has_one = organization closes the second link
+    assert_eq!(
+        error_code(result),
+        u32::from(ErrorCode::ProjectNotInOrganization)
+    );
+    let project = fetch::<Project>(&mut banks, orgs.victim_project).await;
+    assert_eq!(project.budget, BUDGET);
+}
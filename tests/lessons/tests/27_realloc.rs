@@ -0,0 +1,86 @@
+use anchor_lang::system_program;
+use lesson_tests::*;
+use realloc::secure::ItemList;
+use realloc::{accounts, instruction as ix};
+use solana_program_test::BanksClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::TransactionError;
+
+const PROGRAM_ID: Pubkey = realloc::ID;
+
+const ADDITIONAL: u32 = 10;
+
+/// An empty list, sized and funded for zero items
+async fn empty_list(banks: &mut BanksClient, authority: &Keypair) -> Pubkey {
+    let list = Keypair::new();
+    let initialize = instruction(
+        PROGRAM_ID,
+        accounts::InitializeList {
+            list: list.pubkey(),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        ix::InitializeList {},
+    );
+    send(banks, authority, &[initialize], &[&list])
+        .await
+        .unwrap();
+    list.pubkey()
+}
+
+#[tokio::test]
+async fn reserve_insecure_grows_the_list_without_paying_its_rent() {
+    let (mut banks, authority, _) = setup_program(PROGRAM_ID).await;
+    let list = empty_list(&mut banks, &authority).await;
+
+    let reserve = instruction(
+        PROGRAM_ID,
+        accounts::ReserveInsecure {
+            authority: authority.pubkey(),
+            list,
+        },
+        ix::ReserveInsecure {
+            additional: ADDITIONAL,
+        },
+    );
+    let result = send(&mut banks, &authority, &[reserve], &[]).await;
+
+    // ❌ VULNERABLE: the grown account is below the rent-exempt minimum,
+    // so the runtime throws the whole transaction out
+    assert!(matches!(
+        transaction_error(result),
+        TransactionError::InsufficientFundsForRent { .. }
+    ));
+    let account = banks.get_account(list).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), ItemList::space(0));
+}
+
+#[tokio::test]
+async fn reserve_secure_pays_for_a_zeroed_region() {
+    let (mut banks, authority, _) = setup_program(PROGRAM_ID).await;
+    let list = empty_list(&mut banks, &authority).await;
+
+    let reserve = instruction(
+        PROGRAM_ID,
+        accounts::ReserveSecure {
+            authority: authority.pubkey(),
+            list,
+            system_program: system_program::ID,
+        },
+        ix::ReserveSecure {
+            additional: ADDITIONAL,
+        },
+    );
+    send(&mut banks, &authority, &[reserve], &[]).await.unwrap();
+
+    // ✅ SECURE: topped up to rent-exempt, and every new byte is zero
+    let account = banks.get_account(list).await.unwrap().unwrap();
+    let new_len = ItemList::space(ADDITIONAL as usize);
+    let rent = banks.get_rent().await.unwrap();
+    assert_eq!(account.data.len(), new_len);
+    assert!(account.data[ItemList::space(0)..]
+        .iter()
+        .all(|&byte| byte == 0));
+    assert!(account.lamports >= rent.minimum_balance(new_len));
+}
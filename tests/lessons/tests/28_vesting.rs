@@ -0,0 +1,81 @@
+use anchor_lang::system_program;
+use lesson_tests::*;
+use solana_program_test::BanksClient;
+use solana_sdk::clock::Clock;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use vesting::secure::{ErrorCode, VestingSchedule};
+use vesting::{accounts, instruction as ix};
+
+const PROGRAM_ID: Pubkey = vesting::ID;
+
+const TOTAL: u64 = 1_000_000;
+const YEAR: i64 = 365 * 24 * 60 * 60;
+
+/// A four-year grant with a one-year cliff, starting now
+async fn new_grant(banks: &mut BanksClient, beneficiary: &Keypair) -> Pubkey {
+    let now = banks.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    let (schedule, _) =
+        Pubkey::find_program_address(&[b"vesting", beneficiary.pubkey().as_ref()], &PROGRAM_ID);
+    let create = instruction(
+        PROGRAM_ID,
+        accounts::CreateSchedule {
+            creator: beneficiary.pubkey(),
+            beneficiary: beneficiary.pubkey(),
+            schedule,
+            system_program: system_program::ID,
+        },
+        ix::CreateSchedule {
+            total_amount: TOTAL,
+            start_ts: now,
+            cliff_seconds: YEAR,
+            duration_seconds: 4 * YEAR,
+        },
+    );
+    send(banks, beneficiary, &[create], &[]).await.unwrap();
+    schedule
+}
+
+#[tokio::test]
+async fn release_insecure_unlocks_everything_for_a_future_timestamp() {
+    let (mut banks, beneficiary, _) = setup_program(PROGRAM_ID).await;
+    let schedule = new_grant(&mut banks, &beneficiary).await;
+
+    // 🚨 "it is the end of time"
+    let exploit = instruction(
+        PROGRAM_ID,
+        accounts::ReleaseInsecure {
+            beneficiary: beneficiary.pubkey(),
+            schedule,
+        },
+        ix::ReleaseInsecure { now: i64::MAX },
+    );
+    send(&mut banks, &beneficiary, &[exploit], &[])
+        .await
+        .unwrap();
+
+    // ❌ VULNERABLE: the four-year grant fully released on day one
+    let schedule = fetch::<VestingSchedule>(&mut banks, schedule).await;
+    assert_eq!(schedule.released, TOTAL);
+}
+
+#[tokio::test]
+async fn release_secure_reads_the_clock_sysvar() {
+    let (mut banks, beneficiary, _) = setup_program(PROGRAM_ID).await;
+    let schedule = new_grant(&mut banks, &beneficiary).await;
+
+    let release = instruction(
+        PROGRAM_ID,
+        accounts::ReleaseSecure {
+            beneficiary: beneficiary.pubkey(),
+            schedule,
+        },
+        ix::ReleaseSecure {},
+    );
+    let result = send(&mut banks, &beneficiary, &[release], &[]).await;
+
+    // ✅ SECURE: still before the cliff by the runtime's clock
+    assert_eq!(error_code(result), u32::from(ErrorCode::NothingToRelease));
+    let schedule = fetch::<VestingSchedule>(&mut banks, schedule).await;
+    assert_eq!(schedule.released, 0);
+}
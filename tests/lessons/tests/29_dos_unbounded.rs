@@ -0,0 +1,93 @@
+use anchor_lang::system_program;
+use dos_unbounded::secure::{EntryList, ErrorCode, MAX_ENTRIES, MAX_ENTRIES_PER_USER};
+use dos_unbounded::{accounts, instruction as ix};
+use lesson_tests::*;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PROGRAM_ID: Pubkey = dos_unbounded::ID;
+
+fn append_secure(user: &Keypair, list: Pubkey, value: u64) -> Instruction {
+    instruction(
+        PROGRAM_ID,
+        accounts::AppendSecure {
+            user: user.pubkey(),
+            list,
+        },
+        ix::AppendSecure { value },
+    )
+}
+
+#[tokio::test]
+async fn append_insecure_lets_one_user_grow_the_list_without_limit() {
+    let (mut banks, attacker, _) = setup_program(PROGRAM_ID).await;
+    let list = Keypair::new();
+    let initialize = instruction(
+        PROGRAM_ID,
+        accounts::InitializeUnboundedList {
+            list: list.pubkey(),
+            payer: attacker.pubkey(),
+            system_program: system_program::ID,
+        },
+        ix::InitializeUnboundedList {},
+    );
+    send(&mut banks, &attacker, &[initialize], &[&list])
+        .await
+        .unwrap();
+
+    let spam: Vec<_> = (0..=MAX_ENTRIES as u64)
+        .map(|value| {
+            instruction(
+                PROGRAM_ID,
+                accounts::AppendInsecure {
+                    user: attacker.pubkey(),
+                    list: list.pubkey(),
+                    system_program: system_program::ID,
+                },
+                ix::AppendInsecure { value },
+            )
+        })
+        .collect();
+    send(&mut banks, &attacker, &spam, &[]).await.unwrap();
+
+    // ❌ VULNERABLE: one user, every slot and then some - and nothing
+    // stops the next transaction from doing it again
+    let list = fetch::<EntryList>(&mut banks, list.pubkey()).await;
+    assert_eq!(list.entries.len(), MAX_ENTRIES + 1);
+    assert!(list
+        .entries
+        .iter()
+        .all(|entry| entry.author == attacker.pubkey()));
+}
+
+#[tokio::test]
+async fn append_secure_caps_each_users_share() {
+    let (mut banks, attacker, _) = setup_program(PROGRAM_ID).await;
+    let list = Keypair::new();
+    let initialize = instruction(
+        PROGRAM_ID,
+        accounts::InitializeList {
+            list: list.pubkey(),
+            payer: attacker.pubkey(),
+            system_program: system_program::ID,
+        },
+        ix::InitializeList {},
+    );
+    send(&mut banks, &attacker, &[initialize], &[&list])
+        .await
+        .unwrap();
+
+    let share: Vec<_> = (0..MAX_ENTRIES_PER_USER as u64)
+        .map(|value| append_secure(&attacker, list.pubkey(), value))
+        .collect();
+    send(&mut banks, &attacker, &share, &[]).await.unwrap();
+
+    let one_more = append_secure(&attacker, list.pubkey(), u64::MAX);
+    let result = send(&mut banks, &attacker, &[one_more], &[]).await;
+
+    // ✅ SECURE: the rest of the list stays open to everyone else
+    assert_eq!(error_code(result), u32::from(ErrorCode::UserLimitReached));
+    let list = fetch::<EntryList>(&mut banks, list.pubkey()).await;
+    assert_eq!(list.entries.len(), MAX_ENTRIES_PER_USER);
+}
@@ -0,0 +1,53 @@
+use lesson_tests::*;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use unit_confusion::{accounts, instruction as ix};
+
+const PROGRAM_ID: Pubkey = unit_confusion::ID;
+
+const STAKE_SOL: u64 = 10;
+/// 5%
+const RATE_BPS: u16 = 500;
+
+fn logged(logs: &[String], line: &str) -> bool {
+    logs.iter().any(|log| log.contains(line))
+}
+
+#[tokio::test]
+async fn quote_payout_insecure_treats_sol_as_lamports() {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+
+    let quote = instruction(
+        PROGRAM_ID,
+        accounts::QuotePayout {},
+        ix::QuotePayoutInsecure {
+            stake_sol: STAKE_SOL,
+            rate_bps: RATE_BPS,
+        },
+    );
+    let (result, logs) = send_for_logs(&mut banks, &payer, &[quote], &[]).await;
+
+    // ❌ VULNERABLE: 5% of 10 SOL quoted as 5% of 10 lamports - zero
+    result.unwrap();
+    assert!(logged(&logs, "Payout: 0 lamports (INSECURE)"), "{logs:#?}");
+}
+
+#[tokio::test]
+async fn quote_payout_secure_converts_to_lamports_first() {
+    let (mut banks, payer, _) = setup_program(PROGRAM_ID).await;
+
+    let quote = instruction(
+        PROGRAM_ID,
+        accounts::QuotePayout {},
+        ix::QuotePayoutSecure {
+            stake_sol: STAKE_SOL,
+            rate_bps: RATE_BPS,
+        },
+    );
+    let (result, logs) = send_for_logs(&mut banks, &payer, &[quote], &[]).await;
+
+    // ✅ SECURE: half a SOL, in lamports
+    result.unwrap();
+    let payout = format!("Payout: {} lamports", LAMPORTS_PER_SOL / 2);
+    assert!(logged(&logs, &payout), "{logs:#?}");
+}
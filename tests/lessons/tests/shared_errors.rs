@@ -0,0 +1,16 @@
+// Programs return the shared SecurityError, so a client can match on one
+// number no matter which program rejected the transaction
+use account_data_matching::SecurityError as AccountDataMatchingError;
+use missing_signer_check::SecurityError as MissingSignerCheckError;
+
+#[test]
+fn unauthorized_has_the_same_code_in_programs_01_and_04() {
+    assert_eq!(
+        MissingSignerCheckError::Unauthorized as u32,
+        AccountDataMatchingError::Unauthorized as u32
+    );
+
+    // What clients see on-chain: the pinned code past the 7000 offset
+    assert_eq!(u32::from(MissingSignerCheckError::Unauthorized), 7002);
+    assert_eq!(u32::from(AccountDataMatchingError::Unauthorized), 7002);
+}